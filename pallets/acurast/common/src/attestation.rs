@@ -1,7 +1,12 @@
 #![cfg_attr(all(feature = "alloc", not(feature = "std"), not(test)), no_std)]
 
+pub mod app_attest;
 pub mod asn;
+#[cfg(feature = "std")]
+pub mod builder;
+mod cbor;
 pub mod error;
+pub mod status_list;
 
 use asn::*;
 use asn1::{oid, BitString, ObjectIdentifier, ParseError, SequenceOf};
@@ -19,6 +24,8 @@ pub const CHAIN_MAX_LENGTH: u32 = 5;
 pub const CERT_MAX_LENGTH: u32 = 3000;
 pub type CertificateInput = BoundedVec<u8, ConstU32<CERT_MAX_LENGTH>>;
 pub type CertificateChainInput = BoundedVec<CertificateInput, ConstU32<CHAIN_MAX_LENGTH>>;
+/// The sha256 hash of a DER encoded certificate.
+pub type CertificateFingerprint = [u8; 32];
 
 fn parse_cert(serialized: &[u8]) -> Result<Certificate, ParseError> {
     let data = asn1::parse_single::<Certificate>(serialized)?;
@@ -162,6 +169,47 @@ impl PublicKey {
     }
 }
 
+impl PublicKey {
+    /// Returns the canonical encoding of this key, used to bind it to an account.
+    ///
+    /// * P-256 keys are encoded as compressed SEC1 point (33 bytes) as returned by [VerifyingKey::to_bytes].
+    /// * P-384 keys are encoded as uncompressed SEC1 point (97 bytes), `0x04 || x || y` with big endian coordinates.
+    /// * RSA keys are encoded as DER encoded PKCS#1 `RSAPublicKey`, a SEQUENCE of the modulus and the public exponent
+    ///   as minimally encoded positive INTEGERs.
+    pub fn encode_canonical(&self) -> Result<Vec<u8>, ValidationError> {
+        match self {
+            PublicKey::ECDSA(ECDSACurve::CurveP256(key)) => Ok(key.to_bytes().to_vec()),
+            PublicKey::ECDSA(ECDSACurve::CurveP384(point)) => Ok([
+                &[0x04u8][..],
+                point.x.to_sec1().as_slice(),
+                point.y.to_sec1().as_slice(),
+            ]
+            .concat()),
+            PublicKey::RSA(pbk) => {
+                let modulus = unsigned_integer_bytes(&pbk.modulus);
+                let exponent = unsigned_integer_bytes(&pbk.exponent);
+                let pbk = RSAPublicKey {
+                    modulus: asn1::BigUint::new(&modulus)
+                        .ok_or(ValidationError::EncodePublicKey)?,
+                    exponent: asn1::BigUint::new(&exponent)
+                        .ok_or(ValidationError::EncodePublicKey)?,
+                };
+                asn1::write_single(&pbk).map_err(|_| ValidationError::EncodePublicKey)
+            }
+        }
+    }
+}
+
+/// Returns the big endian bytes of `value` as expected for a DER encoded positive INTEGER.
+fn unsigned_integer_bytes(value: &BigUint) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    if bytes[0] & 0x80 != 0 {
+        [&[0u8][..], &bytes].concat()
+    } else {
+        bytes
+    }
+}
+
 const CURVE_P256: ObjectIdentifier = oid!(1, 2, 840, 10045, 3, 1, 7);
 const CURVE_P384: ObjectIdentifier = oid!(1, 3, 132, 0, 34);
 
@@ -279,11 +327,18 @@ pub fn peek_attestation_version(data: &[u8]) -> Result<i64, ParseError> {
     result
 }
 
+/// Returns the sha256 fingerprint of a DER encoded certificate.
+pub fn certificate_fingerprint(certificate: &[u8]) -> CertificateFingerprint {
+    sha2::Sha256::digest(certificate).into()
+}
+
+/// Validates that the chain starts with one of the `trusted_root_certs`.
 pub fn validate_certificate_chain_root(
     chain: &CertificateChainInput,
+    trusted_root_certs: &[&[u8]],
 ) -> Result<(), ValidationError> {
     let first = chain.first().ok_or(ValidationError::ChainTooShort)?;
-    if !TRUSTED_ROOT_CERTS.contains(&first.as_slice()) {
+    if !trusted_root_certs.contains(&first.as_slice()) {
         return Err(ValidationError::UntrustedRoot);
     }
     Ok(())
@@ -291,18 +346,27 @@ pub fn validate_certificate_chain_root(
 
 /// Validates the chain by ensuring that
 ///
-/// - the chain starts with a self-signed certificate at index 0 that matches one of the known [TRUSTED_ROOT_CERTS]
+/// - the chain starts with a self-signed certificate at index 0, use [validate_certificate_chain_root] to check it is trusted
 /// - that the root's contained public key signs the next certificate in the chain
 /// - the next certificate's public key signs the next one and so on...
 pub fn validate_certificate_chain<'a>(
     chain: &'a CertificateChainInput,
+) -> Result<(Vec<CertificateId>, TBSCertificate<'a>, PublicKey), ValidationError> {
+    let chain = chain.iter().map(|cert| cert.as_slice()).collect::<Vec<_>>();
+    validate_certificate_chain_slices(&chain)
+}
+
+/// Validates the chain of DER encoded certificates as described for [validate_certificate_chain],
+/// without checking the root against a list of trusted certificates.
+pub fn validate_certificate_chain_slices<'a>(
+    chain: &[&'a [u8]],
 ) -> Result<(Vec<CertificateId>, TBSCertificate<'a>, PublicKey), ValidationError> {
     let mut cert_ids = Vec::<CertificateId>::new();
     let fold_result = chain.iter().try_fold::<_, _, Result<_, ValidationError>>(
         (Option::<PublicKey>::None, Option::<Certificate>::None),
         |(prev_pbk, _), cert_data| {
-            let cert = parse_cert(&cert_data)?;
-            let payload = parse_cert_payload(&cert_data)?;
+            let cert = parse_cert(*cert_data)?;
+            let payload = parse_cert_payload(*cert_data)?;
             let current_pbk = PublicKey::parse(&cert.tbs_certificate.subject_public_key_info)?;
 
             validate(&cert, payload, prev_pbk.as_ref().unwrap_or(&current_pbk))?;
@@ -326,7 +390,9 @@ pub fn validate_certificate_chain<'a>(
     Ok((cert_ids, last_cert.tbs_certificate, last_cert_pbk))
 }
 
-/// The list of trusted root certificates, as decoded bytes arrays. [Source](https://developer.android.com/training/articles/security-key-attestation#root_certificate)
+/// The list of Google root certificates, as decoded bytes arrays. [Source](https://developer.android.com/training/articles/security-key-attestation#root_certificate)
+///
+/// Used as the initial set of trusted root certificates of `pallet_acurast`, which can be updated without a runtime upgrade.
 ///
 // Adding new root certificate:
 //
@@ -335,7 +401,7 @@ pub fn validate_certificate_chain<'a>(
 //     let line = base64::decode(r"<base64>").unwrap();
 //     output.write_all(&line);
 //
-const TRUSTED_ROOT_CERTS: &[&[u8]] = &[
+pub const TRUSTED_ROOT_CERTS: &[&[u8]] = &[
     // base64 equivalent: r"MIIFYDCCA0igAwIBAgIJAOj6GWMU0voYMA0GCSqGSIb3DQEBCwUAMBsxGTAXBgNVBAUTEGY5MjAwOWU4NTNiNmIwNDUwHhcNMTYwNTI2MTYyODUyWhcNMjYwNTI0MTYyODUyWjAbMRkwFwYDVQQFExBmOTIwMDllODUzYjZiMDQ1MIICIjANBgkqhkiG9w0BAQEFAAOCAg8AMIICCgKCAgEAr7bHgiuxpwHsK7Qui8xUFmOr75gvMsd/dTEDDJdSSxtf6An7xyqpRR90PL2abxM1dEqlXnf2tqw1Ne4Xwl5jlRfdnJLmN0pTy/4lj4/7tv0Sk3iiKkypnEUtR6WfMgH0QZfKHM1+di+y9TFRtv6y//0rb+T+W8a9nsNL/ggjnar86461qO0rOs2cXjp3kOG1FEJ5MVmFmBGtnrKpa73XpXyTqRxB/M0n1n/W9nGqC4FSYa04T6N5RIZGBN2z2MT5IKGbFlbC8UrW0DxW7AYImQQcHtGl/m00QLVWutHQoVJYnFPlXTcHYvASLu+RhhsbDmxMgJJ0mcDpvsC4PjvB+TxywElgS70vE0XmLD+OJtvsBslHZvPBKCOdT0MS+tgSOIfga+z1Z1g7+DVagf7quvmag8jfPioyKvxnK/EgsTUVi2ghzq8wm27ud/mIM7AY2qEORR8Go3TVB4HzWQgpZrt3i5MIlCaY504LzSRiigHCzAPlHws+W0rB5N+er5/2pJKnfBSDiCiFAVtCLOZ7gLiMm0jhO2B6tUXHI/+MRPjy02i59lINMRRev56GKtcd9qO/0kUJWdZTdA2XoS82ixPvZtXQpUpuL12ab+9EaDK8Z4RHJYYfCT3Q5vNAXaiWQ+8PTWm2QgBR/bkwSWc+NpUFgNPN9PvQi8WEg5UmAGMCAwEAAaOBpjCBozAdBgNVHQ4EFgQUNmHhAHyIBQlRi0RsR/8aTMnqTxIwHwYDVR0jBBgwFoAUNmHhAHyIBQlRi0RsR/8aTMnqTxIwDwYDVR0TAQH/BAUwAwEB/zAOBgNVHQ8BAf8EBAMCAYYwQAYDVR0fBDkwNzA1oDOgMYYvaHR0cHM6Ly9hbmRyb2lkLmdvb2dsZWFwaXMuY29tL2F0dGVzdGF0aW9uL2NybC8wDQYJKoZIhvcNAQELBQADggIBACDIw41L3KlXG0aMiS//cqrG+EShHUGo8HNsw30W1kJtjn6UBwRM6jnmiwfBPb8VA91chb2vssAtX2zbTvqBJ9+LBPGCdw/E53Rbf86qhxKaiAHOjpvAy5Y3m00mqC0w/Zwvju1twb4vhLaJ5NkUJYsUS7rmJKHHBnETLi8GFqiEsqTWpG/6ibYCv7rYDBJDcR9W62BW9jfIoBQcxUCUJouMPH25lLNcDc1ssqvC2v7iUgI9LeoM1sNovqPmQUiG9rHli1vXxzCyaMTjwftkJLkf6724DFhuKug2jITV0QkXvaJWF4nUaHOTNA4uJU9WDvZLI1j83A+/xnAJUucIv/zGJ1AMH2boHqF8CY16LpsYgBt6tKxxWH00XcyDCdW2KlBCeqbQPcsFmWyWugxdcekhYsAWyoSf818NUsZdBWBaR/OukXrNLfkQ79IyZohZbvabO/X+MVT3rriAoKc8oE2Uws6DF+60PV7/WIPjNvXySdqspImSN78mflxDqwLqRBYkA3I75qppLGG9rp7UCdRjxMl8ZDBld+7yvHVgt1cVzJx9xnyGCC23UaicMDSXYrB4I4WHXPGjxhZuCuPBLTdOLU8YRvMYdEvYebWHMpvwGCF6bAx3JBpIeOQ1wDB5y0USicV3YgYGmi+NZfhA4URSh77Yd6uuJOJENRaNVTzk"
     include_bytes!("./__root_certs__/00E8FA196314D2FA18.cer"),
     // base64 equivalent: r"MIIFHDCCAwSgAwIBAgIJANUP8luj8tazMA0GCSqGSIb3DQEBCwUAMBsxGTAXBgNVBAUTEGY5MjAwOWU4NTNiNmIwNDUwHhcNMTkxMTIyMjAzNzU4WhcNMzQxMTE4MjAzNzU4WjAbMRkwFwYDVQQFExBmOTIwMDllODUzYjZiMDQ1MIICIjANBgkqhkiG9w0BAQEFAAOCAg8AMIICCgKCAgEAr7bHgiuxpwHsK7Qui8xUFmOr75gvMsd/dTEDDJdSSxtf6An7xyqpRR90PL2abxM1dEqlXnf2tqw1Ne4Xwl5jlRfdnJLmN0pTy/4lj4/7tv0Sk3iiKkypnEUtR6WfMgH0QZfKHM1+di+y9TFRtv6y//0rb+T+W8a9nsNL/ggjnar86461qO0rOs2cXjp3kOG1FEJ5MVmFmBGtnrKpa73XpXyTqRxB/M0n1n/W9nGqC4FSYa04T6N5RIZGBN2z2MT5IKGbFlbC8UrW0DxW7AYImQQcHtGl/m00QLVWutHQoVJYnFPlXTcHYvASLu+RhhsbDmxMgJJ0mcDpvsC4PjvB+TxywElgS70vE0XmLD+OJtvsBslHZvPBKCOdT0MS+tgSOIfga+z1Z1g7+DVagf7quvmag8jfPioyKvxnK/EgsTUVi2ghzq8wm27ud/mIM7AY2qEORR8Go3TVB4HzWQgpZrt3i5MIlCaY504LzSRiigHCzAPlHws+W0rB5N+er5/2pJKnfBSDiCiFAVtCLOZ7gLiMm0jhO2B6tUXHI/+MRPjy02i59lINMRRev56GKtcd9qO/0kUJWdZTdA2XoS82ixPvZtXQpUpuL12ab+9EaDK8Z4RHJYYfCT3Q5vNAXaiWQ+8PTWm2QgBR/bkwSWc+NpUFgNPN9PvQi8WEg5UmAGMCAwEAAaNjMGEwHQYDVR0OBBYEFDZh4QB8iAUJUYtEbEf/GkzJ6k8SMB8GA1UdIwQYMBaAFDZh4QB8iAUJUYtEbEf/GkzJ6k8SMA8GA1UdEwEB/wQFMAMBAf8wDgYDVR0PAQH/BAQDAgIEMA0GCSqGSIb3DQEBCwUAA4ICAQBOMaBc8oumXb2voc7XCWnuXKhBBK3e2KMGz39t7lA3XXRe2ZLLAkLM5y3J7tURkf5a1SutfdOyXAmeE6SRo83Uh6WszodmMkxK5GM4JGrnt4pBisu5igXEydaW7qq2CdC6DOGjG+mEkN8/TA6p3cnoL/sPyz6evdjLlSeJ8rFBH6xWyIZCbrcpYEJzXaUOEaxxXxgYz5/cTiVKN2M1G2okQBUIYSY6bjEL4aUN5cfo7ogP3UvliEo3Eo0YgwuzR2v0KR6C1cZqZJSTnghIC/vAD32KdNQ+c3N+vl2OTsUVMC1GiWkngNx1OO1+kXW+YTnnTUOtOIswUP/Vqd5SYgAImMAfY8U9/iIgkQj6T2W6FsScy94IN9fFhE1UtzmLoBIuUFsVXJMTz+Jucth+IqoWFua9v1R93/k98p41pjtFX+H8DslVgfP097vju4KDlqN64xV1grw3ZLl4CiOe/A91oeLm2UHOq6wn3esB4r2EIQKb6jTVGu5sYCcdWpXr0AUVqcABPdgL+H7qJguBw09ojm6xNIrw2OocrDKsudk/okr/AwqEyPKw9WnMlQgLIKw1rODG2NvU9oR3GVGdMkUBZutL8VuFkERQGt6vQ2OCw0sV47VMkuYbacK/xyZFiRcrPJPb41zgbQj9XAEyLKCHex0SdDrx+tWUDqG8At2JHA=="
@@ -357,7 +423,7 @@ mod tests {
 
     use super::{
         asn::KeyDescription, validate_certificate_chain, validate_certificate_chain_root,
-        CertificateChainInput, CertificateInput,
+        CertificateChainInput, CertificateInput, TRUSTED_ROOT_CERTS,
     };
 
     pub fn decode_certificate_chain(chain: &Vec<&str>) -> CertificateChainInput {
@@ -402,7 +468,7 @@ mod tests {
             SAMSUNG_KEY_CERT,
         ];
         let decoded_chain = decode_certificate_chain(&chain);
-        validate_certificate_chain_root(&decoded_chain)?;
+        validate_certificate_chain_root(&decoded_chain, TRUSTED_ROOT_CERTS)?;
         let (_, cert, _) = validate_certificate_chain(&decoded_chain)?;
         let key_description = extract_attestation(cert.extensions)?;
         match &key_description {
@@ -448,7 +514,8 @@ mod tests {
 
         for chain in chains {
             let decoded_chain = decode_certificate_chain(&chain);
-            validate_certificate_chain_root(&decoded_chain).expect("validating root failed");
+            validate_certificate_chain_root(&decoded_chain, TRUSTED_ROOT_CERTS)
+                .expect("validating root failed");
             let (_, cert, _) =
                 validate_certificate_chain(&decoded_chain).expect("validating chain failed");
             let key_description = extract_attestation(cert.extensions).map_err(|err| {
@@ -486,7 +553,8 @@ mod tests {
             PIXEL_KEY_CERT_INVALID,
         ];
         let decoded_chain = decode_certificate_chain(&chain);
-        validate_certificate_chain_root(&decoded_chain).expect("validating root failed");
+        validate_certificate_chain_root(&decoded_chain, TRUSTED_ROOT_CERTS)
+            .expect("validating root failed");
         let res = validate_certificate_chain(&decoded_chain);
         match res {
             Err(e) => assert_eq!(e, ValidationError::InvalidSignature),
@@ -504,7 +572,7 @@ mod tests {
             PIXEL_KEY_CERT_INVALID,
         ];
         let decoded_chain = decode_certificate_chain(&chain);
-        let res = validate_certificate_chain_root(&decoded_chain);
+        let res = validate_certificate_chain_root(&decoded_chain, TRUSTED_ROOT_CERTS);
         match res {
             Err(e) => assert_eq!(e, ValidationError::UntrustedRoot),
             _ => return Err(()),
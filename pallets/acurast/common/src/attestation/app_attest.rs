@@ -0,0 +1,244 @@
+use asn1::{oid, ObjectIdentifier, SequenceOf};
+use sha2::Digest;
+use sp_std::{prelude::*, vec};
+
+use super::{
+    asn::{AppAttestNonce, Extension, Validity},
+    cbor,
+    error::ValidationError,
+    validate_certificate_chain_slices, CertificateId, ECDSACurve, PublicKey, CHAIN_MAX_LENGTH,
+};
+
+/// The Apple App Attestation Root CA, as decoded bytes array. [Source](https://www.apple.com/certificateauthority/private/)
+pub const APPLE_APP_ATTESTATION_ROOT_CERT: &[u8] =
+    include_bytes!("../__root_certs__/0BF3BE0EF1CDD2E0FB8C6E721F621798.cer");
+
+/// The OID of the extension of the credential certificate containing the nonce.
+/// [See docs](https://developer.apple.com/documentation/devicecheck/validating_apps_that_connect_to_your_server#3576643)
+pub const APP_ATTEST_NONCE_OID: ObjectIdentifier = oid!(1, 2, 840, 113635, 100, 8, 2);
+
+const APP_ATTEST_FORMAT: &[u8] = b"apple-appattest";
+const AAGUID_DEVELOPMENT: &[u8] = b"appattestdevelop";
+const AAGUID_PRODUCTION: &[u8] = b"appattest\0\0\0\0\0\0\0";
+
+/// The App Attest environment a key was generated in, as announced by the AAGUID of the authenticator data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    Development,
+    Production,
+}
+
+/// The relevant content of a validated App Attest attestation object.
+pub struct AppAttestation {
+    /// The ids of the certificates in the chain, starting with the root.
+    pub cert_ids: Vec<CertificateId>,
+    /// The validity of the credential certificate.
+    pub validity: Validity,
+    /// The sign counter of the authenticator data.
+    pub counter: u32,
+    pub environment: Environment,
+}
+
+/// The fields of the [authenticator data](https://www.w3.org/TR/webauthn/#sctn-authenticator-data) relevant for App Attest.
+struct AuthenticatorData<'a> {
+    rp_id_hash: &'a [u8],
+    counter: u32,
+    aaguid: &'a [u8],
+    credential_id: &'a [u8],
+}
+
+impl<'a> AuthenticatorData<'a> {
+    fn parse(data: &'a [u8]) -> Option<Self> {
+        // rpIdHash (32) | flags (1) | signCount (4) | aaguid (16) | credentialIdLength (2) | credentialId
+        let rp_id_hash = data.get(0..32)?;
+        let counter = u32::from_be_bytes(data.get(33..37)?.try_into().ok()?);
+        let aaguid = data.get(37..53)?;
+        let credential_id_length = u16::from_be_bytes(data.get(53..55)?.try_into().ok()?) as usize;
+        let credential_id = data.get(55..55 + credential_id_length)?;
+        Some(Self {
+            rp_id_hash,
+            counter,
+            aaguid,
+            credential_id,
+        })
+    }
+}
+
+/// Validates an App Attest attestation object as returned by `DCAppAttestService.attestKey`, following the
+/// [steps documented by Apple](https://developer.apple.com/documentation/devicecheck/validating_apps_that_connect_to_your_server#3576643):
+///
+/// - the `x5c` certificates form a valid chain starting at `root_cert`
+/// - the credential certificate contains the nonce `sha256(authData || client_data_hash)`
+/// - the sha256 hash of the credential certificate's P-256 public key equals `key_id`
+/// - the authenticator data is bound to `app_id`, has a zero counter, a known AAGUID and `key_id` as credential id
+pub fn validate_app_attestation(
+    attestation_object: &[u8],
+    root_cert: &[u8],
+    key_id: &[u8; 32],
+    app_id: &[u8],
+    client_data_hash: &[u8],
+) -> Result<AppAttestation, ValidationError> {
+    let object =
+        cbor::decode(attestation_object).ok_or(ValidationError::InvalidAppAttestationObject)?;
+    let format = object
+        .get(b"fmt")
+        .and_then(|value| value.as_text())
+        .ok_or(ValidationError::InvalidAppAttestationObject)?;
+    if format != APP_ATTEST_FORMAT {
+        return Err(ValidationError::UnsupportedAppAttestationFormat);
+    }
+    let certificates = object
+        .get(b"attStmt")
+        .and_then(|statement| statement.get(b"x5c"))
+        .and_then(|value| value.as_array())
+        .ok_or(ValidationError::InvalidAppAttestationObject)?;
+    let auth_data = object
+        .get(b"authData")
+        .and_then(|value| value.as_bytes())
+        .ok_or(ValidationError::InvalidAppAttestationObject)?;
+
+    if certificates.is_empty() {
+        return Err(ValidationError::ChainTooShort);
+    }
+    if certificates.len() >= CHAIN_MAX_LENGTH as usize {
+        return Err(ValidationError::ChainTooLong);
+    }
+    // x5c starts with the credential certificate, the chain is validated starting from the root
+    let mut chain = vec![root_cert];
+    for certificate in certificates.iter().rev() {
+        chain.push(
+            certificate
+                .as_bytes()
+                .ok_or(ValidationError::InvalidAppAttestationObject)?,
+        );
+    }
+    let (cert_ids, cert, public_key) = validate_certificate_chain_slices(&chain)?;
+
+    let mut hasher = sha2::Sha256::new();
+    hasher.update(auth_data);
+    hasher.update(client_data_hash);
+    let nonce = hasher.finalize();
+    if extract_nonce(cert.extensions)? != nonce.as_slice() {
+        return Err(ValidationError::AppAttestationNonceMismatch);
+    }
+
+    if !matches!(public_key, PublicKey::ECDSA(ECDSACurve::CurveP256(_))) {
+        return Err(ValidationError::UnsupportedPublicKeyAlgorithm);
+    }
+    let public_key_hash =
+        sha2::Sha256::digest(cert.subject_public_key_info.subject_public_key.as_bytes());
+    if public_key_hash.as_slice() != key_id {
+        return Err(ValidationError::AppAttestationKeyIdMismatch);
+    }
+
+    let auth_data =
+        AuthenticatorData::parse(auth_data).ok_or(ValidationError::InvalidAuthenticatorData)?;
+    if auth_data.rp_id_hash != sha2::Sha256::digest(app_id).as_slice() {
+        return Err(ValidationError::AppIdMismatch);
+    }
+    if auth_data.counter != 0 {
+        return Err(ValidationError::InvalidAuthenticatorData);
+    }
+    let environment = match auth_data.aaguid {
+        AAGUID_DEVELOPMENT => Environment::Development,
+        AAGUID_PRODUCTION => Environment::Production,
+        _ => return Err(ValidationError::InvalidAuthenticatorData),
+    };
+    if auth_data.credential_id != key_id {
+        return Err(ValidationError::AppAttestationKeyIdMismatch);
+    }
+
+    Ok(AppAttestation {
+        cert_ids,
+        validity: cert.validity,
+        counter: auth_data.counter,
+        environment,
+    })
+}
+
+/// Extracts the nonce from the extension field of an App Attest credential certificate.
+fn extract_nonce<'a>(
+    extensions: Option<SequenceOf<'a, Extension<'a>>>,
+) -> Result<&'a [u8], ValidationError> {
+    let extension = extensions
+        .ok_or(ValidationError::ExtensionMissing)?
+        .find(|e| e.extn_id == APP_ATTEST_NONCE_OID)
+        .ok_or(ValidationError::ExtensionMissing)?;
+
+    let parsed = asn1::parse_single::<AppAttestNonce>(extension.extn_value)
+        .map_err(|_| ValidationError::ParseExtension)?;
+    parsed.nonce.ok_or(ValidationError::ParseExtension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // test root and attestation object following the App Attest format, generated for `APP_ID`,
+    // the key `KEY_ID` and the client data hash `sha256([0, 1, ..., 31])`
+    const TEST_ROOT_CERT: &str = r"MIIBuzCCAUGgAwIBAgIBATAKBggqhkjOPQQDAzA+MSUwIwYDVQQDDBxUZXN0IEFwcCBBdHRlc3RhdGlvbiBSb290IENBMRUwEwYDVQQKDAxBY3VyYXN0IFRlc3QwHhcNMjMwMTAxMDAwMDAwWhcNNDMwMTAxMDAwMDAwWjA+MSUwIwYDVQQDDBxUZXN0IEFwcCBBdHRlc3RhdGlvbiBSb290IENBMRUwEwYDVQQKDAxBY3VyYXN0IFRlc3QwdjAQBgcqhkjOPQIBBgUrgQQAIgNiAAT3uzH1Fpg8F5N2/wj5zom5M8jS8iNYpv/ZBTVE/iohyI0+nwU/6F9JtmG5YU/anrqWjUpVxo5mVQM5y0LnVPcTmmQQ99kE78V1jfXkpamxFaTBLhJ0TlCv3CuOFWBn+sqjEzARMA8GA1UdEwEB/wQFMAMBAf8wCgYIKoZIzj0EAwMDaAAwZQIxAIxaaGsDO1tqspmu8NCapIoOWHbwdQJlL2cEpw/kHcW8P6ykuQNPS1n+RD49aunZsgIweETawCx+mW/G6EMwmGD0GpRdRGpuDX0esjYVIcCypOGcSywvt8jWvB1XogQ6Q1+U";
+    const TEST_ATTESTATION_OBJECT: &str = r"o2NmbXRvYXBwbGUtYXBwYXR0ZXN0Z2F0dFN0bXSiY3g1Y4JZAa4wggGqMIIBMaADAgECAgEDMAoGCCqGSM49BAMCMDsxIjAgBgNVBAMMGVRlc3QgQXBwIEF0dGVzdGF0aW9uIENBIDExFTATBgNVBAoMDEFjdXJhc3QgVGVzdDAeFw0yMzAxMDEwMDAwMDBaFw00MzAxMDEwMDAwMDBaMCoxETAPBgNVBAMMCHRlc3Qga2V5MRUwEwYDVQQKDAxBY3VyYXN0IFRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAASFcOldhYJShtuSx4MXZ5vdj/48kNCvhCkb9kEytm/Mmckm8IchLXWx9NvF1JmbTFYFrfZtuAGk3jcc2tOevFXlozcwNTAzBgkqhkiG92NkCAIEJjAkoSIEIFLAe1mtAG+lZdKvjQm5TIJLZ76HYDzBo4RFnMpmZnIhMAoGCCqGSM49BAMCA2cAMGQCMESlxPIQW+MHgUy8ytZwoj2iF+HfqK20SKcuW7HPfWIFahYe4+/XwB75SuJOCQbiiQIwc27VODFfCrj/q6UjPzr08y7ZQH70n/qttEbOI7NzX/ennUR97d/C8bd02bbjXJ7rWQG/MIIBuzCCAUGgAwIBAgIBAjAKBggqhkjOPQQDAzA+MSUwIwYDVQQDDBxUZXN0IEFwcCBBdHRlc3RhdGlvbiBSb290IENBMRUwEwYDVQQKDAxBY3VyYXN0IFRlc3QwHhcNMjMwMTAxMDAwMDAwWhcNNDMwMTAxMDAwMDAwWjA7MSIwIAYDVQQDDBlUZXN0IEFwcCBBdHRlc3RhdGlvbiBDQSAxMRUwEwYDVQQKDAxBY3VyYXN0IFRlc3QwdjAQBgcqhkjOPQIBBgUrgQQAIgNiAATcV2p+4ED9InhiSyaFMs5sfqrH3IK4He45I0EqWgw3EXVKvIzXRgQu3NRPdyX2XdbMai26ubK1od9DXZ/KhWgS75KGvlDbC2Kr0q+CGgpHAaqi5wFu/ZL2YE9J6xBD0wGjFjAUMBIGA1UdEwEB/wQIMAYBAf8CAQAwCgYIKoZIzj0EAwMDaAAwZQIxAJNHrEpqbzVVzeyafco7mcUoKLBUlxbq7dZVZ2PTVUHVYNYsDW+O8EKThqobYdlUZQIwaGmppkA9/lTHMFiCkY/WaCVkkxv4R46CRXmwek9lyefRDKjFJGYyX+I/MwqUWy4pZ3JlY2VpcHRHcmVjZWlwdGhhdXRoRGF0YVhcUVsUeTLSxR2vw51NRqtetKo+ODY6OBtf9kQh0SCfzVNAAAAAAGFwcGF0dGVzdGRldmVsb3AAIFiQofiI1n2+Rj3KpvHHuor2EoT2w7CjwxCR4TwU+MH8pQECAyY=";
+    const APP_ID: &[u8] = b"TEAMID1234.com.acurast.processor";
+    const KEY_ID: [u8; 32] = [
+        88, 144, 161, 248, 136, 214, 125, 190, 70, 61, 202, 166, 241, 199, 186, 138, 246, 18, 132,
+        246, 195, 176, 163, 195, 16, 145, 225, 60, 20, 248, 193, 252,
+    ];
+
+    fn client_data_hash() -> Vec<u8> {
+        sha2::Sha256::digest((0u8..32).collect::<Vec<u8>>()).to_vec()
+    }
+
+    #[test]
+    fn test_validate_app_attestation() {
+        let root = base64::decode(TEST_ROOT_CERT).unwrap();
+        let object = base64::decode(TEST_ATTESTATION_OBJECT).unwrap();
+
+        let attestation =
+            validate_app_attestation(&object, &root, &KEY_ID, APP_ID, &client_data_hash()).unwrap();
+
+        assert_eq!(attestation.cert_ids.len(), 3);
+        assert_eq!(attestation.counter, 0);
+        assert_eq!(attestation.environment, Environment::Development);
+    }
+
+    #[test]
+    fn test_validate_app_attestation_failure() {
+        let root = base64::decode(TEST_ROOT_CERT).unwrap();
+        let object = base64::decode(TEST_ATTESTATION_OBJECT).unwrap();
+
+        // the attestation is bound to the client data hash
+        assert_eq!(
+            validate_app_attestation(&object, &root, &KEY_ID, APP_ID, &[0u8; 32]).err(),
+            Some(ValidationError::AppAttestationNonceMismatch)
+        );
+        assert_eq!(
+            validate_app_attestation(&object, &root, &[0u8; 32], APP_ID, &client_data_hash()).err(),
+            Some(ValidationError::AppAttestationKeyIdMismatch)
+        );
+        assert_eq!(
+            validate_app_attestation(&object, &root, &KEY_ID, b"other.app", &client_data_hash())
+                .err(),
+            Some(ValidationError::AppIdMismatch)
+        );
+        // the chain does not lead up to the Apple root
+        assert!(validate_app_attestation(
+            &object,
+            APPLE_APP_ATTESTATION_ROOT_CERT,
+            &KEY_ID,
+            APP_ID,
+            &client_data_hash()
+        )
+        .is_err());
+        assert_eq!(
+            validate_app_attestation(
+                &object[..object.len() - 1],
+                &root,
+                &KEY_ID,
+                APP_ID,
+                &client_data_hash()
+            )
+            .err(),
+            Some(ValidationError::InvalidAppAttestationObject)
+        );
+    }
+}
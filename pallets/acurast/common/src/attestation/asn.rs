@@ -592,6 +592,13 @@ pub struct AttestationPackageInfo<'a> {
     pub version: i64,
 }
 
+/// The content of the App Attest nonce extension (OID `1.2.840.113635.100.8.2`) of a credential certificate.
+#[derive(asn1::Asn1Read, asn1::Asn1Write)]
+pub struct AppAttestNonce<'a> {
+    #[explicit(1)]
+    pub nonce: Option<&'a [u8]>,
+}
+
 /// One of Verified (0),
 /// SelfSigned (1),
 /// Unverified (2),
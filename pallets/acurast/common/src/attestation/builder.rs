@@ -0,0 +1,683 @@
+//! Builders for [Attestation] values and matching certificate chains, e.g. for genesis configurations and tests.
+//!
+//! [AttestationBuilder::build] creates an attestation as it is stored after validating a key attestation, while
+//! [AttestationBuilder::certificate_chain] creates a certificate chain attesting the same properties. The chain is
+//! signed by a [TestCertificateAuthority], whose root certificate has to be trusted to accept the chain.
+
+use asn1::{oid, BitString, Enumerated, ObjectIdentifier};
+use p256::ecdsa::{signature::Signer, Signature, SigningKey, VerifyingKey};
+use sp_std::prelude::*;
+
+use super::{
+    asn::{
+        AlgorithmIdentifier, AttestationApplicationId, AttestationPackageInfo,
+        AttributeTypeAndValue, AuthorizationListKeyMint, Certificate, Extension,
+        KeyDescriptionKeyMint, RootOfTrust, SubjectPublicKeyInfo, TBSCertificate, Validity,
+    },
+    CertificateChainInput, CertificateInput, CURVE_P256, ECDSA_PBK, ECDSA_WITH_SHA256_ALGORITHM,
+    KEY_ATTESTATION_OID,
+};
+use crate::{
+    Attestation, AttestationContent, AttestationSecurityLevel, AttestationValidity,
+    BoundedAttestationApplicationId, BoundedAttestationPackageInfo, BoundedAuthorizationList,
+    BoundedKeyDescription, BoundedRootOfTrust, ValidatingCertIds, VerifiedBootState,
+};
+
+const COMMON_NAME_OID: ObjectIdentifier = oid!(2, 5, 4, 3);
+/// The KeyMint version of the attestation extension in generated certificate chains.
+const KEY_MINT_VERSION: i64 = 200;
+
+/// Builds an Android key [Attestation] and, given a [TestCertificateAuthority], a certificate chain attesting it.
+///
+/// Defaults to a key generated in a trusted execution environment of a device with a locked bootloader and verified
+/// boot, valid until 1.1.2100.
+#[derive(Clone)]
+pub struct AttestationBuilder {
+    key_description: BoundedKeyDescription,
+    validity: AttestationValidity,
+}
+
+impl Default for AttestationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AttestationBuilder {
+    pub fn new() -> Self {
+        Self {
+            key_description: BoundedKeyDescription {
+                attestation_security_level: AttestationSecurityLevel::TrustedEnvironemnt,
+                key_mint_security_level: AttestationSecurityLevel::TrustedEnvironemnt,
+                software_enforced: empty_authorization_list(),
+                tee_enforced: BoundedAuthorizationList {
+                    root_of_trust: Some(root_of_trust(VerifiedBootState::Verified, true)),
+                    ..empty_authorization_list()
+                },
+            },
+            validity: AttestationValidity {
+                not_before: 0,
+                not_after: 4_102_441_200_000, // 1.1.2100
+            },
+        }
+    }
+
+    /// Sets both the attestation and the KeyMint security level.
+    pub fn security_level(self, security_level: AttestationSecurityLevel) -> Self {
+        self.attestation_security_level(security_level.clone())
+            .key_mint_security_level(security_level)
+    }
+
+    pub fn attestation_security_level(mut self, security_level: AttestationSecurityLevel) -> Self {
+        self.key_description.attestation_security_level = security_level;
+        self
+    }
+
+    pub fn key_mint_security_level(mut self, security_level: AttestationSecurityLevel) -> Self {
+        self.key_description.key_mint_security_level = security_level;
+        self
+    }
+
+    /// Sets the hardware enforced root of trust reporting the `verified_boot_state` and whether the bootloader is locked.
+    pub fn boot_state(
+        mut self,
+        verified_boot_state: VerifiedBootState,
+        device_locked: bool,
+    ) -> Self {
+        self.key_description.tee_enforced.root_of_trust =
+            Some(root_of_trust(verified_boot_state, device_locked));
+        self
+    }
+
+    /// Removes the hardware enforced root of trust.
+    pub fn without_root_of_trust(mut self) -> Self {
+        self.key_description.tee_enforced.root_of_trust = None;
+        self
+    }
+
+    /// Sets the hardware enforced `os_patch_level` in the format `YYYYMM`.
+    pub fn os_patch_level(mut self, os_patch_level: u32) -> Self {
+        self.key_description.tee_enforced.os_patch_level = Some(os_patch_level);
+        self
+    }
+
+    /// Sets the hardware enforced `vendor_patch_level` in the format `YYYYMMDD`.
+    pub fn vendor_patch_level(mut self, vendor_patch_level: u32) -> Self {
+        self.key_description.tee_enforced.vendor_patch_level = Some(vendor_patch_level);
+        self
+    }
+
+    /// Adds a package to the software enforced attestation application id.
+    ///
+    /// Packages and digests are encoded as DER sets in certificate chains, so add them in DER order to get the same
+    /// order when extracting them from a chain. Panics if the package name or the number of packages exceed their bounds.
+    pub fn package(mut self, package_name: &[u8], version: i64) -> Self {
+        self.application_id()
+            .package_infos
+            .try_push(BoundedAttestationPackageInfo {
+                package_name: package_name
+                    .to_vec()
+                    .try_into()
+                    .expect("package name too long"),
+                version,
+            })
+            .expect("too many packages");
+        self
+    }
+
+    /// Adds a signing certificate digest to the software enforced attestation application id.
+    ///
+    /// Panics if the digest or the number of digests exceed their bounds.
+    pub fn signature_digest(mut self, digest: &[u8]) -> Self {
+        self.application_id()
+            .signature_digests
+            .try_push(digest.to_vec().try_into().expect("digest too long"))
+            .expect("too many signature digests");
+        self
+    }
+
+    /// Sets the software enforced `creation_date_time` in milliseconds.
+    pub fn creation_date_time(mut self, timestamp: u64) -> Self {
+        self.key_description.software_enforced.creation_date_time = Some(timestamp);
+        self
+    }
+
+    /// Sets the hardware enforced `usage_expire_date_time` in milliseconds.
+    pub fn usage_expire_date_time(mut self, timestamp: u64) -> Self {
+        self.key_description.tee_enforced.usage_expire_date_time = Some(timestamp);
+        self
+    }
+
+    /// Sets the validity of the attested key's certificate in milliseconds.
+    ///
+    /// Certificates only encode seconds, the milliseconds are truncated in chains created by [Self::certificate_chain].
+    pub fn validity(mut self, not_before: u64, not_after: u64) -> Self {
+        self.validity = AttestationValidity {
+            not_before,
+            not_after,
+        };
+        self
+    }
+
+    /// Returns the attestation without certificate ids, as no chain is involved.
+    pub fn build(&self) -> Attestation {
+        Attestation {
+            cert_ids: ValidatingCertIds::default(),
+            content: AttestationContent::KeyDescription(self.key_description.clone()),
+            validity: self.validity,
+        }
+    }
+
+    /// Returns a certificate chain starting with the root certificate of `authority` and ending with a certificate for
+    /// the key `key_seed`, attesting the properties of this builder.
+    ///
+    /// Validating the chain results in the attestation returned by [Self::build], with the certificate ids of the chain.
+    /// Panics if `key_seed` is not a valid P-256 secret key.
+    pub fn certificate_chain(
+        &self,
+        authority: &TestCertificateAuthority,
+        key_seed: [u8; 32],
+    ) -> CertificateChainInput {
+        let key = SigningKey::from_bytes(&key_seed).expect("invalid key seed");
+        let key_description = self.encode_key_description();
+        let leaf = encode_certificate(
+            2,
+            &authority.name,
+            "Android Keystore Key",
+            &self.validity,
+            &key.verifying_key(),
+            &authority.signing_key,
+            Some(&key_description),
+        );
+
+        vec![authority.certificate.clone(), leaf]
+            .try_into()
+            .expect("chain too long")
+    }
+
+    fn application_id(&mut self) -> &mut BoundedAttestationApplicationId {
+        self.key_description
+            .software_enforced
+            .attestation_application_id
+            .get_or_insert_with(|| BoundedAttestationApplicationId {
+                package_infos: Default::default(),
+                signature_digests: Default::default(),
+            })
+    }
+
+    fn encode_key_description(&self) -> Vec<u8> {
+        let software_enforced = &self.key_description.software_enforced;
+        let tee_enforced = &self.key_description.tee_enforced;
+        let application_id = software_enforced
+            .attestation_application_id
+            .as_ref()
+            .map(encode_application_id);
+
+        asn1::write_single(&KeyDescriptionKeyMint {
+            attestation_version: KEY_MINT_VERSION,
+            attestation_security_level: encode_security_level(
+                &self.key_description.attestation_security_level,
+            ),
+            key_mint_version: KEY_MINT_VERSION,
+            key_mint_security_level: encode_security_level(
+                &self.key_description.key_mint_security_level,
+            ),
+            attestation_challenge: &[],
+            unique_id: &[],
+            software_enforced: encode_authorization_list(
+                software_enforced,
+                application_id.as_deref(),
+            ),
+            tee_enforced: encode_authorization_list(tee_enforced, None),
+        })
+        .expect("encoding key description failed")
+    }
+}
+
+/// A certificate authority with a self-signed P-256 root certificate, signing the chains of [AttestationBuilder].
+///
+/// Its root certificate has to be injected as trusted root, e.g. using the `trusted_root_certificates` of the genesis
+/// config of `pallet_acurast`.
+pub struct TestCertificateAuthority {
+    name: String,
+    signing_key: SigningKey,
+    certificate: CertificateInput,
+}
+
+impl TestCertificateAuthority {
+    /// Creates an authority named `name` with the key `seed`.
+    ///
+    /// Panics if `seed` is not a valid P-256 secret key.
+    pub fn new(name: &str, seed: [u8; 32]) -> Self {
+        let signing_key = SigningKey::from_bytes(&seed).expect("invalid key seed");
+        let certificate = encode_certificate(
+            1,
+            name,
+            name,
+            &AttestationValidity {
+                not_before: 0,
+                not_after: 4_102_441_200_000, // 1.1.2100
+            },
+            &signing_key.verifying_key(),
+            &signing_key,
+            None,
+        );
+        Self {
+            name: name.into(),
+            signing_key,
+            certificate,
+        }
+    }
+
+    /// Returns the DER encoded self-signed root certificate.
+    pub fn root_certificate(&self) -> &CertificateInput {
+        &self.certificate
+    }
+}
+
+fn empty_authorization_list() -> BoundedAuthorizationList {
+    // flags are parsed as `Some(false)` from KeyMint attestations if not present
+    BoundedAuthorizationList {
+        purpose: None,
+        algorithm: None,
+        key_size: None,
+        digest: None,
+        padding: None,
+        ec_curve: None,
+        rsa_public_exponent: None,
+        mgf_digest: None,
+        rollback_resistance: Some(false),
+        early_boot_only: Some(false),
+        active_date_time: None,
+        origination_expire_date_time: None,
+        usage_expire_date_time: None,
+        usage_count_limit: None,
+        no_auth_required: false,
+        user_auth_type: None,
+        auth_timeout: None,
+        allow_while_on_body: false,
+        trusted_user_presence_required: Some(false),
+        trusted_confirmation_required: Some(false),
+        unlocked_device_required: Some(false),
+        all_applications: None,
+        application_id: None,
+        creation_date_time: None,
+        origin: None,
+        root_of_trust: None,
+        os_version: None,
+        os_patch_level: None,
+        attestation_application_id: None,
+        attestation_id_brand: None,
+        attestation_id_device: None,
+        attestation_id_product: None,
+        attestation_id_serial: None,
+        attestation_id_imei: None,
+        attestation_id_meid: None,
+        attestation_id_manufacturer: None,
+        attestation_id_model: None,
+        vendor_patch_level: None,
+        boot_patch_level: None,
+        device_unique_attestation: Some(false),
+    }
+}
+
+fn root_of_trust(
+    verified_boot_state: VerifiedBootState,
+    device_locked: bool,
+) -> BoundedRootOfTrust {
+    BoundedRootOfTrust {
+        verified_boot_key: [0u8; 32].to_vec().try_into().unwrap(),
+        device_locked,
+        verified_boot_state,
+        verified_boot_hash: Some([0u8; 32].to_vec().try_into().unwrap()),
+    }
+}
+
+/// Encodes the properties settable with [AttestationBuilder], all others are omitted.
+fn encode_authorization_list<'a>(
+    list: &'a BoundedAuthorizationList,
+    attestation_application_id: Option<&'a [u8]>,
+) -> AuthorizationListKeyMint<'a> {
+    AuthorizationListKeyMint {
+        purpose: None,
+        algorithm: None,
+        key_size: None,
+        digest: None,
+        padding: None,
+        ec_curve: None,
+        rsa_public_exponent: None,
+        mgf_digest: None,
+        rollback_resistance: None,
+        early_boot_only: None,
+        active_date_time: None,
+        origination_expire_date_time: None,
+        usage_expire_date_time: list.usage_expire_date_time.map(|v| v as i64),
+        usage_count_limit: None,
+        no_auth_required: None,
+        user_auth_type: None,
+        auth_timeout: None,
+        allow_while_on_body: None,
+        trusted_user_presence_required: None,
+        trusted_confirmation_required: None,
+        unlocked_device_required: None,
+        creation_date_time: list.creation_date_time.map(|v| v as i64),
+        origin: None,
+        root_of_trust: list
+            .root_of_trust
+            .as_ref()
+            .map(|root_of_trust| RootOfTrust {
+                verified_boot_key: &root_of_trust.verified_boot_key,
+                device_locked: root_of_trust.device_locked,
+                verified_boot_state: encode_verified_boot_state(&root_of_trust.verified_boot_state),
+                verified_boot_hash: root_of_trust
+                    .verified_boot_hash
+                    .as_ref()
+                    .map_or(&[], |hash| hash.as_slice()),
+            }),
+        os_version: None,
+        os_patch_level: list.os_patch_level.map(|v| v as i64),
+        attestation_application_id,
+        attestation_id_brand: None,
+        attestation_id_device: None,
+        attestation_id_product: None,
+        attestation_id_serial: None,
+        attestation_id_imei: None,
+        attestation_id_meid: None,
+        attestation_id_manufacturer: None,
+        attestation_id_model: None,
+        vendor_patch_level: list.vendor_patch_level.map(|v| v as i64),
+        boot_patch_level: None,
+        device_unique_attestation: None,
+    }
+}
+
+fn encode_application_id(application_id: &BoundedAttestationApplicationId) -> Vec<u8> {
+    let package_infos = asn1::write_single(&asn1::SetOfWriter::new(
+        application_id
+            .package_infos
+            .iter()
+            .map(|info| AttestationPackageInfo {
+                package_name: &info.package_name,
+                version: info.version,
+            })
+            .collect::<Vec<_>>(),
+    ))
+    .expect("encoding package infos failed");
+    let signature_digests = asn1::write_single(&asn1::SetOfWriter::new(
+        application_id
+            .signature_digests
+            .iter()
+            .map(|digest| digest.as_slice())
+            .collect::<Vec<_>>(),
+    ))
+    .expect("encoding signature digests failed");
+
+    asn1::write_single(&AttestationApplicationId {
+        package_infos: parse(&package_infos),
+        signature_digests: parse(&signature_digests),
+    })
+    .expect("encoding attestation application id failed")
+}
+
+fn encode_security_level(security_level: &AttestationSecurityLevel) -> Enumerated {
+    Enumerated::new(match security_level {
+        AttestationSecurityLevel::Software => 0,
+        AttestationSecurityLevel::TrustedEnvironemnt => 1,
+        AttestationSecurityLevel::StrongBox => 2,
+        AttestationSecurityLevel::Unknown => 3,
+    })
+}
+
+fn encode_verified_boot_state(verified_boot_state: &VerifiedBootState) -> Enumerated {
+    Enumerated::new(match verified_boot_state {
+        VerifiedBootState::Verified => 0,
+        VerifiedBootState::SelfSigned => 1,
+        VerifiedBootState::Unverified => 2,
+        VerifiedBootState::Failed => 3,
+    })
+}
+
+/// Encodes an X.509 v3 certificate for `subject_key`, signed by `issuer_key` using ECDSA with SHA-256.
+fn encode_certificate(
+    serial_number: u8,
+    issuer: &str,
+    subject: &str,
+    validity: &AttestationValidity,
+    subject_key: &VerifyingKey,
+    issuer_key: &SigningKey,
+    key_description: Option<&[u8]>,
+) -> CertificateInput {
+    let serial_number = [serial_number];
+    let issuer = encode_name(issuer);
+    let subject = encode_name(subject);
+    let not_before = encode_time(validity.not_before);
+    let not_after = encode_time(validity.not_after);
+    let curve = asn1::write_single(&CURVE_P256).expect("encoding curve failed");
+    let public_key = subject_key.to_encoded_point(false);
+    let extensions = key_description.map(|extn_value| {
+        asn1::write_single(&asn1::SequenceOfWriter::new(vec![Extension {
+            extn_id: KEY_ATTESTATION_OID,
+            critical: false,
+            extn_value,
+        }]))
+        .expect("encoding extensions failed")
+    });
+
+    let signature_algorithm = AlgorithmIdentifier {
+        algorithm: ECDSA_WITH_SHA256_ALGORITHM,
+        parameters: None,
+    };
+    let tbs_certificate = TBSCertificate {
+        version: 2,
+        serial_number: asn1::BigUint::new(&serial_number).expect("invalid serial number"),
+        signature: signature_algorithm.clone(),
+        issuer: parse(&issuer),
+        validity: Validity {
+            not_before: parse(&not_before),
+            not_after: parse(&not_after),
+        },
+        subject: parse(&subject),
+        subject_public_key_info: SubjectPublicKeyInfo {
+            algorithm: AlgorithmIdentifier {
+                algorithm: ECDSA_PBK,
+                parameters: Some(parse(&curve)),
+            },
+            subject_public_key: BitString::new(public_key.as_bytes(), 0)
+                .expect("encoding public key failed"),
+        },
+        issuer_unique_id: None,
+        subject_unique_id: None,
+        extensions: extensions.as_ref().map(|extensions| parse(extensions)),
+    };
+
+    let payload = asn1::write_single(&tbs_certificate).expect("encoding certificate failed");
+    let signature: Signature = issuer_key.sign(&payload);
+    let signature = signature.to_der();
+
+    asn1::write_single(&Certificate {
+        tbs_certificate,
+        signature_algorithm,
+        signature_value: BitString::new(signature.as_bytes(), 0)
+            .expect("encoding signature failed"),
+    })
+    .expect("encoding certificate failed")
+    .try_into()
+    .expect("certificate too long")
+}
+
+/// Encodes a distinguished name consisting of a single common name.
+fn encode_name(common_name: &str) -> Vec<u8> {
+    let value = asn1::write_single(&asn1::Utf8String::new(common_name))
+        .expect("encoding common name failed");
+
+    asn1::write_single(&asn1::SequenceOfWriter::new(vec![asn1::SetOfWriter::new(
+        vec![AttributeTypeAndValue {
+            typ: COMMON_NAME_OID,
+            value: parse(&value),
+        }],
+    )]))
+    .expect("encoding name failed")
+}
+
+/// Encodes the `timestamp` in milliseconds as DER encoded `GeneralizedTime`, truncating the milliseconds.
+fn encode_time(timestamp: u64) -> Vec<u8> {
+    let seconds = timestamp / 1000;
+    let (year, month, day) = civil_from_days(seconds / 86_400);
+    let second_of_day = seconds % 86_400;
+    let time = format!(
+        "{:04}{:02}{:02}{:02}{:02}{:02}Z",
+        year,
+        month,
+        day,
+        second_of_day / 3600,
+        second_of_day % 3600 / 60,
+        second_of_day % 60
+    );
+
+    [&[0x18, time.len() as u8][..], time.as_bytes()].concat()
+}
+
+/// Converts days since 1.1.1970 to a `(year, month, day)` date of the proleptic Gregorian calendar.
+///
+/// See [chrono-Compatible Low-Level Date Algorithms](http://howardhinnant.github.io/date_algorithms.html#civil_from_days).
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400;
+
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+fn parse<'a, T: asn1::Asn1Readable<'a>>(data: &'a [u8]) -> T {
+    asn1::parse_single(data).expect("parsing encoded value failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        attestation::{error::ValidationError, extract_attestation},
+        validate_certificate_chain, validate_certificate_chain_root,
+    };
+
+    const AUTHORITY_SEED: [u8; 32] = [1u8; 32];
+    const KEY_SEED: [u8; 32] = [2u8; 32];
+
+    fn extract(chain: &CertificateChainInput) -> Attestation {
+        let (cert_ids, cert, _) = validate_certificate_chain(chain).unwrap();
+        Attestation {
+            cert_ids: cert_ids
+                .into_iter()
+                .map(|(issuer, serial_number)| {
+                    (
+                        issuer.try_into().unwrap(),
+                        serial_number.try_into().unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            content: AttestationContent::KeyDescription(
+                extract_attestation(cert.extensions)
+                    .unwrap()
+                    .try_into()
+                    .unwrap(),
+            ),
+            validity: AttestationValidity {
+                not_before: cert.validity.not_before.timestamp_millis(),
+                not_after: cert.validity.not_after.timestamp_millis(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_certificate_chain() {
+        let authority = TestCertificateAuthority::new("Acurast Test Root", AUTHORITY_SEED);
+        let builder = AttestationBuilder::new()
+            .security_level(AttestationSecurityLevel::StrongBox)
+            .boot_state(VerifiedBootState::SelfSigned, false)
+            .os_patch_level(202306)
+            .vendor_patch_level(20230605)
+            .package(b"com.acurast.attested.executor", 42)
+            .signature_digest(&[3u8; 32])
+            .creation_date_time(1_672_527_600_000)
+            .usage_expire_date_time(1_767_222_000_000)
+            .validity(1_672_527_600_000, 1_767_222_000_000);
+        let chain = builder.certificate_chain(&authority, KEY_SEED);
+
+        assert_eq!(
+            Ok(()),
+            validate_certificate_chain_root(&chain, &[authority.root_certificate().as_slice()])
+        );
+        let attestation = extract(&chain);
+        assert_eq!(2, attestation.cert_ids.len());
+        assert_eq!(
+            Attestation {
+                cert_ids: attestation.cert_ids.clone(),
+                ..builder.build()
+            },
+            attestation
+        );
+    }
+
+    #[test]
+    fn test_certificate_chain_default() {
+        let authority = TestCertificateAuthority::new("Acurast Test Root", AUTHORITY_SEED);
+        let builder = AttestationBuilder::default();
+        let chain = builder.certificate_chain(&authority, KEY_SEED);
+
+        let attestation = extract(&chain);
+        assert_eq!(
+            Attestation {
+                cert_ids: attestation.cert_ids.clone(),
+                ..builder.build()
+            },
+            attestation
+        );
+    }
+
+    #[test]
+    fn test_certificate_chain_untrusted_root() {
+        let authority = TestCertificateAuthority::new("Acurast Test Root", AUTHORITY_SEED);
+        let other_authority = TestCertificateAuthority::new("Acurast Test Root", [3u8; 32]);
+        let chain = AttestationBuilder::new().certificate_chain(&authority, KEY_SEED);
+
+        assert_eq!(
+            Err(ValidationError::UntrustedRoot),
+            validate_certificate_chain_root(
+                &chain,
+                &[other_authority.root_certificate().as_slice()]
+            )
+        );
+        // the chain's signatures do not verify if the root is replaced by one with a different key
+        let forged_chain: CertificateChainInput =
+            vec![other_authority.root_certificate().clone(), chain[1].clone()]
+                .try_into()
+                .unwrap();
+        assert!(validate_certificate_chain(&forged_chain).is_err());
+    }
+
+    #[test]
+    fn test_encode_time() {
+        assert_eq!(b"\x18\x0f19700101000000Z".to_vec(), encode_time(0));
+        assert_eq!(
+            b"\x18\x0f20230101000000Z".to_vec(),
+            encode_time(1_672_531_200_999)
+        );
+        assert_eq!(
+            b"\x18\x0f20240229235959Z".to_vec(),
+            encode_time(1_709_251_199_000)
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! A minimal decoder for the subset of [CBOR](https://www.rfc-editor.org/rfc/rfc8949) used by Apple App Attest attestation objects.
+//!
+//! Only definite length unsigned integers, byte strings, text strings, arrays and maps are supported.
+
+use sp_std::prelude::*;
+
+/// The maximum nesting of arrays and maps accepted by [decode].
+const MAX_DEPTH: usize = 8;
+
+/// A decoded CBOR data item borrowing its strings from the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value<'a> {
+    Unsigned(u64),
+    Bytes(&'a [u8]),
+    Text(&'a [u8]),
+    Array(Vec<Value<'a>>),
+    Map(Vec<(Value<'a>, Value<'a>)>),
+}
+
+impl<'a> Value<'a> {
+    /// Returns the value of the map entry with the given text key.
+    pub fn get(&self, key: &[u8]) -> Option<&Value<'a>> {
+        match self {
+            Value::Map(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, Value::Text(text) if *text == key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&'a [u8]> {
+        match self {
+            Value::Bytes(bytes) => Some(*bytes),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&'a [u8]> {
+        match self {
+            Value::Text(text) => Some(*text),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value<'a>]> {
+        match self {
+            Value::Array(items) => Some(items.as_slice()),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes a single data item spanning the whole input.
+pub fn decode(input: &[u8]) -> Option<Value> {
+    let mut rest = input;
+    let value = decode_value(&mut rest, 0)?;
+    if !rest.is_empty() {
+        return None;
+    }
+    Some(value)
+}
+
+fn decode_value<'a>(input: &mut &'a [u8], depth: usize) -> Option<Value<'a>> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let (&initial, rest) = input.split_first()?;
+    *input = rest;
+    let argument = read_argument(input, initial & 0x1f)?;
+    match initial >> 5 {
+        0 => Some(Value::Unsigned(argument)),
+        2 => Some(Value::Bytes(take(input, argument)?)),
+        3 => Some(Value::Text(take(input, argument)?)),
+        4 => {
+            // no preallocation since the length is untrusted, every item consumes at least one byte of input
+            let mut items = Vec::new();
+            for _ in 0..argument {
+                items.push(decode_value(input, depth + 1)?);
+            }
+            Some(Value::Array(items))
+        }
+        5 => {
+            let mut entries = Vec::new();
+            for _ in 0..argument {
+                let key = decode_value(input, depth + 1)?;
+                let value = decode_value(input, depth + 1)?;
+                entries.push((key, value));
+            }
+            Some(Value::Map(entries))
+        }
+        _ => None,
+    }
+}
+
+/// Reads the argument following the initial byte, indefinite lengths are not supported.
+fn read_argument(input: &mut &[u8], additional_info: u8) -> Option<u64> {
+    let length = match additional_info {
+        0..=23 => return Some(additional_info as u64),
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return None,
+    };
+    let bytes = take(input, length)?;
+    Some(
+        bytes
+            .iter()
+            .fold(0u64, |value, byte| (value << 8) | *byte as u64),
+    )
+}
+
+fn take<'a>(input: &mut &'a [u8], length: u64) -> Option<&'a [u8]> {
+    let length = usize::try_from(length).ok()?;
+    if input.len() < length {
+        return None;
+    }
+    let (taken, rest) = input.split_at(length);
+    *input = rest;
+    Some(taken)
+}
@@ -52,6 +52,22 @@ pub enum ValidationError {
     /// field in the sequence
     /// [Certificate](https://www.rfc-editor.org/rfc/rfc5280#section-4.1.1.2).
     SignatureMismatch,
+    /// The public key could not be encoded
+    EncodePublicKey,
+    /// The App Attest attestation object is not valid CBOR or misses required fields
+    InvalidAppAttestationObject,
+    /// The App Attest attestation statement format is not `apple-appattest`
+    UnsupportedAppAttestationFormat,
+    /// The nonce contained in the credential certificate does not match the attested data
+    AppAttestationNonceMismatch,
+    /// The attested key does not match the provided key id
+    AppAttestationKeyIdMismatch,
+    /// The authenticator data is malformed or contains unexpected values
+    InvalidAuthenticatorData,
+    /// The authenticator data is not bound to the provided app id
+    AppIdMismatch,
+    /// The attestation status list is not valid JSON or contains invalid entries
+    InvalidStatusList,
 }
 
 impl From<ParseError> for ValidationError {
@@ -0,0 +1,301 @@
+//! Parsing of the [attestation status list](https://developer.android.com/privacy-and-security/security-key-attestation#certificate_status)
+//! published by Google for hardware attestation certificates.
+//!
+//! The status list is a JSON document of the form
+//! `{"entries": {"<serial number as hex>": {"status": "REVOKED", "reason": "KEY_COMPROMISE", ...}, ...}}`.
+
+use sp_std::prelude::*;
+
+use crate::{
+    attestation::error::ValidationError, CertificateRevocation, RevocationReason, RevocationStatus,
+    SerialNumber,
+};
+
+/// The maximum nesting of arrays and objects accepted in the status list.
+const MAX_DEPTH: usize = 8;
+
+/// Parses the entries of a status list into the serial numbers and their [CertificateRevocation].
+///
+/// Serial numbers are returned in their DER integer encoding, as used in [crate::CertId]. Unknown reasons are
+/// mapped to [RevocationReason::Unspecified], while unknown statuses are rejected.
+pub fn parse_status_list(
+    status_list: &[u8],
+) -> Result<Vec<(SerialNumber, CertificateRevocation)>, ValidationError> {
+    let document = decode(status_list).ok_or(ValidationError::InvalidStatusList)?;
+    let entries = match document.get(b"entries") {
+        Some(Value::Object(entries)) => entries,
+        _ => return Err(ValidationError::InvalidStatusList),
+    };
+    entries
+        .iter()
+        .map(|(serial, entry)| {
+            let serial = serial_from_hex(serial).ok_or(ValidationError::InvalidStatusList)?;
+            let status = match entry.get(b"status").and_then(Value::as_string) {
+                Some(b"REVOKED") => RevocationStatus::Revoked,
+                Some(b"SUSPENDED") => RevocationStatus::Suspended,
+                _ => return Err(ValidationError::InvalidStatusList),
+            };
+            let reason = match entry.get(b"reason").and_then(Value::as_string) {
+                Some(b"KEY_COMPROMISE") => RevocationReason::KeyCompromise,
+                Some(b"CA_COMPROMISE") => RevocationReason::CaCompromise,
+                Some(b"SUPERSEDED") => RevocationReason::Superseded,
+                Some(b"SOFTWARE_FLAW") => RevocationReason::SoftwareFlaw,
+                _ => RevocationReason::Unspecified,
+            };
+            Ok((serial, CertificateRevocation { status, reason }))
+        })
+        .collect()
+}
+
+/// Converts a hex encoded serial number into its minimal DER integer encoding.
+fn serial_from_hex(hex: &[u8]) -> Option<SerialNumber> {
+    let digits = hex
+        .iter()
+        .map(|digit| (*digit as char).to_digit(16).map(|value| value as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    if digits.is_empty() {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(digits.len() / 2 + 2);
+    let (first, rest) = digits.split_at(digits.len() % 2);
+    bytes.extend(first.iter().copied());
+    bytes.extend(rest.chunks(2).map(|pair| pair[0] << 4 | pair[1]));
+    let leading_zeros = bytes
+        .iter()
+        .take(bytes.len() - 1)
+        .take_while(|byte| **byte == 0)
+        .count();
+    bytes.drain(..leading_zeros);
+    // positive integers with the high bit set are prefixed with a zero byte
+    if bytes[0] & 0x80 != 0 {
+        bytes.insert(0, 0);
+    }
+    SerialNumber::try_from(bytes).ok()
+}
+
+/// A decoded JSON value borrowing its strings from the input.
+///
+/// Only objects and strings are retained, since no other values are evaluated. Escape sequences are not decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Value<'a> {
+    Object(Vec<(&'a [u8], Value<'a>)>),
+    String(&'a [u8]),
+    Other,
+}
+
+impl<'a> Value<'a> {
+    fn get(&self, key: &[u8]) -> Option<&Value<'a>> {
+        match self {
+            Value::Object(members) => members.iter().find(|(k, _)| *k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn as_string(&self) -> Option<&'a [u8]> {
+        match self {
+            Value::String(string) => Some(*string),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes a single JSON value spanning the whole input, surrounded by optional whitespace.
+fn decode(input: &[u8]) -> Option<Value> {
+    let mut rest = input;
+    let value = decode_value(&mut rest, 0)?;
+    skip_whitespace(&mut rest);
+    if !rest.is_empty() {
+        return None;
+    }
+    Some(value)
+}
+
+fn decode_value<'a>(input: &mut &'a [u8], depth: usize) -> Option<Value<'a>> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    skip_whitespace(input);
+    match input.first()? {
+        b'{' => {
+            *input = &input[1..];
+            let mut members = Vec::new();
+            if !consume(input, b'}') {
+                loop {
+                    skip_whitespace(input);
+                    let key = decode_string(input)?;
+                    if !consume(input, b':') {
+                        return None;
+                    }
+                    members.push((key, decode_value(input, depth + 1)?));
+                    if consume(input, b'}') {
+                        break;
+                    }
+                    if !consume(input, b',') {
+                        return None;
+                    }
+                }
+            }
+            Some(Value::Object(members))
+        }
+        b'[' => {
+            *input = &input[1..];
+            if !consume(input, b']') {
+                loop {
+                    decode_value(input, depth + 1)?;
+                    if consume(input, b']') {
+                        break;
+                    }
+                    if !consume(input, b',') {
+                        return None;
+                    }
+                }
+            }
+            Some(Value::Other)
+        }
+        b'"' => Some(Value::String(decode_string(input)?)),
+        _ => {
+            let length = input
+                .iter()
+                .take_while(|byte| byte.is_ascii_alphanumeric() || b"+-.".contains(*byte))
+                .count();
+            let (literal, rest) = input.split_at(length);
+            let valid = matches!(literal, b"true" | b"false" | b"null")
+                || (!literal.is_empty()
+                    && literal
+                        .iter()
+                        .all(|byte| byte.is_ascii_digit() || b"+-.eE".contains(byte)));
+            if !valid {
+                return None;
+            }
+            *input = rest;
+            Some(Value::Other)
+        }
+    }
+}
+
+/// Decodes a string starting at the opening quote, returning its raw content.
+fn decode_string<'a>(input: &mut &'a [u8]) -> Option<&'a [u8]> {
+    if input.first() != Some(&b'"') {
+        return None;
+    }
+    let mut index = 1;
+    loop {
+        match input.get(index)? {
+            b'"' => break,
+            b'\\' => index += 2,
+            byte if *byte < 0x20 => return None,
+            _ => index += 1,
+        }
+    }
+    let string = &input[1..index];
+    *input = &input[index + 1..];
+    Some(string)
+}
+
+/// Consumes the `expected` byte after optional whitespace, returning whether it was present.
+fn consume(input: &mut &[u8], expected: u8) -> bool {
+    skip_whitespace(input);
+    if input.first() == Some(&expected) {
+        *input = &input[1..];
+        true
+    } else {
+        false
+    }
+}
+
+fn skip_whitespace(input: &mut &[u8]) {
+    let length = input
+        .iter()
+        .take_while(|byte| matches!(byte, b' ' | b'\t' | b'\n' | b'\r'))
+        .count();
+    *input = &input[length..];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUS_LIST: &[u8] = br#"{
+  "entries": {
+    "2c8cdddfd5e03bfc": {
+      "status": "REVOKED",
+      "expires": "2020-11-13",
+      "reason": "KEY_COMPROMISE",
+      "comment": "Key stored on unsecure system"
+    },
+    "c8966fcb2fbb0d7d": {
+      "status": "SUSPENDED",
+      "reason": "SOFTWARE_FLAW",
+      "comment": "Bug in keystore causes this key malfunction b/555555"
+    },
+    "15905857467176635834": {
+      "status": "REVOKED",
+      "flags": [1, 2.5e3, true, null]
+    }
+  }
+}"#;
+
+    #[test]
+    fn test_parse_status_list() {
+        let entries = parse_status_list(STATUS_LIST).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                (
+                    SerialNumber::truncate_from(vec![
+                        0x2c, 0x8c, 0xdd, 0xdf, 0xd5, 0xe0, 0x3b, 0xfc
+                    ]),
+                    CertificateRevocation {
+                        status: RevocationStatus::Revoked,
+                        reason: RevocationReason::KeyCompromise,
+                    }
+                ),
+                (
+                    SerialNumber::truncate_from(vec![
+                        0x00, 0xc8, 0x96, 0x6f, 0xcb, 0x2f, 0xbb, 0x0d, 0x7d
+                    ]),
+                    CertificateRevocation {
+                        status: RevocationStatus::Suspended,
+                        reason: RevocationReason::SoftwareFlaw,
+                    }
+                ),
+                (
+                    SerialNumber::truncate_from(vec![
+                        0x15, 0x90, 0x58, 0x57, 0x46, 0x71, 0x76, 0x63, 0x58, 0x34
+                    ]),
+                    CertificateRevocation {
+                        status: RevocationStatus::Revoked,
+                        reason: RevocationReason::Unspecified,
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_status_list_odd_length_serial() {
+        let entries = parse_status_list(br#"{"entries":{"0abc":{"status":"REVOKED"}}}"#).unwrap();
+        assert_eq!(entries[0].0, SerialNumber::truncate_from(vec![0x0a, 0xbc]));
+        let entries = parse_status_list(br#"{"entries":{"fff":{"status":"REVOKED"}}}"#).unwrap();
+        assert_eq!(entries[0].0, SerialNumber::truncate_from(vec![0x0f, 0xff]));
+    }
+
+    #[test]
+    fn test_parse_status_list_failure() {
+        for status_list in [
+            &br#"{"entries":{"0abc":{"status":"UNKNOWN"}}}"#[..],
+            &br#"{"entries":{"0xbc":{"status":"REVOKED"}}}"#[..],
+            &br#"{"entries":{"":{"status":"REVOKED"}}}"#[..],
+            &br#"{"entries":{"0abc":{"status":"REVOKED"}}"#[..],
+            &br#"{"entries":{"0abc":{"status":"REVOKED"}}} x"#[..],
+            &br#"{"entries":[]}"#[..],
+            &br#"{"entries":{"0abc":{"status":"REVOKED",}}}"#[..],
+            &br#"[[[[[[[[[[[[]]]]]]]]]]]]"#[..],
+        ] {
+            assert_eq!(
+                parse_status_list(status_list),
+                Err(ValidationError::InvalidStatusList)
+            );
+        }
+    }
+}
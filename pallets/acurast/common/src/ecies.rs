@@ -0,0 +1,66 @@
+//! Envelope format of environment variable values encrypted for a processor using ECIES.
+//!
+//! A value is encrypted for the [crate::PubKey] referenced by [crate::Environment::public_key] as follows:
+//!
+//! 1. generate an ephemeral key pair on the curve of the processor's key,
+//! 2. derive a 32 bytes key with HKDF-SHA256 (no salt, no info) from the x-coordinate of the ECDH shared secret
+//!    of the ephemeral private key and the processor's public key,
+//! 3. encrypt the value with AES-256-GCM using the derived key and a random nonce, without associated data.
+//!
+//! The encrypted value is stored as `ephemeral_public_key || nonce || ciphertext || tag`, with the ephemeral public
+//! key in SEC1 compressed form.
+
+use sp_std::prelude::*;
+
+/// The length of the SEC1 compressed ephemeral public key.
+pub const ECIES_EPHEMERAL_PUBLIC_KEY_LENGTH: usize = 33;
+/// The length of the AES-GCM nonce.
+pub const ECIES_NONCE_LENGTH: usize = 12;
+/// The length of the AES-GCM authentication tag.
+pub const ECIES_TAG_LENGTH: usize = 16;
+/// The number of bytes an [EciesEnvelope] adds to the length of the plaintext.
+pub const ECIES_OVERHEAD: usize =
+    ECIES_EPHEMERAL_PUBLIC_KEY_LENGTH + ECIES_NONCE_LENGTH + ECIES_TAG_LENGTH;
+
+/// An environment variable value encrypted for a processor, borrowing its parts from the encoded value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EciesEnvelope<'a> {
+    /// The SEC1 compressed ephemeral public key.
+    pub ephemeral_public_key: &'a [u8],
+    pub nonce: &'a [u8],
+    pub ciphertext: &'a [u8],
+    pub tag: &'a [u8],
+}
+
+impl<'a> EciesEnvelope<'a> {
+    /// Splits an encoded envelope into its parts, returning [None] if it is too short or the ephemeral public key
+    /// is not in SEC1 compressed form.
+    pub fn decode(encoded: &'a [u8]) -> Option<Self> {
+        if encoded.len() < ECIES_OVERHEAD {
+            return None;
+        }
+        let (ephemeral_public_key, rest) = encoded.split_at(ECIES_EPHEMERAL_PUBLIC_KEY_LENGTH);
+        if !matches!(ephemeral_public_key[0], 0x02 | 0x03) {
+            return None;
+        }
+        let (nonce, rest) = rest.split_at(ECIES_NONCE_LENGTH);
+        let (ciphertext, tag) = rest.split_at(rest.len() - ECIES_TAG_LENGTH);
+        Some(Self {
+            ephemeral_public_key,
+            nonce,
+            ciphertext,
+            tag,
+        })
+    }
+
+    /// Encodes the envelope as `ephemeral_public_key || nonce || ciphertext || tag`.
+    pub fn encode(&self) -> Vec<u8> {
+        [
+            self.ephemeral_public_key,
+            self.nonce,
+            self.ciphertext,
+            self.tag,
+        ]
+        .concat()
+    }
+}
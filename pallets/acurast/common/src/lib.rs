@@ -7,8 +7,12 @@ pub use attestation::*;
 #[cfg(test)]
 mod tests;
 
+mod ecies;
+mod script;
 mod traits;
 mod types;
 
+pub use ecies::*;
+pub use script::*;
 pub use traits::*;
 pub use types::*;
@@ -0,0 +1,305 @@
+use frame_support::{pallet_prelude::*, storage::bounded_vec::BoundedVec};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_std::prelude::*;
+
+/// The url scheme of scripts hosted on IPFS.
+pub const IPFS_SCHEME: &[u8] = b"ipfs://";
+/// The url scheme of scripts hosted on Arweave.
+pub const ARWEAVE_SCHEME: &[u8] = b"ar://";
+/// The url scheme of scripts identified only by the sha256 digest of their content.
+pub const SHA256_SCHEME: &[u8] = b"sha256://";
+
+/// The maximum length of the multibase string representation of an IPFS CID.
+pub const IPFS_CID_MAX_LENGTH: u32 = 128;
+
+/// The maximum length of a legacy script url, see [Script::Legacy].
+pub const LEGACY_SCRIPT_MAX_LENGTH: u32 = 53;
+
+/// The utf8 bytes of a legacy `ipfs://` script url.
+pub type LegacyScriptUrl = BoundedVec<u8, ConstU32<LEGACY_SCRIPT_MAX_LENGTH>>;
+/// The utf8 bytes of the string representation of an IPFS CID, e.g. `Qm...` (CIDv0) or `bafy...` (CIDv1).
+pub type IpfsCid = BoundedVec<u8, ConstU32<IPFS_CID_MAX_LENGTH>>;
+/// The 32 bytes of an Arweave transaction id, i.e. its base64url representation decoded.
+pub type ArweaveTxId = [u8; 32];
+/// A raw sha256 digest.
+pub type Sha256Digest = [u8; 32];
+
+/// Type representing a typed locator of a script.
+///
+/// The variant index acts as the version of the SCALE encoding: indices of existing variants never change
+/// and new kinds of locators are added under new indices. The legacy encoding (the utf8 bytes of an `ipfs://` url)
+/// is converted by the storage migration of `pallet_acurast`.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Script {
+    /// A script hosted on IPFS, identified by a CIDv0 or CIDv1.
+    #[codec(index = 0)]
+    Ipfs(IpfsCid),
+    /// A script hosted on Arweave, identified by the id of the transaction that stored it.
+    #[codec(index = 1)]
+    Arweave(ArweaveTxId),
+    /// A script distributed out of band, identified by the sha256 digest of its content.
+    #[codec(index = 2)]
+    Sha256(Sha256Digest),
+    /// The raw utf8 bytes of an `ipfs://` url registered before the introduction of typed locators.
+    ///
+    /// Only produced by the storage migration for registrations whose url is not a valid locator, so that they keep
+    /// working as before. It cannot be used for new registrations.
+    #[codec(index = 3)]
+    Legacy(LegacyScriptUrl),
+}
+
+/// Errors returned when parsing or validating a [Script].
+#[derive(RuntimeDebug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptError {
+    /// The url does not start with any of the supported schemes.
+    UnsupportedScheme,
+    /// The CID exceeds [IPFS_CID_MAX_LENGTH].
+    CidTooLong,
+    /// The CID uses an unsupported multibase or is not valid in the announced multibase.
+    InvalidMultibase,
+    /// The decoded CID is not a valid CIDv0 or CIDv1.
+    InvalidCid,
+    /// The CID's multihash uses an unsupported hash function.
+    UnsupportedMultihash,
+    /// The Arweave transaction id is not 43 characters of base64url.
+    InvalidArweaveTxId,
+    /// The digest is not 64 hex characters.
+    InvalidDigest,
+    /// The script is a [Script::Legacy] url, only kept for migrated registrations.
+    LegacyScript,
+}
+
+impl Script {
+    /// Parses a script url of the form `ipfs://<cid>`, `ar://<tx id>` or `sha256://<hex digest>`.
+    pub fn from_url(url: &[u8]) -> Result<Self, ScriptError> {
+        if let Some(cid) = url.strip_prefix(IPFS_SCHEME) {
+            validate_ipfs_cid(cid)?;
+            let cid = IpfsCid::try_from(cid.to_vec()).map_err(|_| ScriptError::CidTooLong)?;
+            return Ok(Script::Ipfs(cid));
+        }
+        if let Some(tx_id) = url.strip_prefix(ARWEAVE_SCHEME) {
+            let tx_id = decode_base64url(tx_id)
+                .and_then(|bytes| ArweaveTxId::try_from(bytes).ok())
+                .ok_or(ScriptError::InvalidArweaveTxId)?;
+            return Ok(Script::Arweave(tx_id));
+        }
+        if let Some(digest) = url.strip_prefix(SHA256_SCHEME) {
+            let digest = decode_hex(digest)
+                .and_then(|bytes| Sha256Digest::try_from(bytes).ok())
+                .ok_or(ScriptError::InvalidDigest)?;
+            return Ok(Script::Sha256(digest));
+        }
+        Err(ScriptError::UnsupportedScheme)
+    }
+
+    /// Returns the url representation of this script, the inverse of [Script::from_url].
+    pub fn to_url(&self) -> Vec<u8> {
+        match self {
+            Script::Ipfs(cid) => [IPFS_SCHEME, cid.as_slice()].concat(),
+            Script::Arweave(tx_id) => [ARWEAVE_SCHEME, &encode_base64url(tx_id)].concat(),
+            Script::Sha256(digest) => [SHA256_SCHEME, &encode_hex(digest)].concat(),
+            Script::Legacy(url) => url.to_vec(),
+        }
+    }
+
+    /// Validates the content of the locator.
+    ///
+    /// Arweave transaction ids and sha256 digests are valid by construction, IPFS CIDs have to be checked
+    /// since the SCALE encoding only bounds their length. Legacy urls are never valid for new registrations.
+    pub fn validate(&self) -> Result<(), ScriptError> {
+        match self {
+            Script::Ipfs(cid) => validate_ipfs_cid(cid),
+            Script::Arweave(_) | Script::Sha256(_) => Ok(()),
+            Script::Legacy(_) => Err(ScriptError::LegacyScript),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Script {
+    type Error = ScriptError;
+
+    fn try_from(url: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_url(url)
+    }
+}
+
+impl TryFrom<Vec<u8>> for Script {
+    type Error = ScriptError;
+
+    fn try_from(url: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_url(&url)
+    }
+}
+
+pub fn is_valid_script(script: &Script) -> bool {
+    script.validate().is_ok()
+}
+
+/// Validates the string representation of an IPFS CID.
+///
+/// CIDv0 are expected as the plain base58btc encoding of a sha2-256 multihash (`Qm...`), CIDv1 as a multibase string
+/// in base32 (`b`), base58btc (`z`) or base16 (`f`).
+pub fn validate_ipfs_cid(cid: &[u8]) -> Result<(), ScriptError> {
+    if cid.len() > IPFS_CID_MAX_LENGTH as usize {
+        return Err(ScriptError::CidTooLong);
+    }
+    if cid.len() == 46 && cid.starts_with(b"Qm") {
+        let bytes = decode_base58(cid).ok_or(ScriptError::InvalidMultibase)?;
+        if bytes.len() != 34 || bytes[0] != 0x12 || bytes[1] != 0x20 {
+            return Err(ScriptError::InvalidCid);
+        }
+        return Ok(());
+    }
+
+    let (multibase, encoded) = cid.split_first().ok_or(ScriptError::InvalidCid)?;
+    let bytes = match multibase {
+        b'b' => decode_base32(encoded),
+        b'z' => decode_base58(encoded),
+        b'f' => decode_hex(encoded),
+        _ => None,
+    }
+    .ok_or(ScriptError::InvalidMultibase)?;
+
+    let mut rest = bytes.as_slice();
+    let version = read_varint(&mut rest).ok_or(ScriptError::InvalidCid)?;
+    if version != 1 {
+        return Err(ScriptError::InvalidCid);
+    }
+    // any content codec is accepted, the processor decides whether it can resolve the content
+    let _codec = read_varint(&mut rest).ok_or(ScriptError::InvalidCid)?;
+    validate_multihash(rest)
+}
+
+fn validate_multihash(bytes: &[u8]) -> Result<(), ScriptError> {
+    let mut rest = bytes;
+    let code = read_varint(&mut rest).ok_or(ScriptError::InvalidCid)?;
+    let length = read_varint(&mut rest).ok_or(ScriptError::InvalidCid)?;
+    let expected_length = match code {
+        // sha2-256, sha3-256, blake3, blake2b-256
+        0x12 | 0x16 | 0x1e | 0xb220 => 32,
+        // sha2-512
+        0x13 => 64,
+        _ => return Err(ScriptError::UnsupportedMultihash),
+    };
+    if length != expected_length || rest.len() as u64 != length {
+        return Err(ScriptError::InvalidCid);
+    }
+    Ok(())
+}
+
+/// Reads an unsigned LEB128 varint as used by multiformats, advancing `input` past it.
+fn read_varint(input: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (i, byte) in input.iter().enumerate().take(9) {
+        value |= ((byte & 0x7f) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            *input = &input[i + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+fn decode_base58(input: &[u8]) -> Option<Vec<u8>> {
+    // little endian big number, multiplied by 58 for every input character
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for c in input {
+        let mut carry = BASE58_ALPHABET.iter().position(|a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // leading '1's encode leading zero bytes
+    let zeros = input
+        .iter()
+        .take_while(|c| **c == BASE58_ALPHABET[0])
+        .count();
+    bytes.resize(bytes.len() + zeros, 0);
+    bytes.reverse();
+    Some(bytes)
+}
+
+fn decode_base32(input: &[u8]) -> Option<Vec<u8>> {
+    decode_bits(input, 5, |c| BASE32_ALPHABET.iter().position(|a| *a == c))
+}
+
+fn decode_base64url(input: &[u8]) -> Option<Vec<u8>> {
+    decode_bits(input, 6, |c| {
+        BASE64URL_ALPHABET.iter().position(|a| *a == c)
+    })
+}
+
+fn decode_hex(input: &[u8]) -> Option<Vec<u8>> {
+    decode_bits(input, 4, |c| {
+        HEX_ALPHABET
+            .iter()
+            .position(|a| *a == c.to_ascii_lowercase())
+    })
+}
+
+fn encode_base64url(input: &[u8]) -> Vec<u8> {
+    encode_bits(input, 6, BASE64URL_ALPHABET)
+}
+
+fn encode_hex(input: &[u8]) -> Vec<u8> {
+    encode_bits(input, 4, HEX_ALPHABET)
+}
+
+/// Decodes an unpadded RFC 4648 style encoding where every character carries `width` bits.
+fn decode_bits(
+    input: &[u8],
+    width: u32,
+    value_of: impl Fn(u8) -> Option<usize>,
+) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * width as usize / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+    for c in input {
+        buffer = (buffer << width) | value_of(*c)? as u32;
+        bits += width;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    // the trailing bits are padding and must not carry any data
+    if bits >= width || buffer != 0 {
+        return None;
+    }
+    Some(output)
+}
+
+fn encode_bits(input: &[u8], width: u32, alphabet: &[u8]) -> Vec<u8> {
+    let mask = (1 << width) - 1;
+    let mut output = Vec::with_capacity((input.len() * 8 + width as usize - 1) / width as usize);
+    let mut buffer = 0u32;
+    let mut bits = 0u32;
+    for byte in input {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+        while bits >= width {
+            bits -= width;
+            output.push(alphabet[((buffer >> bits) & mask) as usize]);
+        }
+        buffer &= (1 << bits) - 1;
+    }
+    if bits > 0 {
+        output.push(alphabet[((buffer << (width - bits)) & mask) as usize]);
+    }
+    output
+}
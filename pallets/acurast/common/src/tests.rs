@@ -1,6 +1,26 @@
 #![cfg(test)]
 
-use crate::Schedule;
+use codec::{Decode, Encode};
+
+use crate::{
+    effective_environment, CronPattern, EciesEnvelope, Environment, EnvironmentVersion, JobModule,
+    JobModules, PubKey, Schedule, ScheduleKind, ScheduleTimestamps, Script, ScriptError, CU32,
+    ECIES_OVERHEAD, MAX_JOB_MODULES,
+};
+
+const HOUR: u64 = 3_600_000;
+const MINUTE: u64 = 60_000;
+/// Monday, 2 January 2023 00:00:00 UTC.
+const MONDAY: u64 = 1_672_617_600_000;
+
+/// Every full hour from 9:00 to 16:00 on weekdays.
+fn business_hours() -> ScheduleKind {
+    ScheduleKind::Cron(CronPattern {
+        minutes: 1,
+        hours: 0b11111111 << 9,
+        days_of_week: 0b0111110,
+    })
+}
 
 macro_rules! tests {
     ($property_test_func:ident {
@@ -31,6 +51,7 @@ tests! {
                 end_time: 8,
                 interval: 2,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             4
         );
@@ -43,6 +64,7 @@ tests! {
                 end_time: 15,
                 interval: 5,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             3
         );
@@ -55,6 +77,7 @@ tests! {
                 end_time: 9,
                 interval: 5,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             2
         );
@@ -69,6 +92,7 @@ tests! {
                 end_time: 15,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             3
         );
@@ -83,6 +107,7 @@ tests! {
                 end_time: 9,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             2
         );
@@ -97,6 +122,7 @@ tests! {
                 end_time: 11,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             3
         );
@@ -109,6 +135,7 @@ tests! {
                 end_time: 0,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             0
         );
@@ -123,6 +150,7 @@ tests! {
                 end_time: 1,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             1
         );
@@ -137,9 +165,32 @@ tests! {
                 end_time: 14,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
+            },
+            3
+        );
+        test_schedule_execution_count_timestamps(
+            Schedule{
+                duration: 5,
+                start_time: 0,
+                end_time: 100,
+                interval: 0,
+                max_start_delay: 0,
+                kind: ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(vec![10, 40, 90])),
             },
             3
         );
+        test_schedule_execution_count_cron(
+            Schedule{
+                duration: 30 * MINUTE,
+                start_time: MONDAY,
+                end_time: MONDAY + 7 * 24 * HOUR,
+                interval: 0,
+                max_start_delay: 0,
+                kind: business_hours(),
+            },
+            40
+        );
     }
 }
 
@@ -161,6 +212,7 @@ tests! {
                 end_time: 8,
                 interval: 2,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![0,2,4,6]
@@ -174,6 +226,7 @@ tests! {
                 end_time: 15,
                 interval: 5,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![0,5,10]
@@ -187,6 +240,7 @@ tests! {
                 end_time: 9,
                 interval: 5,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![0,5]
@@ -200,6 +254,7 @@ tests! {
                 end_time: 15,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![0,5,10]
@@ -213,6 +268,7 @@ tests! {
                 end_time: 15,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             2,
             vec![2,7,12]
@@ -228,6 +284,7 @@ tests! {
                 end_time: 9,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![0,5]
@@ -243,6 +300,7 @@ tests! {
                 end_time: 11,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![0,5,10]
@@ -256,6 +314,7 @@ tests! {
                 end_time: 0,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![]
@@ -271,10 +330,35 @@ tests! {
                 end_time: 1,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![0]
         );
+            test_schedule_iter_timestamps(
+            Schedule{
+                duration: 5,
+                start_time: 0,
+                end_time: 100,
+                interval: 0,
+                max_start_delay: 2,
+                kind: ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(vec![10, 40, 90])),
+            },
+            2,
+            vec![12, 42, 92]
+        );
+        test_schedule_iter_cron(
+            Schedule{
+                duration: 30 * MINUTE,
+                start_time: MONDAY + 10 * HOUR,
+                end_time: MONDAY + 24 * HOUR + 11 * HOUR,
+                interval: 0,
+                max_start_delay: 0,
+                kind: business_hours(),
+            },
+            0,
+            (10..17).chain(24 + 9..24 + 11).map(|hour| MONDAY + hour * HOUR).collect()
+        );
     }
 }
 
@@ -307,6 +391,7 @@ tests! {
                 end_time: 8,
                 interval: 2,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![((0,1), true), ((8,10), false)]
@@ -325,6 +410,7 @@ tests! {
                 end_time: 9,
                 interval: 2,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![((0,1), false), ((0,2), true), ((0, 10), true), ((8, 10), true), ((9, 10), false)]
@@ -342,6 +428,7 @@ tests! {
                 end_time: 15,
                 interval: 5,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![((5,6), true), ((6,7), true), ((7,8), false), ((12, 16), false)]
@@ -360,6 +447,7 @@ tests! {
                 end_time: 14,
                 interval: 5,
                 max_start_delay: 2,
+                kind: ScheduleKind::Interval,
             },
             2,
             vec![((0,3), false), ((2,4), false), ((10,12), true), ((12,15), false)]
@@ -371,6 +459,7 @@ tests! {
                 end_time: 0,
                 interval: 2,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![((0,1), false), ((0,2), false)]
@@ -382,9 +471,295 @@ tests! {
                 end_time: 0,
                 interval: 2,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             0,
             vec![((0,1), false), ((0,2), false)]
         );
+        test_schedule_overlaps_timestamps(
+            Schedule{
+                duration: 5,
+                start_time: 0,
+                end_time: 100,
+                interval: 0,
+                max_start_delay: 2,
+                kind: ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(vec![10, 40, 90])),
+            },
+            2,
+            vec![((13,20), true), ((17,42), false), ((17,43), true), ((50,92), false), ((94,200), true)]
+        );
+        test_schedule_overlaps_cron(
+            Schedule{
+                duration: 30 * MINUTE,
+                start_time: MONDAY,
+                end_time: MONDAY + 7 * 24 * HOUR,
+                interval: 0,
+                max_start_delay: 0,
+                kind: business_hours(),
+            },
+            0,
+            vec![
+                ((MONDAY + 9 * HOUR + 30 * MINUTE, MONDAY + 10 * HOUR), false),
+                ((MONDAY + 9 * HOUR + 29 * MINUTE, MONDAY + 10 * HOUR), true),
+                ((MONDAY + 4 * 24 * HOUR + 16 * HOUR + 29 * MINUTE, MONDAY + 5 * 24 * HOUR), true),
+                ((MONDAY + 5 * 24 * HOUR, MONDAY + 7 * 24 * HOUR), false),
+            ]
+        );
+    }
+}
+
+#[test]
+fn test_schedule_kind_validity() {
+    let schedule = Schedule {
+        duration: 5,
+        start_time: 0,
+        end_time: 100,
+        interval: 0,
+        max_start_delay: 0,
+        kind: ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(vec![10, 40, 90])),
+    };
+    assert!(schedule.is_valid_kind());
+    assert_eq!(schedule.min_interval(), 30);
+    assert_eq!(schedule.period(), Some(100));
+
+    let shifted = schedule.shift(1000).unwrap();
+    assert_eq!(
+        shifted.kind,
+        ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(vec![1010, 1040, 1090]))
+    );
+    assert!(shifted.is_valid_kind());
+
+    for timestamps in [vec![10, 10], vec![40, 10], vec![100]] {
+        let schedule = Schedule {
+            kind: ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(timestamps)),
+            ..schedule.clone()
+        };
+        assert!(!schedule.is_valid_kind());
+    }
+
+    let schedule = Schedule {
+        duration: 30 * MINUTE,
+        start_time: MONDAY,
+        end_time: MONDAY + 7 * 24 * HOUR,
+        interval: 0,
+        max_start_delay: 0,
+        kind: business_hours(),
+    };
+    assert!(schedule.is_valid_kind());
+    assert_eq!(schedule.min_interval(), HOUR);
+    assert_eq!(
+        schedule.range(0),
+        Some((
+            MONDAY + 9 * HOUR,
+            MONDAY + 4 * 24 * HOUR + 16 * HOUR + 30 * MINUTE
+        ))
+    );
+
+    let schedule = Schedule {
+        kind: ScheduleKind::Cron(CronPattern {
+            minutes: 1 << 60,
+            hours: 1,
+            days_of_week: 1,
+        }),
+        ..schedule
+    };
+    assert!(!schedule.is_valid_kind());
+}
+
+#[test]
+fn test_on_demand_schedule() {
+    let schedule = Schedule {
+        duration: 5,
+        start_time: 10,
+        end_time: 100,
+        interval: 0,
+        max_start_delay: 2,
+        kind: ScheduleKind::OnDemand,
+    };
+    assert!(schedule.is_on_demand());
+    assert!(schedule.is_valid_kind());
+    assert_eq!(schedule.execution_count(), 0);
+    assert_eq!(schedule.iter(2).unwrap().next(), None);
+    // the range covers every execution that could be triggered
+    assert_eq!(schedule.range(2), Some((12, 107)));
+    assert_eq!(schedule.overlaps(2, 0, 12), Some(false));
+    assert_eq!(schedule.overlaps(2, 0, 13), Some(true));
+    assert_eq!(schedule.overlaps(2, 106, 200), Some(true));
+    assert_eq!(schedule.overlaps(2, 107, 200), Some(false));
+}
+
+fn test_script_from_url(url: &[u8], expected: Result<Script, ScriptError>) {
+    let script = Script::from_url(url);
+    assert_eq!(script, expected);
+    if let Ok(script) = script {
+        assert_eq!(script.validate(), Ok(()));
+        assert_eq!(script.to_url(), url.to_vec());
+    }
+}
+
+tests! {
+    test_script_from_url {
+        test_script_from_url_cid_v0(
+            b"ipfs://QmdJNvMLfvjzJnHQJmsEBC8KUD1fyTusFrkXAF5YaZouT2",
+            Ok(Script::Ipfs(b"QmdJNvMLfvjzJnHQJmsEBC8KUD1fyTusFrkXAF5YaZouT2".to_vec().try_into().unwrap()))
+        );
+        test_script_from_url_cid_v1_base32(
+            b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            Ok(Script::Ipfs(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec().try_into().unwrap()))
+        );
+        test_script_from_url_arweave(
+            b"ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U",
+            Ok(Script::Arweave([
+                108, 214, 192, 221, 49, 16, 84, 190, 180, 198, 88, 2, 114, 167, 115, 225, 147, 199,
+                21, 158, 245, 213, 198, 119, 134, 105, 41, 26, 219, 67, 183, 245,
+            ]))
+        );
+        test_script_from_url_sha256(
+            b"sha256://e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            Ok(Script::Sha256([
+                227, 176, 196, 66, 152, 252, 28, 20, 154, 251, 244, 200, 153, 111, 185, 36, 39,
+                174, 65, 228, 100, 155, 147, 76, 164, 149, 153, 27, 120, 82, 184, 85,
+            ]))
+        );
+        test_script_from_url_cid_v0_truncated(
+            b"ipfs://QmdJNvMLfvjzJnHQJmsEBC8KUD1fyTusFrkXAF5YaZouT",
+            Err(ScriptError::InvalidMultibase)
+        );
+        test_script_from_url_cid_v0_invalid_character(
+            b"ipfs://QmdJNvMLfvjzJnHQJmsEBC8KUD1fyTusFrkXAF5YaZou0",
+            Err(ScriptError::InvalidMultibase)
+        );
+        test_script_from_url_cid_v1_invalid_multihash_length(
+            b"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz",
+            Err(ScriptError::InvalidCid)
+        );
+        test_script_from_url_zero_bytes(
+            &[b"ipfs://".as_slice(), &[0u8; 46]].concat(),
+            Err(ScriptError::InvalidMultibase)
+        );
+        test_script_from_url_arweave_too_short(
+            b"ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt",
+            Err(ScriptError::InvalidArweaveTxId)
+        );
+        test_script_from_url_sha256_odd_length(
+            b"sha256://e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b85",
+            Err(ScriptError::InvalidDigest)
+        );
+        test_script_from_url_unsupported_scheme(
+            b"https://example.com/script.js",
+            Err(ScriptError::UnsupportedScheme)
+        );
+    }
+}
+
+#[test]
+fn test_script_validate_decoded_cid() {
+    let script = Script::Ipfs(
+        b"QmdJNvMLfvjzJnHQJmsEBC8KUD1fyTusFrkXAF5YaZouT"
+            .to_vec()
+            .try_into()
+            .unwrap(),
+    );
+    assert_eq!(script.validate(), Err(ScriptError::InvalidMultibase));
+}
+
+#[test]
+fn test_script_legacy() {
+    let url = [b"ipfs://".as_slice(), &[0u8; 46]].concat();
+    let script = Script::Legacy(url.clone().try_into().unwrap());
+    assert_eq!(script.validate(), Err(ScriptError::LegacyScript));
+    assert_eq!(script.to_url(), url);
+}
+
+#[test]
+fn test_job_module_discriminants() {
+    for (i, module) in JobModule::ALL.into_iter().enumerate() {
+        assert_eq!(JobModule::try_from(i as u32), Ok(module));
+        assert_eq!(u32::from(module), i as u32);
+        assert_eq!(module.encode(), vec![i as u8]);
+        assert_eq!(
+            JobModule::decode(&mut module.encode().as_slice()).unwrap(),
+            module
+        );
     }
+
+    let unknown = JobModule::ALL.len() as u32;
+    assert_eq!(JobModule::try_from(unknown), Err(()));
+    assert!(JobModule::decode(&mut [unknown as u8].as_slice()).is_err());
+}
+
+#[test]
+fn test_job_modules_bound() {
+    let all: JobModules = JobModule::ALL.to_vec().try_into().unwrap();
+    assert_eq!(all.len(), JobModule::ALL.len());
+    assert!(JobModules::try_from(vec![JobModule::Gps; MAX_JOB_MODULES as usize + 1]).is_err());
+}
+
+#[test]
+fn test_effective_environment() {
+    let schedule = Schedule {
+        duration: 10,
+        start_time: 1000,
+        end_time: 1100,
+        interval: 25,
+        max_start_delay: 0,
+        kind: ScheduleKind::Interval,
+    };
+    let public_key = |key: u8| PubKey::SECP256r1(vec![key].try_into().unwrap());
+    let version = |effective_from: u64, key: u8| EnvironmentVersion {
+        effective_from,
+        environment: Environment::<CU32<1>, CU32<1>, CU32<1>> {
+            public_key: public_key(key),
+            variables: Default::default(),
+        },
+    };
+    let versions = vec![version(900, 1), version(1030, 2), version(1050, 3)];
+
+    let public_keys = schedule
+        .iter(0)
+        .unwrap()
+        .map(|start| effective_environment(&versions, start).map(|env| env.public_key.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        public_keys,
+        vec![
+            Some(public_key(1)),
+            Some(public_key(1)),
+            Some(public_key(3)),
+            Some(public_key(3))
+        ]
+    );
+    assert_eq!(effective_environment(&versions, 899), None);
+    assert_eq!(
+        effective_environment::<CU32<1>, CU32<1>, CU32<1>>(&[], 1000),
+        None
+    );
+}
+
+#[test]
+fn test_ecies_envelope() {
+    let mut encoded = vec![0x02];
+    encoded.extend([1u8; 32]);
+    encoded.extend([2u8; 12]);
+    encoded.extend(b"secret");
+    encoded.extend([3u8; 16]);
+
+    let envelope = EciesEnvelope::decode(&encoded).unwrap();
+    assert_eq!(envelope.ephemeral_public_key[0], 0x02);
+    assert_eq!(envelope.nonce, &[2u8; 12]);
+    assert_eq!(envelope.ciphertext, b"secret");
+    assert_eq!(envelope.tag, &[3u8; 16]);
+    assert_eq!(envelope.encode(), encoded);
+
+    // empty ciphertexts are allowed
+    let mut empty = encoded.clone();
+    empty.drain(45..51);
+    assert_eq!(empty.len(), ECIES_OVERHEAD);
+    assert_eq!(EciesEnvelope::decode(&empty).unwrap().ciphertext, b"");
+
+    // too short
+    assert_eq!(EciesEnvelope::decode(&empty[1..]), None);
+    // uncompressed ephemeral public key
+    encoded[0] = 0x04;
+    assert_eq!(EciesEnvelope::decode(&encoded), None);
 }
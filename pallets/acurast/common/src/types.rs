@@ -7,25 +7,14 @@ pub use bounded_attestation::*;
 use frame_support::{pallet_prelude::*, storage::bounded_vec::BoundedVec};
 use sp_std::prelude::*;
 
-use crate::ParameterBound;
+use crate::{ParameterBound, Script};
 #[cfg(feature = "std")]
 use serde;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 
-pub(crate) const SCRIPT_PREFIX: &[u8] = b"ipfs://";
-pub(crate) const SCRIPT_LENGTH: u32 = 53;
-
-/// Type representing the utf8 bytes of a string containing the value of an ipfs url.
-/// The ipfs url is expected to point to a script.
-pub type Script = BoundedVec<u8, ConstU32<SCRIPT_LENGTH>>;
 pub type AllowedSources<AccountId, MaxAllowedSources> = BoundedVec<AccountId, MaxAllowedSources>;
 
-pub fn is_valid_script(script: &Script) -> bool {
-    let script_len: u32 = script.len().try_into().unwrap_or(0);
-    script_len == SCRIPT_LENGTH && script.starts_with(SCRIPT_PREFIX)
-}
-
 /// https://datatracker.ietf.org/doc/html/rfc5280#section-4.1.2.2
 const SERIAL_NUMBER_MAX_LENGTH: u32 = 20;
 
@@ -70,13 +59,21 @@ where
 /// Structure used to updated the allowed sources list of a [Registration].
 pub type AllowedSourcesUpdate<AccountId> = ListUpdate<AccountId>;
 
-/// Structure used to updated the certificate recovation list.
-pub type CertificateRevocationListUpdate = ListUpdate<SerialNumber>;
-
 /// Structure representing a job registration.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "std",
+    serde(
+        rename_all = "camelCase",
+        bound(
+            serialize = "AccountId: Serialize, Extra: Serialize",
+            deserialize = "AccountId: Deserialize<'de>, Extra: Deserialize<'de>"
+        )
+    )
+)]
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
 pub struct JobRegistration<AccountId, MaxAllowedSources: Get<u32>, Extra> {
-    /// The script to execute. It is a vector of bytes representing a utf8 string. The string needs to be a ipfs url that points to the script.
+    /// The locator of the script to execute, see [Script].
     pub script: Script,
     /// An optional array of the [AccountId]s allowed to fulfill the job. If the array is [None], then all sources are allowed.
     pub allowed_sources: Option<AllowedSources<AccountId, MaxAllowedSources>>,
@@ -96,9 +93,80 @@ pub struct JobRegistration<AccountId, MaxAllowedSources: Get<u32>, Extra> {
     pub extra: Extra,
 }
 
+/// Structure used to partially update a [JobRegistration]. Fields set to [None] are left unchanged.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Default)]
+pub struct JobRegistrationUpdate {
+    /// New maximum memory bytes used during a single execution of the job.
+    pub memory: Option<u32>,
+    /// New maximum network request used during a single execution of the job.
+    pub network_requests: Option<u32>,
+    /// New maximum storage bytes used during the whole period of the job's executions.
+    pub storage: Option<u32>,
+    /// New end time of the job's [Schedule] in milliseconds since Unix Epoch.
+    pub end_time: Option<u64>,
+    /// New modules required for the job.
+    pub required_modules: Option<JobModules>,
+}
+
+impl<AccountId, MaxAllowedSources: Get<u32>, Extra>
+    JobRegistration<AccountId, MaxAllowedSources, Extra>
+where
+    Self: Clone,
+{
+    /// Returns a copy of this registration with the given `update` applied.
+    pub fn updated(&self, update: &JobRegistrationUpdate) -> Self {
+        let mut registration = self.clone();
+        if let Some(memory) = update.memory {
+            registration.memory = memory;
+        }
+        if let Some(network_requests) = update.network_requests {
+            registration.network_requests = network_requests;
+        }
+        if let Some(storage) = update.storage {
+            registration.storage = storage;
+        }
+        if let Some(end_time) = update.end_time {
+            registration.schedule.end_time = end_time;
+        }
+        if let Some(required_modules) = &update.required_modules {
+            registration.required_modules = required_modules.clone();
+        }
+        registration
+    }
+}
+
+/// The type of a job template identifier sequence.
+pub type JobTemplateId = u128;
+
+pub const JOB_TEMPLATE_NAME_MAX_LENGTH: u32 = 32;
+pub type JobTemplateName = BoundedVec<u8, ConstU32<JOB_TEMPLATE_NAME_MAX_LENGTH>>;
+
+/// A named [JobRegistration] stored for repeated registrations that only differ in their [Schedule] and reward.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+pub struct JobTemplate<AccountId, MaxAllowedSources: Get<u32>, Extra> {
+    /// The account that created the template and is allowed to register jobs from it.
+    pub owner: AccountId,
+    /// A name for the template, not required to be unique.
+    pub name: JobTemplateName,
+    /// The registration jobs are created from. Its schedule is replaced when registering a job from the template.
+    pub registration: JobRegistration<AccountId, MaxAllowedSources, Extra>,
+}
+
+pub const NUMBER_OF_PUB_KEYS: u32 = 2;
 pub const PUB_KEYS_MAX_LENGTH: u32 = 33;
+
 pub type PubKeyBytes = BoundedVec<u8, ConstU32<PUB_KEYS_MAX_LENGTH>>;
 
+/// The public keys of the processor revealed when a job is acknowledged.
+pub type PubKeys = BoundedVec<PubKey, ConstU32<NUMBER_OF_PUB_KEYS>>;
+
+/// The public key revealed by a processor, tagged with its curve.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
+pub enum PubKey {
+    SECP256r1(PubKeyBytes),
+    SECP256k1(PubKeyBytes),
+}
+
 /// Structure representing execution environment variables encrypted for a specific processor.
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
 pub struct Environment<
@@ -106,30 +174,97 @@ pub struct Environment<
     KeyMaxSize: ParameterBound,
     ValueMaxSize: ParameterBound,
 > {
-    /// Public key of key pair specifically created to encrypt environment secrets.
-    pub public_key: PubKeyBytes,
-    /// Environment variables with cleartext key, encrypted value.
+    /// The processor's public key the environment secrets are encrypted for, one of the [PubKeys] revealed when
+    /// acknowledging the job.
+    pub public_key: PubKey,
+    /// Environment variables with cleartext key, value encrypted as [EciesEnvelope] for `public_key`.
     pub variables:
         BoundedVec<(BoundedVec<u8, KeyMaxSize>, BoundedVec<u8, ValueMaxSize>), MaxEnvVars>,
 }
 
-pub const MAX_JOB_MODULES: u32 = 1;
+/// An [Environment] effective for executions starting at or after `effective_from`, until superseded by a later version.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+pub struct EnvironmentVersion<
+    MaxEnvVars: ParameterBound,
+    KeyMaxSize: ParameterBound,
+    ValueMaxSize: ParameterBound,
+> {
+    /// The timestamp in milliseconds from which on the environment is used.
+    pub effective_from: u64,
+    pub environment: Environment<MaxEnvVars, KeyMaxSize, ValueMaxSize>,
+}
 
+/// Returns the environment of the version effective for an execution starting at `execution_start`,
+/// given `versions` ordered by ascending `effective_from`.
+pub fn effective_environment<
+    MaxEnvVars: ParameterBound,
+    KeyMaxSize: ParameterBound,
+    ValueMaxSize: ParameterBound,
+>(
+    versions: &[EnvironmentVersion<MaxEnvVars, KeyMaxSize, ValueMaxSize>],
+    execution_start: u64,
+) -> Option<&Environment<MaxEnvVars, KeyMaxSize, ValueMaxSize>> {
+    versions
+        .iter()
+        .rev()
+        .find(|version| version.effective_from <= execution_start)
+        .map(|version| &version.environment)
+}
+
+/// The maximum number of modules a job can require, respectively a processor can advertise.
+pub const MAX_JOB_MODULES: u32 = 16;
+
+/// A capability of a processor that jobs can require.
+///
+/// The discriminants are used as SCALE variant indices as well as the integer representation in Hyperdrive payloads
+/// and must never change. New modules are appended, unknown discriminants are rejected when decoding.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq)]
 pub enum JobModule {
-    DataEncryption,
+    /// Encryption of job data with keys generated inside the processor's TEE.
+    DataEncryption = 0,
+    /// On-device inference of large language models.
+    LlmInference = 1,
+    /// Access to Bluetooth Low Energy devices.
+    Bluetooth = 2,
+    /// Access to NFC tags and readers.
+    Nfc = 3,
+    /// Access to the device's GPS location.
+    Gps = 4,
+    /// Version 1 of the JS runtime.
+    JsRuntimeV1 = 5,
+    /// Version 2 of the JS runtime.
+    JsRuntimeV2 = 6,
+}
+
+impl JobModule {
+    /// All known modules, in order of their discriminants.
+    pub const ALL: [JobModule; 7] = [
+        JobModule::DataEncryption,
+        JobModule::LlmInference,
+        JobModule::Bluetooth,
+        JobModule::Nfc,
+        JobModule::Gps,
+        JobModule::JsRuntimeV1,
+        JobModule::JsRuntimeV2,
+    ];
 }
 
 impl TryFrom<u32> for JobModule {
     type Error = ();
 
     fn try_from(value: u32) -> Result<Self, Self::Error> {
-        match value {
-            0 => Ok(JobModule::DataEncryption),
-            _ => Err(()),
-        }
+        JobModule::ALL
+            .into_iter()
+            .find(|module| *module as u32 == value)
+            .ok_or(())
+    }
+}
+
+impl From<JobModule> for u32 {
+    fn from(module: JobModule) -> Self {
+        module as u32
     }
 }
 
@@ -165,23 +300,213 @@ pub struct Schedule {
     ///
     /// (start_delay is the actual start delay chosen within `[0, max_start_delay]` during assigning the job to an available processor)
     pub end_time: u64,
-    /// Interval at which to repeat execution in milliseconds.
+    /// Interval at which to repeat execution in milliseconds. Only used by [`ScheduleKind::Interval`] schedules.
     pub interval: u64,
     /// Maximum delay before each execution in milliseconds.
     pub max_start_delay: u64,
+    /// Defines at which times in `[start_time, end_time)` an execution starts.
+    pub kind: ScheduleKind,
+}
+
+/// The maximum number of execution timestamps of a [`ScheduleKind::Timestamps`] schedule.
+pub const MAX_SCHEDULE_TIMESTAMPS: u32 = 64;
+
+pub type ScheduleTimestamps = BoundedVec<u64, ConstU32<MAX_SCHEDULE_TIMESTAMPS>>;
+
+/// Defines at which times the executions of a [`Schedule`] start, always restricted to `[start_time, end_time)`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
+pub enum ScheduleKind {
+    /// Executions start at `start_time` and repeat every `interval`.
+    Interval,
+    /// Executions start at the given timestamps in milliseconds since Unix Epoch, in strictly increasing order.
+    Timestamps(ScheduleTimestamps),
+    /// Executions start at every full minute matching the pattern.
+    Cron(CronPattern),
+    /// Executions start whenever triggered on-chain, hence no executions are known in advance.
+    ///
+    /// The processors assigned to the job are committed to execute within `[start_time + start_delay, end_time + start_delay + duration]`.
+    OnDemand,
+}
+
+const MINUTE_MILLIS: u64 = 60_000;
+const DAY_MILLIS: u64 = 86_400_000;
+const MINUTES_PER_DAY: u64 = 1440;
+const ALL_MINUTES: u64 = (1 << 60) - 1;
+const ALL_HOURS: u32 = (1 << 24) - 1;
+const ALL_DAYS_OF_WEEK: u8 = (1 << 7) - 1;
+
+/// A cron-like pattern of full minutes (in UTC) at which executions start.
+///
+/// Each field is a bitmask with bit `i` set if the `i`-th minute, hour or day of week matches,
+/// e.g. `minutes: 1, hours: 0b11111111 << 9, days_of_week: 0b0111110` matches every full hour from 9:00 to 16:00 on weekdays.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
+pub struct CronPattern {
+    /// The minutes `0..60` of an hour.
+    pub minutes: u64,
+    /// The hours `0..24` of a day.
+    pub hours: u32,
+    /// The days of week `0..7`, starting with Sunday.
+    pub days_of_week: u8,
+}
+
+impl CronPattern {
+    /// A pattern is valid if it matches at least one minute of a week and has no bits set outside of the fields' ranges.
+    pub fn is_valid(&self) -> bool {
+        self.minutes != 0
+            && self.minutes & !ALL_MINUTES == 0
+            && self.hours != 0
+            && self.hours & !ALL_HOURS == 0
+            && self.days_of_week != 0
+            && self.days_of_week & !ALL_DAYS_OF_WEEK == 0
+    }
+
+    fn matches_day(&self, day: u64) -> bool {
+        // the Unix Epoch was a Thursday
+        self.days_of_week & (1 << ((day + 4) % 7)) != 0
+    }
+
+    fn matches_hour(&self, hour: u64) -> bool {
+        self.hours & (1 << hour) != 0
+    }
+
+    /// Returns the first time at or after `time` matching the pattern.
+    pub fn next_match(&self, time: u64) -> Option<u64> {
+        if !self.is_valid() {
+            return None;
+        }
+        let mut minute_index = time.checked_add(MINUTE_MILLIS - 1)? / MINUTE_MILLIS;
+        // a valid pattern matches at the latest after skipping the hours of two days and a week
+        for _ in 0..(2 * 24 + 7 + 1) {
+            let day = minute_index / MINUTES_PER_DAY;
+            if !self.matches_day(day) {
+                minute_index = day.checked_add(1)?.checked_mul(MINUTES_PER_DAY)?;
+                continue;
+            }
+            let hour = minute_index % MINUTES_PER_DAY / 60;
+            let minute = minute_index % 60;
+            let remaining_minutes = self.minutes >> minute << minute;
+            if !self.matches_hour(hour) || remaining_minutes == 0 {
+                minute_index = (minute_index / 60).checked_add(1)?.checked_mul(60)?;
+                continue;
+            }
+            return (minute_index - minute)
+                .checked_add(remaining_minutes.trailing_zeros() as u64)?
+                .checked_mul(MINUTE_MILLIS);
+        }
+        None
+    }
+
+    /// Returns the last time at or before `time` matching the pattern.
+    pub fn previous_match(&self, time: u64) -> Option<u64> {
+        if !self.is_valid() {
+            return None;
+        }
+        let mut minute_index = time / MINUTE_MILLIS;
+        // a valid pattern matches at the latest after skipping the hours of two days and a week
+        for _ in 0..(2 * 24 + 7 + 1) {
+            let day = minute_index / MINUTES_PER_DAY;
+            if !self.matches_day(day) {
+                minute_index = (day * MINUTES_PER_DAY).checked_sub(1)?;
+                continue;
+            }
+            let hour = minute_index % MINUTES_PER_DAY / 60;
+            let minute = minute_index % 60;
+            let passed_minutes = self.minutes & ((2 << minute) - 1);
+            if !self.matches_hour(hour) || passed_minutes == 0 {
+                minute_index = (minute_index - minute).checked_sub(1)?;
+                continue;
+            }
+            return (minute_index - minute)
+                .checked_add(63 - passed_minutes.leading_zeros() as u64)?
+                .checked_mul(MINUTE_MILLIS);
+        }
+        None
+    }
+
+    /// The number of matches in `[start, end)`.
+    pub fn count(&self, start: u64, end: u64) -> u64 {
+        if !self.is_valid() || end <= start {
+            return 0;
+        }
+        let first_full_day = (start / DAY_MILLIS).saturating_add((start % DAY_MILLIS != 0) as u64);
+        let last_full_day = end / DAY_MILLIS;
+        if first_full_day >= last_full_day {
+            return self.count_one_by_one(start, end);
+        }
+
+        let matches_per_day = self.minutes.count_ones() as u64 * self.hours.count_ones() as u64;
+        let days = last_full_day - first_full_day;
+        let mut matching_days = days / 7 * self.days_of_week.count_ones() as u64;
+        for day in (last_full_day - days % 7)..last_full_day {
+            if self.matches_day(day) {
+                matching_days += 1;
+            }
+        }
+
+        self.count_one_by_one(start, first_full_day * DAY_MILLIS)
+            .saturating_add(matching_days.saturating_mul(matches_per_day))
+            .saturating_add(self.count_one_by_one(last_full_day * DAY_MILLIS, end))
+    }
+
+    /// Counts the matches in `[start, end)` by iterating them, intended for ranges within a day.
+    fn count_one_by_one(&self, start: u64, end: u64) -> u64 {
+        let mut count = 0;
+        let mut time = start;
+        while let Some(next) = self.next_match(time) {
+            if next >= end {
+                break;
+            }
+            count += 1;
+            time = next + 1;
+        }
+        count
+    }
+
+    /// The minimum time in milliseconds between the starts of two consecutive matches.
+    pub fn min_interval(&self) -> u64 {
+        let minutes = (0..60u64)
+            .filter(|minute| self.minutes & (1 << minute) != 0)
+            .collect::<Vec<_>>();
+        let wrapping = match (minutes.first(), minutes.last()) {
+            (Some(first), Some(last)) => first + 60 - last,
+            _ => return u64::MAX,
+        };
+        minutes
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .fold(wrapping, u64::min)
+            * MINUTE_MILLIS
+    }
 }
 
 impl Schedule {
     /// The number of executions in the [`Schedule`] which corresponds to the length of [`Schedule::iter()`].
     pub fn execution_count(&self) -> u64 {
-        (|| -> Option<u64> {
-            self.end_time
-                .checked_sub(self.start_time)?
-                .checked_sub(1u64)?
-                .checked_div(self.interval)?
-                .checked_add(1u64)
-        })()
-        .unwrap_or(0u64)
+        match &self.kind {
+            ScheduleKind::Interval => (|| -> Option<u64> {
+                self.end_time
+                    .checked_sub(self.start_time)?
+                    .checked_sub(1u64)?
+                    .checked_div(self.interval)?
+                    .checked_add(1u64)
+            })()
+            .unwrap_or(0u64),
+            ScheduleKind::Timestamps(timestamps) => timestamps
+                .iter()
+                .filter(|timestamp| self.contains(**timestamp))
+                .count() as u64,
+            ScheduleKind::Cron(pattern) => pattern.count(self.start_time, self.end_time),
+            ScheduleKind::OnDemand => 0,
+        }
+    }
+
+    /// Returns `true` if executions are triggered on-demand instead of following a schedule known in advance.
+    pub fn is_on_demand(&self) -> bool {
+        self.kind == ScheduleKind::OnDemand
     }
 
     /// Iterates over the start times of all the [`Schedule`]'s executions.
@@ -193,7 +518,10 @@ impl Schedule {
         Some(ScheduleIter {
             delayed_start_time: self.start_time.checked_add(start_delay)?,
             delayed_end_time: self.end_time.checked_add(start_delay)?,
+            start_delay,
             interval: self.interval,
+            kind: self.kind.clone(),
+            index: 0,
             current: None,
         })
     }
@@ -202,20 +530,51 @@ impl Schedule {
     ///
     /// Example:
     /// ___□□■■_□□■■_□□■■__.range(2) -> (3, 17)
+    ///
+    /// For [`ScheduleKind::OnDemand`] schedules, the range covers any execution that could be triggered.
     pub fn range(&self, start_delay: u64) -> Option<(u64, u64)> {
-        let actual_start = self.start_time.checked_add(start_delay)?;
-        let count = self.execution_count();
-        let actual_end = if count > 0 {
-            actual_start
-                .checked_add((count - 1).checked_mul(self.interval)?)?
-                .checked_add(self.duration)?
-        } else {
-            actual_start
-        };
-        Some((actual_start, actual_end))
+        if self.is_on_demand() {
+            return Some((
+                self.start_time.checked_add(start_delay)?,
+                self.end_time
+                    .checked_add(start_delay)?
+                    .checked_add(self.duration)?,
+            ));
+        }
+        match (
+            self.first_execution_from(self.start_time),
+            self.last_execution(),
+        ) {
+            (Some(first), Some(last)) => Some((
+                first.checked_add(start_delay)?,
+                last.checked_add(start_delay)?.checked_add(self.duration)?,
+            )),
+            _ => {
+                let actual_start = self.start_time.checked_add(start_delay)?;
+                Some((actual_start, actual_start))
+            }
+        }
     }
 
     pub fn overlaps(&self, start_delay: u64, a: u64, b: u64) -> Option<bool> {
+        if self.is_on_demand() {
+            let (start, end) = self.range(start_delay)?;
+            return Some(a < b && a < end && start < b);
+        }
+        if self.kind != ScheduleKind::Interval {
+            if b <= a {
+                return Some(false);
+            }
+            // the first execution not ending before `a` is the only candidate to overlap with `[a, b]`
+            let earliest_start = a
+                .saturating_add(1)
+                .saturating_sub(start_delay.saturating_add(self.duration));
+            return Some(match self.first_execution_from(earliest_start) {
+                Some(start) => start.checked_add(start_delay)? < b,
+                None => false,
+            });
+        }
+
         let (start, end) = self.range(start_delay)?;
         if b <= a || start == end || b <= start || end <= a {
             return Some(false);
@@ -240,13 +599,128 @@ impl Schedule {
             Some(false)
         }
     }
+
+    /// The minimum time in milliseconds between the starts of two consecutive executions.
+    pub fn min_interval(&self) -> u64 {
+        match &self.kind {
+            ScheduleKind::Interval => self.interval,
+            ScheduleKind::Timestamps(timestamps) => timestamps
+                .windows(2)
+                .map(|pair| pair[1].saturating_sub(pair[0]))
+                .min()
+                .unwrap_or(u64::MAX),
+            ScheduleKind::Cron(pattern) => pattern.min_interval(),
+            ScheduleKind::OnDemand => u64::MAX,
+        }
+    }
+
+    /// The time in milliseconds after which the schedule can be repeated without overlapping itself.
+    ///
+    /// For [`ScheduleKind::Interval`] schedules the repeated executions continue the interval.
+    pub fn period(&self) -> Option<u64> {
+        match &self.kind {
+            ScheduleKind::Interval => self.interval.checked_mul(self.execution_count()),
+            ScheduleKind::Timestamps(_) | ScheduleKind::Cron(_) | ScheduleKind::OnDemand => {
+                self.end_time.checked_sub(self.start_time)
+            }
+        }
+    }
+
+    /// Returns the schedule moved by `offset` milliseconds.
+    ///
+    /// Note that the executions of a [`ScheduleKind::Cron`] schedule stay aligned to the pattern.
+    pub fn shift(&self, offset: u64) -> Option<Schedule> {
+        let kind = match &self.kind {
+            ScheduleKind::Timestamps(timestamps) => ScheduleKind::Timestamps(
+                timestamps
+                    .iter()
+                    .map(|timestamp| timestamp.checked_add(offset))
+                    .collect::<Option<Vec<_>>>()?
+                    .try_into()
+                    .ok()?,
+            ),
+            kind => kind.clone(),
+        };
+        Some(Schedule {
+            start_time: self.start_time.checked_add(offset)?,
+            end_time: self.end_time.checked_add(offset)?,
+            kind,
+            ..self.clone()
+        })
+    }
+
+    /// Returns `true` if the [`ScheduleKind`] is well-formed, i.e. timestamps are strictly increasing and within
+    /// `[start_time, end_time)` and a cron pattern is valid.
+    pub fn is_valid_kind(&self) -> bool {
+        match &self.kind {
+            ScheduleKind::Interval | ScheduleKind::OnDemand => true,
+            ScheduleKind::Timestamps(timestamps) => {
+                timestamps.iter().all(|timestamp| self.contains(*timestamp))
+                    && timestamps.windows(2).all(|pair| pair[0] < pair[1])
+            }
+            ScheduleKind::Cron(pattern) => pattern.is_valid(),
+        }
+    }
+
+    fn contains(&self, time: u64) -> bool {
+        self.start_time <= time && time < self.end_time
+    }
+
+    /// The start time of the first execution at or after `time`, without start delay.
+    fn first_execution_from(&self, time: u64) -> Option<u64> {
+        let time = time.max(self.start_time);
+        let start = match &self.kind {
+            ScheduleKind::Interval => {
+                let offset = time - self.start_time;
+                let executions = offset.checked_add(self.interval.checked_sub(1)?)? / self.interval;
+                self.start_time
+                    .checked_add(executions.checked_mul(self.interval)?)?
+            }
+            ScheduleKind::Timestamps(timestamps) => *timestamps
+                .iter()
+                .filter(|timestamp| **timestamp >= time)
+                .min()?,
+            ScheduleKind::Cron(pattern) => pattern.next_match(time)?,
+            ScheduleKind::OnDemand => return None,
+        };
+        if start < self.end_time {
+            Some(start)
+        } else {
+            None
+        }
+    }
+
+    /// The start time of the last execution, without start delay.
+    fn last_execution(&self) -> Option<u64> {
+        let start = match &self.kind {
+            ScheduleKind::Interval => self.start_time.checked_add(
+                self.execution_count()
+                    .checked_sub(1)?
+                    .checked_mul(self.interval)?,
+            )?,
+            ScheduleKind::Timestamps(timestamps) => *timestamps
+                .iter()
+                .filter(|timestamp| self.contains(**timestamp))
+                .max()?,
+            ScheduleKind::Cron(pattern) => pattern.previous_match(self.end_time.checked_sub(1)?)?,
+            ScheduleKind::OnDemand => return None,
+        };
+        if start >= self.start_time {
+            Some(start)
+        } else {
+            None
+        }
+    }
 }
 
 /// Implements the [Iterator] trait so that scheduled jobs in a [Schedule] can be iterated.
 pub struct ScheduleIter {
     delayed_start_time: u64,
     delayed_end_time: u64,
+    start_delay: u64,
     interval: u64,
+    kind: ScheduleKind,
+    index: usize,
     current: Option<u64>,
 }
 
@@ -260,23 +734,30 @@ impl<'a> Iterator for ScheduleIter {
     // We use Self::Item in the return type, so we can change
     // the type without having to update the function signatures.
     fn next(&mut self) -> Option<Self::Item> {
-        self.current = match self.current {
-            None => {
-                if self.delayed_start_time < self.delayed_end_time {
-                    Some(self.delayed_start_time)
-                } else {
-                    None
+        let next = match (&self.kind, self.current) {
+            (ScheduleKind::Interval, None) => Some(self.delayed_start_time),
+            (ScheduleKind::Interval, Some(curr)) => curr.checked_add(self.interval),
+            (ScheduleKind::Timestamps(timestamps), _) => loop {
+                let timestamp = timestamps.get(self.index)?.checked_add(self.start_delay)?;
+                self.index += 1;
+                if timestamp >= self.delayed_start_time {
+                    break Some(timestamp);
                 }
-            }
-            Some(curr) => {
-                let next = curr.checked_add(self.interval)?;
-                if next < self.delayed_end_time {
-                    Some(next)
+            },
+            (ScheduleKind::Cron(pattern), curr) => {
+                let from = curr.unwrap_or(self.delayed_start_time) - self.start_delay;
+                let from = if curr.is_some() {
+                    from.checked_add(1)?
                 } else {
-                    None
-                }
+                    from
+                };
+                pattern
+                    .next_match(from)
+                    .and_then(|start| start.checked_add(self.start_delay))
             }
+            (ScheduleKind::OnDemand, _) => None,
         };
+        self.current = next.filter(|start| *start < self.delayed_end_time);
         self.current
     }
 }
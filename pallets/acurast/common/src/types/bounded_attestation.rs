@@ -2,10 +2,11 @@
 
 use crate::{
     attestation::{
+        app_attest,
         asn::{self, KeyDescription},
-        CertificateChainInput, CHAIN_MAX_LENGTH,
+        CertificateChainInput, CertificateFingerprint, CertificateInput, CHAIN_MAX_LENGTH,
     },
-    SerialNumber,
+    ListUpdate, ParameterBound, SerialNumber,
 };
 
 use frame_support::{pallet_prelude::*, storage::bounded_vec::BoundedVec};
@@ -26,6 +27,9 @@ pub(crate) const ATTESTATION_ID_MAX_LENGTH: u32 = 256;
 pub(crate) const BOUNDED_SET_PROPERTY: u32 = 16;
 pub(crate) const PACKAGE_NAME_MAX_LENGTH: u32 = 128;
 pub(crate) const SIGNATURE_DIGEST_SET_MAX_LENGTH: u32 = 16;
+pub(crate) const APP_ATTESTATION_OBJECT_MAX_LENGTH: u32 = 8192;
+pub(crate) const APP_ID_MAX_LENGTH: u32 = 128;
+pub(crate) const POLICY_ALLOW_LIST_MAX_LENGTH: u32 = 16;
 
 pub type Purpose = BoundedVec<u8, ConstU32<PURPOSE_MAX_LENGTH>>;
 pub type Digest = BoundedVec<u8, ConstU32<DIGEST_MAX_LENGTH>>;
@@ -35,17 +39,121 @@ pub type VerifiedBootKey = BoundedVec<u8, ConstU32<VERIFIED_BOOT_KEY_MAX_LENGTH>
 pub type VerifiedBootHash = BoundedVec<u8, ConstU32<VERIFIED_BOOT_HASH_MAX_LENGTH>>;
 pub type AttestationIdProperty = BoundedVec<u8, ConstU32<ATTESTATION_ID_MAX_LENGTH>>;
 pub type CertId = (IssuerName, SerialNumber);
+/// Structure used to updated the certificate recovation list, identifying certificates by issuer and serial number.
+pub type CertificateRevocationListUpdate = ListUpdate<CertId>;
 pub type ValidatingCertIds = BoundedVec<CertId, ConstU32<CHAIN_MAX_LENGTH>>;
 pub type BoundedSetProperty = BoundedVec<CertId, ConstU32<BOUNDED_SET_PROPERTY>>;
 pub type PackageName = BoundedVec<u8, ConstU32<PACKAGE_NAME_MAX_LENGTH>>;
 pub type SignatureDigestSet = BoundedVec<Digest, ConstU32<SIGNATURE_DIGEST_SET_MAX_LENGTH>>;
 pub type PackageInfoSet = BoundedVec<BoundedAttestationPackageInfo, ConstU32<16>>;
+pub type AppAttestationObject = BoundedVec<u8, ConstU32<APP_ATTESTATION_OBJECT_MAX_LENGTH>>;
+pub type AppId = BoundedVec<u8, ConstU32<APP_ID_MAX_LENGTH>>;
+pub type AppAttestKeyId = [u8; 32];
+/// Structure used to update the app ids App Attest attestations are accepted for.
+pub type AppIdUpdate = ListUpdate<AppId>;
+pub type AllowedPackageNames = BoundedVec<PackageName, ConstU32<POLICY_ALLOW_LIST_MAX_LENGTH>>;
+pub type AllowedSignatureDigests = BoundedVec<Digest, ConstU32<POLICY_ALLOW_LIST_MAX_LENGTH>>;
 
-/// Structure representing a submitted attestation chain.
+/// Enum representing a submitted attestation.
+///
+/// Before the introduction of App Attest this was a struct holding the `certificate_chain` only. Clients encoding it
+/// without up-to-date metadata have to prefix the previous encoding with the variant index `0x00` of
+/// [AttestationChain::AndroidKeyAttestation], or convert the chain with [From]. The previous encoding starts with the
+/// compact length of a chain of at least two certificates and thus never decodes as a valid variant.
 #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
-pub struct AttestationChain {
-    /// An ordered array of [CertificateInput]s describing a valid chain from known root certificate to attestation certificate.
-    pub certificate_chain: CertificateChainInput,
+pub enum AttestationChain {
+    /// An Android Key Attestation.
+    #[codec(index = 0)]
+    AndroidKeyAttestation {
+        /// An ordered array of [CertificateInput]s describing a valid chain from known root certificate to attestation certificate.
+        certificate_chain: CertificateChainInput,
+    },
+    /// An Apple App Attest attestation, validated against the Apple App Attestation Root CA.
+    #[codec(index = 1)]
+    AppleAppAttest {
+        /// The CBOR encoded attestation object as returned by `DCAppAttestService.attestKey`.
+        attestation_object: AppAttestationObject,
+        /// The identifier of the attested key, the sha256 hash of its public key.
+        key_id: AppAttestKeyId,
+        /// The app identifier (`<team id>.<bundle id>`) the key was generated for.
+        app_id: AppId,
+    },
+}
+
+impl From<CertificateChainInput> for AttestationChain {
+    fn from(certificate_chain: CertificateChainInput) -> Self {
+        AttestationChain::AndroidKeyAttestation { certificate_chain }
+    }
+}
+
+/// Update of the set of trusted root certificates.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+pub enum TrustedRootCertificateUpdate {
+    /// Adds a DER encoded self-signed root certificate.
+    Add(CertificateInput),
+    /// Removes the root certificate with the given fingerprint.
+    Remove(CertificateFingerprint),
+}
+
+/// The status of a certificate contained in the attestation status list.
+#[derive(
+    RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, PalletError, Clone, Copy, PartialEq, Eq,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RevocationStatus {
+    /// The certificate is permanently revoked.
+    Revoked,
+    /// The certificate is temporarily suspended and might become valid again.
+    Suspended,
+}
+
+/// The reason a certificate was revoked, as published in the attestation status list.
+#[derive(
+    RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, PalletError, Clone, Copy, PartialEq, Eq,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RevocationReason {
+    Unspecified,
+    KeyCompromise,
+    CaCompromise,
+    Superseded,
+    SoftwareFlaw,
+}
+
+/// A revoked certificate's status and the reason for the revocation.
+#[derive(
+    RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, PalletError, Clone, Copy, PartialEq, Eq,
+)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct CertificateRevocation {
+    pub status: RevocationStatus,
+    pub reason: RevocationReason,
+}
+
+/// A stored revocation together with the time it took effect.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RevocationEntry {
+    pub revocation: CertificateRevocation,
+    /// The timestamp in milliseconds from which on the revocation is effective, work performed before is still honored.
+    /// [None] for revocations of unknown time, effective for all work.
+    pub revoked_at: Option<u64>,
+}
+
+impl RevocationEntry {
+    /// Returns whether the revocation is effective for work performed at timestamp `at` in milliseconds.
+    pub fn is_effective_at(&self, at: u64) -> bool {
+        self.revoked_at.map_or(true, |revoked_at| at >= revoked_at)
+    }
+}
+
+/// A snapshot of the attestation status list, signed by the configured status list signer.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+pub struct RevocationStatusListSnapshot<MaxLength: ParameterBound> {
+    /// The sequence number of the snapshot, has to be greater than the one of the previously applied snapshot.
+    pub sequence: u64,
+    /// The status list JSON as published by Google.
+    pub status_list: BoundedVec<u8, MaxLength>,
 }
 
 /// Structure representing a stored attestation.
@@ -53,10 +161,46 @@ pub struct AttestationChain {
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Attestation {
     pub cert_ids: ValidatingCertIds,
-    pub key_description: BoundedKeyDescription,
+    pub content: AttestationContent,
     pub validity: AttestationValidity,
 }
 
+/// The content extracted from a validated attestation, depending on the kind of attestation.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AttestationContent {
+    #[codec(index = 0)]
+    KeyDescription(BoundedKeyDescription),
+    #[codec(index = 1)]
+    AppAttest(BoundedAppAttest),
+}
+
+/// The content of a validated Apple App Attest attestation.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct BoundedAppAttest {
+    pub app_id: AppId,
+    pub key_id: AppAttestKeyId,
+    pub counter: u32,
+    pub environment: AppAttestEnvironment,
+}
+
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum AppAttestEnvironment {
+    Development,
+    Production,
+}
+
+impl From<app_attest::Environment> for AppAttestEnvironment {
+    fn from(data: app_attest::Environment) -> Self {
+        match data {
+            app_attest::Environment::Development => AppAttestEnvironment::Development,
+            app_attest::Environment::Production => AppAttestEnvironment::Production,
+        }
+    }
+}
+
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct AttestationValidity {
@@ -782,3 +926,120 @@ impl<'a> TryFrom<asn::AttestationPackageInfo<'a>> for BoundedAttestationPackageI
         })
     }
 }
+
+/// Requirements an Android key attestation has to fulfill to be accepted.
+///
+/// Properties are only read from the hardware enforced authorization list, except for the
+/// `attestation_application_id` which Android always reports as software enforced.
+/// App Attest attestations do not carry any of these properties and are therefore rejected by policies setting any requirement.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AttestationPolicy {
+    /// The minimum `attestation_security_level`, where [AttestationSecurityLevel::StrongBox] satisfies [AttestationSecurityLevel::TrustedEnvironemnt].
+    pub min_security_level: Option<AttestationSecurityLevel>,
+    /// Requires the root of trust to report [VerifiedBootState::Verified].
+    pub require_verified_boot: bool,
+    /// Requires the root of trust to report a locked bootloader.
+    pub require_locked_bootloader: bool,
+    /// The minimum `os_patch_level` in the format `YYYYMM`.
+    pub min_os_patch_level: Option<u32>,
+    /// The minimum `vendor_patch_level` in the format `YYYYMMDD`.
+    pub min_vendor_patch_level: Option<u32>,
+    /// If set, at least one of the attested package names has to be contained.
+    pub allowed_package_names: Option<AllowedPackageNames>,
+    /// If set, at least one of the attested signature digests has to be contained.
+    pub allowed_signature_digests: Option<AllowedSignatureDigests>,
+}
+
+impl AttestationPolicy {
+    /// Returns `true` if the `attestation` fulfills all requirements of this policy.
+    pub fn accepts(&self, attestation: &Attestation) -> bool {
+        let key_description = match &attestation.content {
+            AttestationContent::KeyDescription(key_description) => key_description,
+            // none of the requirements can be verified for App Attest attestations
+            AttestationContent::AppAttest(_) => return self == &AttestationPolicy::default(),
+        };
+        let hardware_enforced = &key_description.tee_enforced;
+
+        if let Some(min_security_level) = &self.min_security_level {
+            if key_description.attestation_security_level.rank() < min_security_level.rank() {
+                return false;
+            }
+        }
+
+        if self.require_verified_boot || self.require_locked_bootloader {
+            let root_of_trust = match &hardware_enforced.root_of_trust {
+                Some(root_of_trust) => root_of_trust,
+                None => return false,
+            };
+            if self.require_verified_boot
+                && root_of_trust.verified_boot_state != VerifiedBootState::Verified
+            {
+                return false;
+            }
+            if self.require_locked_bootloader && !root_of_trust.device_locked {
+                return false;
+            }
+        }
+
+        if !satisfies_min(hardware_enforced.os_patch_level, self.min_os_patch_level)
+            || !satisfies_min(
+                hardware_enforced.vendor_patch_level,
+                self.min_vendor_patch_level,
+            )
+        {
+            return false;
+        }
+
+        if self.allowed_package_names.is_some() || self.allowed_signature_digests.is_some() {
+            let application_id = match key_description
+                .software_enforced
+                .attestation_application_id
+                .as_ref()
+                .or(hardware_enforced.attestation_application_id.as_ref())
+            {
+                Some(application_id) => application_id,
+                None => return false,
+            };
+            if let Some(allowed) = &self.allowed_package_names {
+                if !application_id
+                    .package_infos
+                    .iter()
+                    .any(|info| allowed.contains(&info.package_name))
+                {
+                    return false;
+                }
+            }
+            if let Some(allowed) = &self.allowed_signature_digests {
+                if !application_id
+                    .signature_digests
+                    .iter()
+                    .any(|digest| allowed.contains(digest))
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+fn satisfies_min(value: Option<u32>, min: Option<u32>) -> bool {
+    match min {
+        Some(min) => value.map_or(false, |value| value >= min),
+        None => true,
+    }
+}
+
+impl AttestationSecurityLevel {
+    /// Orders the security levels by the protection they offer.
+    fn rank(&self) -> u8 {
+        match self {
+            AttestationSecurityLevel::Unknown => 0,
+            AttestationSecurityLevel::Software => 1,
+            AttestationSecurityLevel::TrustedEnvironemnt => 2,
+            AttestationSecurityLevel::StrongBox => 3,
+        }
+    }
+}
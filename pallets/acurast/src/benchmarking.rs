@@ -1,8 +1,9 @@
-use frame_benchmarking::{account, benchmarks, whitelist_account};
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, whitelist_account, BenchmarkError};
 use frame_support::{
     assert_ok,
     sp_runtime::traits::{AccountIdConversion, Get},
-    traits::OriginTrait,
+    traits::{EnsureOrigin, OriginTrait},
     BoundedVec,
 };
 use frame_system::RawOrigin;
@@ -10,7 +11,11 @@ use hex_literal::hex;
 use sp_std::prelude::*;
 
 use crate::Config;
-use acurast_common::{AttestationChain, JobRegistration, Script};
+use acurast_common::{
+    certificate_fingerprint, AppIdUpdate, AttestationChain, AttestationPolicy,
+    AttestationSecurityLevel, CertificateInput, JobRegistration, ListUpdateOperation,
+    RevocationStatusListSnapshot, Script, TrustedRootCertificateUpdate,
+};
 
 use crate::utils::validate_and_extract_attestation;
 use crate::Pallet as Acurast;
@@ -22,11 +27,39 @@ pub const ROOT_CERT: [u8; 1380] = hex!("3082056030820348a003020102020900e8fa1963
 pub const INT_CERT_1: [u8; 987] = hex!("308203d7308201bfa003020102020a038826676065899685f5300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3139303830393233303332335a170d3239303830363233303332335a302f31193017060355040513103534663539333730353432663561393531123010060355040c0c095374726f6e67426f783076301006072a8648ce3d020106052b8104002203620004e352276f9bfcea4301a5f0427fa6478e573209ae44fd762cfbc57cbbd4713631509e802ea0e940536e54fa2570ca2846154698075509293b3100b3955b4317768b286bf6fe2651c59af6c6b0db3360090a4647c7860e76ecc3b8a7db5ce57acca381b63081b3301d0603551d0e041604146990b10c3b088aee2af88c3387b42c12dadfc3a6301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff04040302020430500603551d1f044930473045a043a041863f68747470733a2f2f616e64726f69642e676f6f676c65617069732e636f6d2f6174746573746174696f6e2f63726c2f38463637333443394641353034373839300d06092a864886f70d01010b050003820201005c591327a0b0249ecadc949184c9651ed1f2a617a17516439875429e9bd21f87fd2365d0dcde747022c19410f23ab380fe1cef0f47aebc443c2a4531df3eca4101bf96d6bc30dfd878ed6734653111b5e782a03350cc2605e128b48a57e7ff1fe4bf4104de3f7ca9ace6afb01bdd9205fa10b91837a337257afb8290afa456fa629cfae5477b172b009bf28d43dcd4d31edcbf3dc1b6fcfcca5c38a79773d38b5a9d3ccd8152d51f25f9900701d9fb4fbf1307e17fcf5ddc759409863d2f0fb2e6c24468c9c5d85154e104318cb10ae60ba27bb252080e072645681c39e560e8586a64550867162f4bde9db75645882cb9eaff4efe1b0a312f5bd40224298c91f135061b8e04e8fa4c618c33f7b942c028f00d18113bfb6e55a952ccb5d71ee046f9bfdc85aa083e26d94be354545954b70c812ac4e326fdf07703bb79e536d429ff1d099c81722d81714593c7c2bb56740ccbc801332bb548695e28f2c8ac1452a260cfe57f311adc132e8dda01d638f9a4a31288a623a917f5b6c87e1c8316927129a0d11f384251d2df26b942a76844ab91968f4953e7484f2ecd2d6e187f9772d3b4584ac986e2079bc75f20773f8814ba2d16c7266761d6a3505f939fc316efda8787085a5d4f479df944f9d061d2c99acce73ed31770659297113f94140500306887be1b88082b96b18e123cabfcffbd79b68782a0408748cbf4f02f42");
 pub const INT_CERT_2: [u8; 564] = hex!("30820230308201b7a003020102020a15905857467176635834300a06082a8648ce3d040302302f31193017060355040513103534663539333730353432663561393531123010060355040c0c095374726f6e67426f78301e170d3139303732373031353231395a170d3239303732343031353231395a302f31193017060355040513103937333533373739333664306464373431123010060355040c0c095374726f6e67426f783059301306072a8648ce3d020106082a8648ce3d030107034200047639963abb7d336b5f238d8b355efdb395a22b2ccde67bda24328e4bbf802fefa97f204dd8bdb450332cb5e566f759bdc6ffafb9f3bc78e3747dfce8278e5f02a381ba3081b7301d0603551d0e04160414413e3ca9b34bc7a51cbb0125c0421be651ad7ad8301f0603551d230418301680146990b10c3b088aee2af88c3387b42c12dadfc3a6300f0603551d130101ff040530030101ff300e0603551d0f0101ff04040302020430540603551d1f044d304b3049a047a045864368747470733a2f2f616e64726f69642e676f6f676c65617069732e636f6d2f6174746573746174696f6e2f63726c2f3135393035383537343637313736363335383334300a06082a8648ce3d0403020367003064023017a0df3880a22ea1d4b3dfbdb6c04a4e5655d0ba70bdc8a5ac483b270c1e6d520cda9800b3ad775bae8dfccc7a86ecf802302898f95f24867bb3112f440db5dad27769e42be7db8dc51cf0b2af55aa43c11002e340a24f3965032f9a3a7c83c6bbdb");
 pub const LEAF_CERT: [u8; 672] = hex!("3082029c30820241a003020102020101300c06082a8648ce3d0403020500302f31193017060355040513103937333533373739333664306464373431123010060355040c0c095374726f6e67426f783022180f32303232303730393130353135355a180f32303238303532333233353935395a301f311d301b06035504030c14416e64726f6964204b657973746f7265204b65793059301306072a8648ce3d020106082a8648ce3d03010703420004b20c1d15477662623ecf430104898006e0f81c0db1bae87cb96a87c7777404659e585d3d9057b8a2ff8ae61f401a078fc75cf52c8c4268e810f93798c729e862a382015630820152300e0603551d0f0101ff0404030207803082013e060a2b06010401d6790201110482012e3082012a0201040a01020201290a0102040874657374617364660400306cbf853d0802060181e296611fbf85455c045a305831323030042b636f6d2e7562696e657469632e61747465737465642e6578656375746f722e746573742e746573746e657402010e31220420bdcb4560f6b3c41dad920668169c28be1ef9ea49f23d98cd8eb2f37ae4488ff93081a1a1053103020102a203020103a30402020100a5053103020100aa03020101bf8377020500bf853e03020100bf85404c304a0420879cd3f18ea76e244d4d4ac3bcb9c337c13b4667190b19035afe2536550050f10101ff0a010004203f4136ee3581e6aba8ea337a6b43d703de1eca241f9b7f277ecdfafff7a8dcf1bf854105020301d4c0bf85420502030315debf854e06020401348abdbf854f06020401348abd300c06082a8648ce3d04030205000347003044022033a613cce9a6ed25026a492b651f0ac67c3c0289d4e4743168c6903e2faa0bda0220324cd35c4bf2695d71ad12a28868e69232112922eaf0e3699f6add8133d528d9");
-const SCRIPT_BYTES: [u8; 53] = hex!("697066733A2F2F00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000");
+const SCRIPT_BYTES: [u8; 53] = hex!("697066733A2F2F516D4E4C656937387A576D7A556462655242334369556641697A5755726265655A68354B31726841514B43683531");
 
 pub trait BenchmarkHelper<T: Config> {
     fn registration_extra(instant_match: bool) -> T::RegistrationExtra;
     fn funded_account(index: u32) -> T::AccountId;
+    /// Signs a status list snapshot with the key configured as [Config::RevocationStatusListSigner].
+    fn sign_revocation_status_list(message: &[u8]) -> sp_core::ed25519::Signature;
+    /// Makes `source` accept environments for the job encrypted for `public_key`, e.g. by acknowledging an assignment
+    /// revealing the key, as checked by [Config::JobHooks].
+    fn acknowledge_environment_key(
+        job_id: &JobId<T::AccountId>,
+        source: &T::AccountId,
+        public_key: &PubKey,
+    );
+}
+
+/// Generates a status list revoking `entries` serial numbers starting from `offset`.
+pub fn revocation_status_list(offset: u32, entries: u32) -> Vec<u8> {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut status_list = b"{\"entries\":{".to_vec();
+    for serial_number in offset..offset + entries {
+        if serial_number > offset {
+            status_list.push(b',');
+        }
+        status_list.push(b'"');
+        for byte in serial_number.to_be_bytes() {
+            status_list.push(HEX_DIGITS[(byte >> 4) as usize]);
+            status_list.push(HEX_DIGITS[(byte & 0x0f) as usize]);
+        }
+        status_list.extend_from_slice(b"\":{\"status\":\"REVOKED\",\"reason\":\"KEY_COMPROMISE\"}");
+    }
+    status_list.extend_from_slice(b"}}");
+    status_list
 }
 
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -44,6 +77,7 @@ pub fn job_registration<T: Config>(extra: T::RegistrationExtra) -> JobRegistrati
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -65,7 +99,7 @@ where
 }
 
 pub fn attestation_chain() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![
             ROOT_CERT.to_vec().try_into().unwrap(),
             INT_CERT_1.to_vec().try_into().unwrap(),
@@ -97,6 +131,36 @@ fn register_job<T: Config>(
     (caller, job)
 }
 
+/// Generates an environment with `x` variables.
+fn environment<T: Config>(x: u32) -> EnvironmentFor<T> {
+    let variables = (0..x)
+        .map(|_| {
+            (
+                BoundedVec::truncate_from(vec![105, 112, 102, 115, 58, 47, 47, 8]),
+                BoundedVec::truncate_from(vec![105, 112, 102, 115, 58, 47, 47, 8]),
+            )
+        })
+        .collect::<Vec<_>>();
+    Environment {
+        public_key: PubKey::SECP256r1(BoundedVec::truncate_from([2u8; 33].to_vec())),
+        variables: BoundedVec::truncate_from(variables),
+    }
+}
+
+/// Generates the maximum number of versions of `environment` minus one, all staged for the far future.
+fn staged_environment_versions<T: Config>(
+    environment: &EnvironmentFor<T>,
+) -> EnvironmentVersionsFor<T> {
+    BoundedVec::truncate_from(
+        (1..T::MaxEnvironmentVersions::get())
+            .map(|i| EnvironmentVersion {
+                effective_from: u64::MAX - (T::MaxEnvironmentVersions::get() - i) as u64,
+                environment: environment.clone(),
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
 benchmarks! {
     where_clause {  where
         T: pallet_timestamp::Config,
@@ -160,7 +224,10 @@ benchmarks! {
     update_certificate_revocation_list {
         let updates =  vec![CertificateRevocationListUpdate {
             operation: ListUpdateOperation::Add,
-            item: hex!("15905857467176635834").to_vec().try_into().unwrap()
+            item: (
+                hex!("302f31193017060355040513103534663539333730353432663561393531123010060355040c0c095374726f6e67426f78").to_vec().try_into().unwrap(),
+                hex!("15905857467176635834").to_vec().try_into().unwrap(),
+            ),
         }];
 
         let pallet_account: T::AccountId = T::PalletId::get().into_account_truncating();
@@ -176,23 +243,207 @@ benchmarks! {
     set_environment {
         let x in 1 .. T::MaxEnvVars::get();
         let (caller, job) = register_job::<T>(true, false);
-        let mut vars: Vec<(BoundedVec<u8, T::EnvKeyMaxSize>, BoundedVec<u8, T::EnvValueMaxSize>)> = vec![];
-        for i in 0..x {
-            (&mut vars).push((BoundedVec::truncate_from(vec![
-                105, 112, 102, 115, 58, 47, 47, 8]), BoundedVec::truncate_from(vec![
-                105, 112, 102, 115, 58, 47, 47, 8])))
+        let env = environment::<T>(x);
+        let local_job_id = 1;
+        let job_id = (MultiOrigin::Acurast(caller.clone()), local_job_id);
+        let source: T::AccountId = account("processor", 0, SEED);
+        T::BenchmarkHelper::acknowledge_environment_key(&job_id, &source, &env.public_key);
+        // staged versions have to be kept, except for the one replaced by the new version
+        <ExecutionEnvironment<T>>::insert(&job_id, &source, staged_environment_versions::<T>(&env));
+        let now = crate::utils::now::<T>().unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), local_job_id, source.clone(), env.clone(), None)
+    verify {
+        assert_last_event::<T>(Event::ExecutionEnvironmentUpdated(job_id, source, now).into());
+    }
+
+    set_environments {
+        let x in 1 .. T::MaxSlots::get();
+        let (caller, job) = register_job::<T>(true, false);
+        let env = environment::<T>(T::MaxEnvVars::get());
+        let local_job_id = 1;
+        let job_id = (MultiOrigin::Acurast(caller.clone()), local_job_id);
+        let environments = (0..x).map(|i| {
+            let source: T::AccountId = account("processor", i, SEED);
+            T::BenchmarkHelper::acknowledge_environment_key(&job_id, &source, &env.public_key);
+            <ExecutionEnvironment<T>>::insert(&job_id, &source, staged_environment_versions::<T>(&env));
+            (source, env.clone())
+        }).collect::<Vec<_>>();
+        let now = crate::utils::now::<T>().unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), local_job_id, BoundedVec::truncate_from(environments), None)
+    verify {
+        assert_last_event::<T>(Event::ExecutionEnvironmentUpdated(
+            job_id, account("processor", x - 1, SEED), now
+        ).into());
+    }
+
+    update_registration {
+        let (caller, job) = register_job::<T>(true, false);
+        let update = JobRegistrationUpdate {
+            memory: Some(job.memory + 1),
+            network_requests: Some(job.network_requests + 1),
+            storage: Some(job.storage + 1),
+            end_time: Some(job.schedule.end_time + job.schedule.interval),
+            required_modules: Some(JobModules::default()),
+        };
+        let local_job_id = 1;
+    }: _(RawOrigin::Signed(caller.clone()), local_job_id, update.clone())
+    verify {
+        assert_last_event::<T>(Event::JobRegistrationUpdated(
+            (MultiOrigin::Acurast(caller), local_job_id), job, update
+        ).into());
+    }
+
+    update_trusted_root_certificates {
+        let x in 1 .. T::MaxTrustedRootCertificateUpdates::get();
+        let origin = T::TrustedRootCertificatesUpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let certificate: CertificateInput = ROOT_CERT.to_vec().try_into().unwrap();
+        let updates: BoundedVec<TrustedRootCertificateUpdate, T::MaxTrustedRootCertificateUpdates> =
+            vec![TrustedRootCertificateUpdate::Add(certificate.clone()); x as usize].try_into().unwrap();
+    }: _<T::RuntimeOrigin>(origin, updates)
+    verify {
+        assert_last_event::<T>(Event::TrustedRootCertificateAdded(
+            certificate_fingerprint(&certificate)
+        ).into());
+    }
+
+    set_attestation_policy {
+        let origin = T::AttestationPolicyUpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let policy = AttestationPolicy {
+            min_security_level: Some(AttestationSecurityLevel::StrongBox),
+            require_verified_boot: true,
+            require_locked_bootloader: true,
+            min_os_patch_level: Some(202206),
+            min_vendor_patch_level: Some(20220605),
+            allowed_package_names: Some(BoundedVec::truncate_from(vec![
+                BoundedVec::truncate_from(vec![0u8; 128]); 16
+            ])),
+            allowed_signature_digests: Some(BoundedVec::truncate_from(vec![
+                BoundedVec::truncate_from(vec![0u8; 32]); 16
+            ])),
+        };
+    }: _<T::RuntimeOrigin>(origin, Some(policy.clone()))
+    verify {
+        assert_last_event::<T>(Event::AttestationPolicyUpdated(Some(policy)).into());
+    }
+
+    sweep_attestation {
+        let processor_account: T::AccountId = processor_account_id::<T>();
+        let timestamp_call = pallet_timestamp::Pallet::<T>::set(T::RuntimeOrigin::none(), 1657363915001u64.into());
+        assert_ok!(timestamp_call);
+        let attestation = validate_and_extract_attestation::<T>(&processor_account, &attestation_chain()).unwrap();
+        <StoredAttestation<T>>::insert(&processor_account, attestation.clone());
+        <AttestationExpiryNotified<T>>::insert(&processor_account, ());
+        let now = attestation.validity.not_after;
+    }: {
+        Acurast::<T>::sweep_attestation(now, &processor_account, &attestation);
+    }
+    verify {
+        assert_last_event::<T>(Event::AttestationExpired(processor_account).into());
+    }
+
+    submit_revocation_status_list {
+        let x in 1 .. T::MaxRevocationStatusListEntries::get();
+        let y in 0 .. T::MaxRevocationStatusListEntries::get();
+        let caller: T::AccountId = account("caller", 0, SEED);
+        whitelist_account!(caller);
+
+        let previous_entries = status_list::parse_status_list(&revocation_status_list(x, y)).unwrap();
+        for (serial_number, revocation) in previous_entries.iter() {
+            <StoredRevokedSerialNumber<T>>::insert(serial_number, RevocationEntry { revocation: *revocation, revoked_at: Some(0) });
         }
-        let env: EnvironmentFor<T> = Environment{
-            public_key: BoundedVec::truncate_from(vec![105, 112, 102, 115, 58, 47, 47, 8]),
-            variables: BoundedVec::try_from(vars).unwrap(),
+        <RevocationStatusListEntries<T>>::put(BoundedVec::truncate_from(
+            previous_entries.into_iter().map(|(serial_number, _)| serial_number).collect::<Vec<_>>()
+        ));
+
+        let snapshot = RevocationStatusListSnapshot {
+            sequence: 1,
+            status_list: BoundedVec::try_from(revocation_status_list(0, x)).unwrap(),
         };
+        let signature = T::BenchmarkHelper::sign_revocation_status_list(&snapshot.encode());
+    }: _(RawOrigin::Signed(caller), snapshot, signature)
+    verify {
+        assert_last_event::<T>(Event::RevocationStatusListApplied(1, x, y).into());
+    }
+
+    transfer_job {
+        let (caller, _) = register_job::<T>(true, false);
+        let new_owner: T::AccountId = account("new_owner", 0, SEED);
         let local_job_id = 1;
-    }: _(RawOrigin::Signed(caller.clone()), local_job_id, account("processor", 0, SEED), env.clone())
+    }: _(RawOrigin::Signed(caller.clone()), local_job_id, new_owner.clone())
     verify {
-        assert_last_event::<T>(Event::ExecutionEnvironmentUpdated(
-            (MultiOrigin::Acurast(caller), 1), account("processor", 0, SEED)
+        assert_last_event::<T>(Event::JobTransferProposed(
+            (MultiOrigin::Acurast(caller), local_job_id), Some(new_owner)
+        ).into());
+    }
+
+    cancel_job_transfer {
+        let (caller, _) = register_job::<T>(true, false);
+        let local_job_id = 1;
+        let job_id = (MultiOrigin::Acurast(caller.clone()), local_job_id);
+        <JobTransfers<T>>::insert(&job_id, account::<T::AccountId>("new_owner", 0, SEED));
+    }: _(RawOrigin::Signed(caller.clone()), local_job_id)
+    verify {
+        assert_last_event::<T>(Event::JobTransferProposed(job_id, None).into());
+    }
+
+    accept_job_transfer {
+        let (caller, _) = register_job::<T>(true, false);
+        let new_owner: T::AccountId = account("new_owner", 0, SEED);
+        whitelist_account!(new_owner);
+        let local_job_id = 1;
+        let job_id = (MultiOrigin::Acurast(caller.clone()), local_job_id);
+        let env = environment::<T>(T::MaxEnvVars::get());
+        for i in 0..T::MaxSlots::get() {
+            let source: T::AccountId = account("processor", i, SEED);
+            <ExecutionEnvironment<T>>::insert(&job_id, &source, staged_environment_versions::<T>(&env));
+        }
+        <JobTransfers<T>>::insert(&job_id, &new_owner);
+    }: _(RawOrigin::Signed(new_owner.clone()), job_id.clone())
+    verify {
+        assert_last_event::<T>(Event::JobTransferred(
+            job_id, (MultiOrigin::Acurast(new_owner), local_job_id)
         ).into());
     }
 
+    create_job_template {
+        let (caller, job) = register_job::<T>(false, false);
+        let name: JobTemplateName = BoundedVec::truncate_from(vec![b'a'; JOB_TEMPLATE_NAME_MAX_LENGTH as usize]);
+    }: _(RawOrigin::Signed(caller.clone()), name.clone(), job)
+    verify {
+        assert_last_event::<T>(Event::JobTemplateCreated(1, caller, name).into());
+    }
+
+    remove_job_template {
+        let (caller, job) = register_job::<T>(false, false);
+        assert_ok!(Acurast::<T>::create_job_template(RawOrigin::Signed(caller.clone()).into(), JobTemplateName::default(), job));
+    }: _(RawOrigin::Signed(caller.clone()), 1)
+    verify {
+        assert_last_event::<T>(Event::JobTemplateRemoved(1).into());
+    }
+
+    register_from_template {
+        let (caller, job) = register_job::<T>(false, true);
+        assert_ok!(Acurast::<T>::create_job_template(RawOrigin::Signed(caller.clone()).into(), JobTemplateName::default(), job.clone()));
+    }: _(RawOrigin::Signed(caller.clone()), 1, job.schedule.clone(), None)
+    verify {
+        assert_last_event::<T>(Event::JobRegisteredFromTemplate(
+            (MultiOrigin::Acurast(caller), 1), 1
+        ).into());
+    }
+
+    update_allowed_app_ids {
+        let x in 1 .. T::MaxAllowedAppIdUpdates::get();
+        let origin = T::AllowedAppIdsUpdateOrigin::try_successful_origin()
+            .map_err(|_| BenchmarkError::Weightless)?;
+        let app_id: AppId = BoundedVec::truncate_from(vec![0u8; 128]);
+        let updates: BoundedVec<AppIdUpdate, T::MaxAllowedAppIdUpdates> =
+            vec![AppIdUpdate { operation: ListUpdateOperation::Add, item: app_id.clone() }; x as usize].try_into().unwrap();
+    }: _<T::RuntimeOrigin>(origin, updates)
+    verify {
+        assert_last_event::<T>(Event::AllowedAppIdAdded(app_id).into());
+    }
+
     impl_benchmark_test_suite!(Acurast, mock::ExtBuilder::default().build(), mock::Test);
 }
@@ -8,8 +8,11 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-mod migration;
+pub mod migration;
+#[cfg(feature = "std")]
+pub mod rpc;
 mod traits;
+pub mod types;
 pub mod utils;
 pub mod weights;
 
@@ -18,6 +21,10 @@ pub use acurast_common::*;
 pub use benchmarking::BenchmarkHelper;
 pub use pallet::*;
 pub use traits::*;
+pub use types::*;
+
+use frame_support::pallet_prelude::Get;
+use sp_std::prelude::*;
 
 pub type JobRegistrationFor<T> = JobRegistration<
     <T as frame_system::Config>::AccountId,
@@ -25,12 +32,31 @@ pub type JobRegistrationFor<T> = JobRegistration<
     <T as Config>::RegistrationExtra,
 >;
 
+pub type JobTemplateFor<T> = JobTemplate<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::MaxAllowedSources,
+    <T as Config>::RegistrationExtra,
+>;
+
+/// The reward offered by a job's [Config::RegistrationExtra].
+pub type RewardFor<T> = <<T as Config>::RegistrationExtra as RegistrationExtraReward>::Reward;
+
 pub type EnvironmentFor<T> = Environment<
     <T as Config>::MaxEnvVars,
     <T as Config>::EnvKeyMaxSize,
     <T as Config>::EnvValueMaxSize,
 >;
 
+pub type EnvironmentVersionFor<T> = EnvironmentVersion<
+    <T as Config>::MaxEnvVars,
+    <T as Config>::EnvKeyMaxSize,
+    <T as Config>::EnvValueMaxSize,
+>;
+
+/// The versions of an execution environment ordered by ascending `effective_from`.
+pub type EnvironmentVersionsFor<T> =
+    BoundedVec<EnvironmentVersionFor<T>, <T as Config>::MaxEnvironmentVersions>;
+
 #[frame_support::pallet]
 pub mod pallet {
     #[cfg(feature = "runtime-benchmarks")]
@@ -44,18 +70,39 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use sp_std::prelude::*;
 
-    use crate::{traits::*, utils::*, EnvironmentFor, JobRegistrationFor};
+    use crate::{
+        traits::*, types::*, utils::*, EnvironmentFor, JobRegistrationFor, JobTemplateFor,
+        RewardFor,
+    };
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// Extra structure to include in the registration of a job.
-        type RegistrationExtra: Parameter + Member + MaxEncodedLen;
+        type RegistrationExtra: Parameter + Member + MaxEncodedLen + RegistrationExtraReward;
+        /// Converts the [Config::RegistrationExtra] of job registrations stored before storage version 11. Use `()` if the
+        /// extra did not change with the upgrade.
+        type RegistrationExtraMigration: RegistrationExtraMigration<Self>;
         /// The max length of the allowed sources list for a registration.
         #[pallet::constant]
         type MaxAllowedSources: Get<u32> + ParameterBound;
         #[pallet::constant]
         type MaxCertificateRevocationListUpdates: Get<u32>;
+        /// The maximum length in bytes of a status list submitted with [Pallet::submit_revocation_status_list].
+        #[pallet::constant]
+        type MaxRevocationStatusListLength: Get<u32> + ParameterBound;
+        /// The maximum number of entries of a status list submitted with [Pallet::submit_revocation_status_list].
+        #[pallet::constant]
+        type MaxRevocationStatusListEntries: Get<u32>;
+        /// The key status list snapshots have to be signed with.
+        #[pallet::constant]
+        type RevocationStatusListSigner: Get<sp_core::ed25519::Public>;
+        /// The maximum number of trusted root certificates added or removed in a single call.
+        #[pallet::constant]
+        type MaxTrustedRootCertificateUpdates: Get<u32>;
+        /// The maximum number of allowed app ids added or removed in a single call.
+        #[pallet::constant]
+        type MaxAllowedAppIdUpdates: Get<u32>;
         /// The maximum allowed slots and therefore maximum length of the planned executions per job.
         #[pallet::constant]
         type MaxSlots: Get<u32> + ParameterBound;
@@ -68,10 +115,28 @@ pub mod pallet {
         type EnvKeyMaxSize: Get<u32> + ParameterBound;
         #[pallet::constant]
         type EnvValueMaxSize: Get<u32> + ParameterBound;
+        /// The maximum number of environment versions stored per job and source, including the one currently effective.
+        #[pallet::constant]
+        type MaxEnvironmentVersions: Get<u32> + ParameterBound;
+        /// The maximum number of job templates an account can own at the same time.
+        #[pallet::constant]
+        type MaxJobTemplatesPerOwner: Get<u32>;
         /// Barrier for the update_certificate_revocation_list extrinsic call.
         type RevocationListUpdateBarrier: RevocationListUpdateBarrier<Self>;
+        /// Origin allowed to update the trusted root certificates, e.g. root or a governance body.
+        type TrustedRootCertificatesUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to update the [AllowedAppIds], e.g. root or a governance body.
+        type AllowedAppIdsUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origin allowed to set the [AttestationPolicy] enforced by [AttestationPolicyBarrier].
+        type AttestationPolicyUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// The time in milliseconds ahead of an attestation's expiry from which on [Event::AttestationExpiringSoon] is emitted.
+        #[pallet::constant]
+        type AttestationExpiryGracePeriod: Get<u64>;
         /// Barrier for submit_attestation extrinsic call.
         type KeyAttestationBarrier: KeyAttestationBarrier<Self>;
+        /// Binding of the attested public key to the account submitting the attestation. [PublicKeyHashBinding] is the
+        /// recommended binding, accepting P-256, P-384 and RSA keys. [()] binds keys the same way.
+        type AttestationKeyBinding: AttestationKeyBinding<Self>;
         /// Timestamp
         type UnixTime: UnixTime;
         /// Hooks used by tightly coupled subpallets.
@@ -87,10 +152,15 @@ pub mod pallet {
     pub struct GenesisConfig<T: Config> {
         /// Genesis attestations considered valid without ever calling [`Pallet<T>::submit_attestation`] and therefore skipping validation!
         ///
-        /// Specify a list o tuples (account_id, attestation) or (account_id, None) to use default long-term valid attestation.
+        /// Specify a list o tuples (account_id, attestation) or (account_id, None) to use the long-term valid attestation
+        /// of [builder::AttestationBuilder::new], created on 1.1.2023. Use the builder to create custom attestations.
         ///
         /// This should only be used for test runtime configurations.
         pub attestations: Vec<(T::AccountId, Option<Attestation>)>,
+        /// The root certificates attestation chains are trusted to start with. Defaults to [TRUSTED_ROOT_CERTS].
+        pub trusted_root_certificates: Vec<CertificateInput>,
+        /// The app ids App Attest attestations are accepted for. Defaults to none.
+        pub allowed_app_ids: Vec<AppId>,
     }
 
     #[cfg(feature = "std")]
@@ -98,6 +168,11 @@ pub mod pallet {
         fn default() -> Self {
             Self {
                 attestations: vec![],
+                trusted_root_certificates: TRUSTED_ROOT_CERTS
+                    .iter()
+                    .map(|certificate| CertificateInput::truncate_from(certificate.to_vec()))
+                    .collect(),
+                allowed_app_ids: vec![],
             }
         }
     }
@@ -105,110 +180,32 @@ pub mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
+            for certificate in self.trusted_root_certificates.clone() {
+                <TrustedRootCertificates<T>>::insert(
+                    certificate_fingerprint(&certificate),
+                    certificate,
+                );
+            }
+            for app_id in self.allowed_app_ids.clone() {
+                <AllowedAppIds<T>>::insert(app_id, ());
+            }
             for (who, attestation) in self.attestations.clone() {
                 <StoredAttestation<T>>::insert(
                     &who,
-                    attestation.unwrap_or(Attestation {
-                        cert_ids: ValidatingCertIds::default(),
-                        key_description: BoundedKeyDescription {
-                            attestation_security_level: AttestationSecurityLevel::Unknown,
-                            key_mint_security_level: AttestationSecurityLevel::Unknown,
-                            software_enforced: BoundedAuthorizationList {
-                                purpose: None,
-                                algorithm: None,
-                                key_size: None,
-                                digest: None,
-                                padding: None,
-                                ec_curve: None,
-                                rsa_public_exponent: None,
-                                mgf_digest: None,
-                                rollback_resistance: None,
-                                early_boot_only: None,
-                                active_date_time: None,
-                                origination_expire_date_time: None,
-                                usage_expire_date_time: None,
-                                usage_count_limit: None,
-                                no_auth_required: false,
-                                user_auth_type: None,
-                                auth_timeout: None,
-                                allow_while_on_body: false,
-                                trusted_user_presence_required: None,
-                                trusted_confirmation_required: None,
-                                unlocked_device_required: None,
-                                all_applications: None,
-                                application_id: None,
-                                creation_date_time: Some(1_672_527_600_000), // 1.1.2023
-                                origin: None,
-                                root_of_trust: None,
-                                os_version: None,
-                                os_patch_level: None,
-                                attestation_application_id: None,
-                                attestation_id_brand: None,
-                                attestation_id_device: None,
-                                attestation_id_product: None,
-                                attestation_id_serial: None,
-                                attestation_id_imei: None,
-                                attestation_id_meid: None,
-                                attestation_id_manufacturer: None,
-                                attestation_id_model: None,
-                                vendor_patch_level: None,
-                                boot_patch_level: None,
-                                device_unique_attestation: None,
-                            },
-                            tee_enforced: BoundedAuthorizationList {
-                                purpose: None,
-                                algorithm: None,
-                                key_size: None,
-                                digest: None,
-                                padding: None,
-                                ec_curve: None,
-                                rsa_public_exponent: None,
-                                mgf_digest: None,
-                                rollback_resistance: None,
-                                early_boot_only: None,
-                                active_date_time: None,
-                                origination_expire_date_time: None,
-                                usage_expire_date_time: None,
-                                usage_count_limit: None,
-                                no_auth_required: false,
-                                user_auth_type: None,
-                                auth_timeout: None,
-                                allow_while_on_body: false,
-                                trusted_user_presence_required: None,
-                                trusted_confirmation_required: None,
-                                unlocked_device_required: None,
-                                all_applications: None,
-                                application_id: None,
-                                creation_date_time: None,
-                                origin: None,
-                                root_of_trust: None,
-                                os_version: None,
-                                os_patch_level: None,
-                                attestation_application_id: None,
-                                attestation_id_brand: None,
-                                attestation_id_device: None,
-                                attestation_id_product: None,
-                                attestation_id_serial: None,
-                                attestation_id_imei: None,
-                                attestation_id_meid: None,
-                                attestation_id_manufacturer: None,
-                                attestation_id_model: None,
-                                vendor_patch_level: None,
-                                boot_patch_level: None,
-                                device_unique_attestation: None,
-                            },
-                        },
-                        validity: AttestationValidity {
-                            not_before: 0,
-                            not_after: 4_102_441_200_000, // 1.1.2100
-                        },
+                    attestation.unwrap_or_else(|| {
+                        builder::AttestationBuilder::new()
+                            .creation_date_time(1_672_527_600_000) // 1.1.2023
+                            .build()
                     }),
                 );
             }
         }
     }
 
-    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    /// The maximum length of the [AttestationSweepCursor], fitting the pallet and storage prefixes, the hash and the account id.
+    pub(crate) const ATTESTATION_SWEEP_CURSOR_MAX_LENGTH: u32 = 128;
+
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(11);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -231,7 +228,9 @@ pub mod pallet {
         JobRegistrationFor<T>,
     >;
 
-    /// Env variables as a map [`JobId`] -> [`AccountId`] `(source)` -> [`EnvVars`].
+    /// Env variables as a map [`JobId`] -> [`AccountId`] `(source)` -> [`EnvironmentVersionsFor`].
+    ///
+    /// Each execution uses the latest version effective at the execution's start, see [effective_environment].
     #[pallet::storage]
     #[pallet::getter(fn execution_environment)]
     pub type ExecutionEnvironment<T: Config> = StorageDoubleMap<
@@ -240,7 +239,7 @@ pub mod pallet {
         JobId<T::AccountId>,
         Blake2_128Concat,
         T::AccountId,
-        EnvironmentFor<T>,
+        EnvironmentVersionsFor<T>,
     >;
 
     /// The storage for [Attestation]s. They are stored by [AccountId].
@@ -249,11 +248,78 @@ pub mod pallet {
     pub type StoredAttestation<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, Attestation>;
 
-    /// Certificate revocation list storage.
+    /// Certificate revocation list storage, certificates are identified by issuer and serial number.
     #[pallet::storage]
     #[pallet::getter(fn stored_revoked_certificate)]
     pub type StoredRevokedCertificate<T: Config> =
-        StorageMap<_, Blake2_128Concat, SerialNumber, ()>;
+        StorageMap<_, Blake2_128Concat, CertId, RevocationEntry>;
+
+    /// Revocations by serial number only, applied to certificates of any issuer. Populated by status list snapshots,
+    /// which do not identify the issuer, and by revocations migrated from before [StoredRevokedCertificate] included it.
+    #[pallet::storage]
+    #[pallet::getter(fn stored_revoked_serial_number)]
+    pub type StoredRevokedSerialNumber<T: Config> =
+        StorageMap<_, Blake2_128Concat, SerialNumber, RevocationEntry>;
+
+    /// The sequence number of the last status list snapshot applied with [Pallet::submit_revocation_status_list].
+    #[pallet::storage]
+    #[pallet::getter(fn revocation_status_list_sequence)]
+    pub type RevocationStatusListSequence<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// The serial numbers revoked by the last applied status list snapshot, removed from [StoredRevokedSerialNumber]
+    /// when missing in the next snapshot.
+    #[pallet::storage]
+    pub type RevocationStatusListEntries<T: Config> =
+        StorageValue<_, BoundedVec<SerialNumber, T::MaxRevocationStatusListEntries>, ValueQuery>;
+
+    /// The trusted root certificates Android key attestation chains have to start with, stored by their [CertificateFingerprint].
+    #[pallet::storage]
+    #[pallet::getter(fn trusted_root_certificate)]
+    pub type TrustedRootCertificates<T: Config> =
+        StorageMap<_, Identity, CertificateFingerprint, CertificateInput>;
+
+    /// The app ids (`<team id>.<bundle id>`) App Attest attestations are accepted for.
+    #[pallet::storage]
+    #[pallet::getter(fn allowed_app_id)]
+    pub type AllowedAppIds<T: Config> = StorageMap<_, Blake2_128Concat, AppId, ()>;
+
+    /// The [AttestationPolicy] enforced by [AttestationPolicyBarrier]. No policy accepts all attestations.
+    #[pallet::storage]
+    #[pallet::getter(fn attestation_policy)]
+    pub type StoredAttestationPolicy<T: Config> = StorageValue<_, AttestationPolicy>;
+
+    /// The raw storage key of the last [StoredAttestation] visited by the expiry sweep in `on_idle`.
+    #[pallet::storage]
+    pub type AttestationSweepCursor<T: Config> =
+        StorageValue<_, BoundedVec<u8, ConstU32<ATTESTATION_SWEEP_CURSOR_MAX_LENGTH>>>;
+
+    /// The accounts [Event::AttestationExpiringSoon] was emitted for, until they submit a new attestation or the attestation expires.
+    #[pallet::storage]
+    pub type AttestationExpiryNotified<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Pending job ownership transfers as a map [`JobId`] -> [`AccountId`] `(new owner)`, until accepted by the new owner.
+    #[pallet::storage]
+    #[pallet::getter(fn job_transfer)]
+    pub type JobTransfers<T: Config> =
+        StorageMap<_, Blake2_128Concat, JobId<T::AccountId>, T::AccountId>;
+
+    /// The last assigned [JobTemplateId].
+    #[pallet::storage]
+    #[pallet::getter(fn job_template_id_sequence)]
+    pub type JobTemplateIdSequence<T: Config> = StorageValue<_, JobTemplateId, ValueQuery>;
+
+    /// The storage for [JobTemplate]s as a map [`JobTemplateId`] -> [`JobTemplateFor`].
+    #[pallet::storage]
+    #[pallet::getter(fn stored_job_template)]
+    pub type StoredJobTemplate<T: Config> =
+        StorageMap<_, Blake2_128Concat, JobTemplateId, JobTemplateFor<T>>;
+
+    /// The number of [JobTemplate]s owned by an account, limited to [Config::MaxJobTemplatesPerOwner].
+    #[pallet::storage]
+    #[pallet::getter(fn job_template_count)]
+    pub type JobTemplateCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
@@ -275,8 +341,40 @@ pub mod pallet {
             T::AccountId,
             BoundedVec<CertificateRevocationListUpdate, T::MaxCertificateRevocationListUpdates>,
         ),
-        /// The execution environment has been updated. [job_id, source]
-        ExecutionEnvironmentUpdated(JobId<T::AccountId>, T::AccountId),
+        /// The execution environment has been updated. [job_id, source, effective_from]
+        ExecutionEnvironmentUpdated(JobId<T::AccountId>, T::AccountId, u64),
+        /// A registration was successfully updated. [job_id, old_registration, update]
+        JobRegistrationUpdated(
+            JobId<T::AccountId>,
+            JobRegistrationFor<T>,
+            JobRegistrationUpdate,
+        ),
+        /// A trusted root certificate was added. [fingerprint]
+        TrustedRootCertificateAdded(CertificateFingerprint),
+        /// A trusted root certificate was removed. [fingerprint]
+        TrustedRootCertificateRemoved(CertificateFingerprint),
+        /// The attestation policy has been set or removed. [policy]
+        AttestationPolicyUpdated(Option<AttestationPolicy>),
+        /// A status list snapshot was applied. [sequence, revoked, reinstated]
+        RevocationStatusListApplied(u64, u32, u32),
+        /// An expired attestation was removed. [who]
+        AttestationExpired(T::AccountId),
+        /// An attestation expires within [Config::AttestationExpiryGracePeriod]. [who, expiry]
+        AttestationExpiringSoon(T::AccountId, u64),
+        /// The transfer of a job to a new owner was proposed or cancelled if [None]. [job_id, new_owner]
+        JobTransferProposed(JobId<T::AccountId>, Option<T::AccountId>),
+        /// A job was transferred to a new owner, continuing under a new job id. [previous_job_id, job_id]
+        JobTransferred(JobId<T::AccountId>, JobId<T::AccountId>),
+        /// A job template was created. [template_id, owner, name]
+        JobTemplateCreated(JobTemplateId, T::AccountId, JobTemplateName),
+        /// A job template was removed. [template_id]
+        JobTemplateRemoved(JobTemplateId),
+        /// A job was registered from a job template, following [Event::JobRegistrationStored]. [job_id, template_id]
+        JobRegisteredFromTemplate(JobId<T::AccountId>, JobTemplateId),
+        /// An app id was added to the app ids App Attest attestations are accepted for. [app_id]
+        AllowedAppIdAdded(AppId),
+        /// An app id was removed from the app ids App Attest attestations are accepted for. [app_id]
+        AllowedAppIdRemoved(AppId),
     }
 
     #[pallet::error]
@@ -291,7 +389,7 @@ pub mod pallet {
         TooManyAllowedSources,
         /// The allowed soruces list for a registration cannot be empty if provided.
         TooFewAllowedSources,
-        /// The provided script value is not valid. The value needs to be and ipfs:// url.
+        /// The provided script locator is not valid, e.g. an IPFS CID with an invalid multibase or multihash.
         InvalidScriptValue,
         /// The provided attestation could not be parsed or is invalid.
         AttestationUsageExpired,
@@ -317,8 +415,8 @@ pub mod pallet {
         AttestationRejected,
         /// Timestamp error.
         FailedTimestampConversion,
-        /// Certificate was revoked.
-        RevokedCertificate,
+        /// Certificate was revoked, carrying the revocation status and reason.
+        RevokedCertificate(CertificateRevocation),
         /// Origin is not allowed to update the certificate revocation list.
         CertificateRevocationListUpdateNotAllowed,
         /// The attestation was issued for an unsupported public key type.
@@ -327,6 +425,40 @@ pub mod pallet {
         AttestationPublicKeyDoesNotMatchSource,
         /// Calling a job hook produced an error.
         JobHookFailed,
+        /// The submitted App Attest attestation object is not valid.
+        AppAttestationValidationFailed,
+        /// The root certificate to add is not a valid self-signed certificate.
+        InvalidTrustedRootCertificate,
+        /// The root certificate to remove is not trusted.
+        TrustedRootCertificateNotFound,
+        /// The status list snapshot is not signed by [Config::RevocationStatusListSigner].
+        InvalidRevocationStatusListSignature,
+        /// The status list snapshot's sequence number is not greater than the one of the last applied snapshot.
+        OutdatedRevocationStatusList,
+        /// The status list is not valid.
+        InvalidRevocationStatusList,
+        /// The status list contains more than [Config::MaxRevocationStatusListEntries] entries.
+        TooManyRevocationStatusListEntries,
+        /// The environment would become effective before the current time.
+        EnvironmentEffectiveFromInPast,
+        /// The source already has [Config::MaxEnvironmentVersions] environment versions that are not superseded.
+        TooManyEnvironmentVersions,
+        /// No transfer of the job to the caller is pending.
+        JobTransferNotFound,
+        /// A job cannot be transferred to its current owner.
+        JobTransferToOwner,
+        /// The job id under the new owner is already taken.
+        JobTransferJobIdTaken,
+        /// The job template was not found.
+        JobTemplateNotFound,
+        /// Only the owner of a job template can use or remove it.
+        NotJobTemplateOwner,
+        /// The App Attest attestation was created for an app id not contained in [AllowedAppIds].
+        AppIdNotAllowed,
+        /// The app id to remove is not contained in [AllowedAppIds].
+        AllowedAppIdNotFound,
+        /// The caller already owns [Config::MaxJobTemplatesPerOwner] job templates.
+        TooManyJobTemplates,
     }
 
     #[pallet::hooks]
@@ -334,6 +466,10 @@ pub mod pallet {
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
             crate::migration::migrate::<T>()
         }
+
+        fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::sweep_attestations(remaining_weight)
+        }
     }
 
     #[pallet::call]
@@ -425,11 +561,17 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Submits an attestation given a valid certificate chain.
+        /// Submits an attestation given a valid certificate chain or App Attest attestation object.
         ///
-        /// - As input a list of binary certificates is expected.
-        /// - The list must be ordered, starting from one of the known [trusted root certificates](https://developer.android.com/training/articles/security-key-attestation#root_certificate).
-        /// - If the represented chain is valid, the [Attestation] details are stored. An existing attestion for signing account gets overwritten.
+        /// - For an [AttestationChain::AndroidKeyAttestation] a list of binary certificates is expected.
+        ///   The list must be ordered, starting from one of the known [trusted root certificates](https://developer.android.com/training/articles/security-key-attestation#root_certificate).
+        /// - For an [AttestationChain::AppleAppAttest] the attestation object returned by `DCAppAttestService.attestKey` is expected,
+        ///   attesting a key for a client data hash equal to the sha256 hash of the SCALE encoded signing account. The app id has to
+        ///   be contained in [AllowedAppIds].
+        ///
+        /// Note that the [AttestationChain] is SCALE encoded with a leading variant index since the introduction of App Attest,
+        /// see [AttestationChain] for migrating clients.
+        /// - If the attestation is valid, the [Attestation] details are stored. An existing attestion for signing account gets overwritten.
         ///
         /// Revocation: Each atttestation is stored with the unique IDs of the certificates on the chain proofing the attestation's validity.
         #[pallet::call_index(5)]
@@ -439,10 +581,14 @@ pub mod pallet {
             attestation_chain: AttestationChain,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-            ensure!(
-                (&attestation_chain).certificate_chain.len() >= 2,
-                Error::<T>::CertificateChainTooShort,
-            );
+            if let AttestationChain::AndroidKeyAttestation { certificate_chain } =
+                &attestation_chain
+            {
+                ensure!(
+                    certificate_chain.len() >= 2,
+                    Error::<T>::CertificateChainTooShort,
+                );
+            }
 
             let attestation = validate_and_extract_attestation::<T>(&who, &attestation_chain)?;
 
@@ -455,13 +601,17 @@ pub mod pallet {
             ensure_not_revoked::<T>(&attestation)?;
 
             <StoredAttestation<T>>::insert(&who, attestation.clone());
+            <AttestationExpiryNotified<T>>::remove(&who);
             Self::deposit_event(Event::AttestationStored(attestation, who));
             Ok(().into())
         }
 
-        /// Updates the certificate revocation list by adding or removing a revoked certificate serial number. Attestations signed
-        /// by a revoked certificate will not be considered valid anymore. The `RevocationListUpdateBarrier` configured in [Config] can be used to
-        /// customize who can execute this action.
+        /// Updates the certificate revocation list by adding or removing a revoked certificate identified by issuer and serial number.
+        /// Attestations signed by a revoked certificate will not be considered valid anymore, work performed before the revocation is
+        /// still honored. The `RevocationListUpdateBarrier` configured in [Config] can be used to customize who can execute this action.
+        ///
+        /// Removing a certificate also removes a revocation of its serial number not managed by the status list, as migrated from
+        /// before revocations were identified by issuer.
         #[pallet::weight(<T as Config>::WeightInfo::update_certificate_revocation_list())]
         #[pallet::call_index(6)]
         pub fn update_certificate_revocation_list(
@@ -475,13 +625,28 @@ pub mod pallet {
             if !T::RevocationListUpdateBarrier::can_update_revocation_list(&who, &updates) {
                 return Err(Error::<T>::CertificateRevocationListUpdateNotAllowed)?;
             }
+            let now = now::<T>()?;
+            let status_list_entries = <RevocationStatusListEntries<T>>::get();
             for update in &updates {
                 match &update.operation {
                     ListUpdateOperation::Add => {
-                        <StoredRevokedCertificate<T>>::insert(&update.item, ());
+                        <StoredRevokedCertificate<T>>::insert(
+                            &update.item,
+                            RevocationEntry {
+                                revocation: CertificateRevocation {
+                                    status: RevocationStatus::Revoked,
+                                    reason: RevocationReason::Unspecified,
+                                },
+                                revoked_at: Some(now),
+                            },
+                        );
                     }
                     ListUpdateOperation::Remove => {
                         <StoredRevokedCertificate<T>>::remove(&update.item);
+                        let (_, serial_number) = &update.item;
+                        if !status_list_entries.contains(serial_number) {
+                            <StoredRevokedSerialNumber<T>>::remove(serial_number);
+                        }
                     }
                 }
             }
@@ -489,9 +654,10 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Updates the certificate revocation list by adding or removing a revoked certificate serial number. Attestations signed
-        /// by a revoked certificate will not be considered valid anymore. The `RevocationListUpdateBarrier` configured in [Config] can be used to
-        /// customize who can execute this action.
+        /// Sets the execution environment of a job for a source, effective for executions starting at or after `effective_from`
+        /// or immediately if [None].
+        ///
+        /// Versions superseded before the current time are removed, while earlier executions keep using the previous version.
         #[pallet::weight(<T as Config>::WeightInfo::set_environment(environment.variables.len() as u32))]
         #[pallet::call_index(7)]
         pub fn set_environment(
@@ -499,11 +665,335 @@ pub mod pallet {
             job_id_seq: JobIdSequence,
             source: T::AccountId,
             environment: EnvironmentFor<T>,
+            effective_from: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let multi_origin = MultiOrigin::Acurast(who);
+            let job_id: JobId<T::AccountId> = (multi_origin, job_id_seq);
+            Self::set_environment_for(job_id, source, environment, effective_from)
+        }
+
+        /// Updates parts of a [JobRegistration] without deregistering it.
+        ///
+        /// Which changes are accepted depends on the [Config::JobHooks], e.g. the marketplace only accepts
+        /// extensions of the schedule's `end_time` once a job is matched.
+        #[pallet::call_index(8)]
+        #[pallet::weight(< T as Config >::WeightInfo::update_registration())]
+        pub fn update_registration(
+            origin: OriginFor<T>,
+            local_job_id: JobIdSequence,
+            update: JobRegistrationUpdate,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let multi_origin = MultiOrigin::Acurast(who);
+            let job_id: JobId<T::AccountId> = (multi_origin, local_job_id);
+            Self::update_registration_for(job_id, update)
+        }
+
+        /// Adds or removes trusted root certificates. Android key attestation chains are only accepted if they start with a trusted root certificate.
+        ///
+        /// Added certificates have to be valid self-signed certificates and are stored by their [CertificateFingerprint].
+        /// The `TrustedRootCertificatesUpdateOrigin` configured in [Config] determines who can execute this action.
+        #[pallet::call_index(9)]
+        #[pallet::weight(< T as Config >::WeightInfo::update_trusted_root_certificates(updates.len() as u32))]
+        pub fn update_trusted_root_certificates(
+            origin: OriginFor<T>,
+            updates: BoundedVec<TrustedRootCertificateUpdate, T::MaxTrustedRootCertificateUpdates>,
+        ) -> DispatchResultWithPostInfo {
+            T::TrustedRootCertificatesUpdateOrigin::ensure_origin(origin)?;
+            for update in updates {
+                match update {
+                    TrustedRootCertificateUpdate::Add(certificate) => {
+                        validate_certificate_chain_slices(&[certificate.as_slice()])
+                            .map_err(|_| Error::<T>::InvalidTrustedRootCertificate)?;
+                        let fingerprint = certificate_fingerprint(&certificate);
+                        <TrustedRootCertificates<T>>::insert(fingerprint, certificate);
+                        Self::deposit_event(Event::TrustedRootCertificateAdded(fingerprint));
+                    }
+                    TrustedRootCertificateUpdate::Remove(fingerprint) => {
+                        ensure!(
+                            <TrustedRootCertificates<T>>::contains_key(fingerprint),
+                            Error::<T>::TrustedRootCertificateNotFound
+                        );
+                        <TrustedRootCertificates<T>>::remove(fingerprint);
+                        Self::deposit_event(Event::TrustedRootCertificateRemoved(fingerprint));
+                    }
+                }
+            }
+            Ok(().into())
+        }
+
+        /// Sets or removes the [AttestationPolicy] enforced by [AttestationPolicyBarrier].
+        ///
+        /// The policy only applies to attestations submitted after the update, already stored attestations are not re-evaluated.
+        /// The `AttestationPolicyUpdateOrigin` configured in [Config] determines who can execute this action.
+        #[pallet::call_index(10)]
+        #[pallet::weight(< T as Config >::WeightInfo::set_attestation_policy())]
+        pub fn set_attestation_policy(
+            origin: OriginFor<T>,
+            policy: Option<AttestationPolicy>,
+        ) -> DispatchResultWithPostInfo {
+            T::AttestationPolicyUpdateOrigin::ensure_origin(origin)?;
+            <StoredAttestationPolicy<T>>::set(policy.clone());
+            Self::deposit_event(Event::AttestationPolicyUpdated(policy));
+            Ok(().into())
+        }
+
+        /// Applies a snapshot of Google's attestation status list signed by [Config::RevocationStatusListSigner].
+        ///
+        /// Revoked and suspended certificates of the snapshot are added to the certificate revocation list together with the
+        /// revocation reason, while certificates contained in the previously applied snapshot but missing in this one are removed.
+        /// Since the status list identifies certificates by serial number only, these revocations are stored in
+        /// [StoredRevokedSerialNumber] and apply to certificates of any issuer with a matching serial number.
+        /// Certificates revoked with [Pallet::update_certificate_revocation_list] are not affected unless contained in a snapshot.
+        #[pallet::call_index(11)]
+        #[pallet::weight(< T as Config >::WeightInfo::submit_revocation_status_list(
+            snapshot.status_list.len() as u32,
+            T::MaxRevocationStatusListEntries::get(),
+        ))]
+        pub fn submit_revocation_status_list(
+            origin: OriginFor<T>,
+            snapshot: RevocationStatusListSnapshot<T::MaxRevocationStatusListLength>,
+            signature: sp_core::ed25519::Signature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            ensure!(
+                sp_io::crypto::ed25519_verify(
+                    &signature,
+                    &snapshot.encode(),
+                    &T::RevocationStatusListSigner::get()
+                ),
+                Error::<T>::InvalidRevocationStatusListSignature
+            );
+            ensure!(
+                snapshot.sequence > <RevocationStatusListSequence<T>>::get(),
+                Error::<T>::OutdatedRevocationStatusList
+            );
+
+            let entries = status_list::parse_status_list(&snapshot.status_list)
+                .map_err(|_| Error::<T>::InvalidRevocationStatusList)?;
+            let serial_numbers: BoundedVec<SerialNumber, T::MaxRevocationStatusListEntries> =
+                entries
+                    .iter()
+                    .map(|(serial_number, _)| serial_number.clone())
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .map_err(|_| Error::<T>::TooManyRevocationStatusListEntries)?;
+
+            let now = now::<T>()?;
+            let mut reinstated: u32 = 0;
+            for serial_number in <RevocationStatusListEntries<T>>::get() {
+                if !serial_numbers.contains(&serial_number) {
+                    <StoredRevokedSerialNumber<T>>::remove(&serial_number);
+                    reinstated += 1;
+                }
+            }
+            for (serial_number, revocation) in entries {
+                match <StoredRevokedSerialNumber<T>>::get(&serial_number) {
+                    Some(entry) if entry.revocation == revocation => {}
+                    // a changed status or reason keeps the time the certificate was first revoked
+                    entry => <StoredRevokedSerialNumber<T>>::insert(
+                        &serial_number,
+                        RevocationEntry {
+                            revocation,
+                            revoked_at: entry.map_or(Some(now), |entry| entry.revoked_at),
+                        },
+                    ),
+                }
+            }
+
+            <RevocationStatusListEntries<T>>::put(&serial_numbers);
+            <RevocationStatusListSequence<T>>::put(snapshot.sequence);
+            Self::deposit_event(Event::RevocationStatusListApplied(
+                snapshot.sequence,
+                serial_numbers.len() as u32,
+                reinstated,
+            ));
+            Ok(().into())
+        }
+
+        /// Sets the execution environments of a job for multiple sources in one call, e.g. the same variables encrypted for
+        /// each processor assigned to the job. The environments are effective for executions starting at or after
+        /// `effective_from` or immediately if [None], see [Pallet::set_environment].
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_environments(environments.len() as u32))]
+        pub fn set_environments(
+            origin: OriginFor<T>,
+            job_id_seq: JobIdSequence,
+            environments: BoundedVec<(T::AccountId, EnvironmentFor<T>), T::MaxSlots>,
+            effective_from: Option<u64>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             let multi_origin = MultiOrigin::Acurast(who);
             let job_id: JobId<T::AccountId> = (multi_origin, job_id_seq);
-            Self::set_environment_for(job_id, source, environment)
+            Self::set_environments_for(job_id, environments, effective_from)
+        }
+
+        /// Proposes to transfer the ownership of a job to `new_owner`, replacing a previously proposed transfer.
+        ///
+        /// The transfer only takes effect once accepted by the new owner using [Pallet::accept_job_transfer].
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_job())]
+        pub fn transfer_job(
+            origin: OriginFor<T>,
+            local_job_id: JobIdSequence,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(who != new_owner, Error::<T>::JobTransferToOwner);
+            let job_id: JobId<T::AccountId> = (MultiOrigin::Acurast(who), local_job_id);
+            ensure!(
+                <StoredJobRegistration<T>>::contains_key(&job_id.0, &job_id.1),
+                Error::<T>::JobRegistrationNotFound
+            );
+            <JobTransfers<T>>::insert(&job_id, &new_owner);
+            Self::deposit_event(Event::JobTransferProposed(job_id, Some(new_owner)));
+            Ok(().into())
+        }
+
+        /// Cancels a pending transfer of a job proposed with [Pallet::transfer_job].
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_job_transfer())]
+        pub fn cancel_job_transfer(
+            origin: OriginFor<T>,
+            local_job_id: JobIdSequence,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let job_id: JobId<T::AccountId> = (MultiOrigin::Acurast(who), local_job_id);
+            <JobTransfers<T>>::take(&job_id).ok_or(Error::<T>::JobTransferNotFound)?;
+            Self::deposit_event(Event::JobTransferProposed(job_id, None));
+            Ok(().into())
+        }
+
+        /// Accepts the pending transfer of the job `job_id` to the caller.
+        ///
+        /// The job continues under the job id `(caller, job_id.1)`, together with its execution environments and the state
+        /// kept by [Config::JobHooks], e.g. the marketplace's remaining budget refunded to the new owner. [Config::JobHooks]
+        /// can reject the transfer, e.g. the marketplace rejects transfers of jobs with assigned processors.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_job_transfer())]
+        pub fn accept_job_transfer(
+            origin: OriginFor<T>,
+            job_id: JobId<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                <JobTransfers<T>>::get(&job_id).as_ref() == Some(&who),
+                Error::<T>::JobTransferNotFound
+            );
+            Self::transfer_job_for(job_id, who)
+        }
+
+        /// Stores a named job template, see [Pallet::register_from_template].
+        ///
+        /// The registration is validated like in [Pallet::register], except for the requirements checked by
+        /// [Config::JobHooks] that are only checked when registering a job from the template.
+        /// An account can own at most [Config::MaxJobTemplatesPerOwner] templates.
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_job_template())]
+        pub fn create_job_template(
+            origin: OriginFor<T>,
+            name: JobTemplateName,
+            registration: JobRegistrationFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_valid_registration(&registration)?;
+            <JobTemplateCount<T>>::try_mutate(&who, |count| {
+                ensure!(
+                    *count < T::MaxJobTemplatesPerOwner::get(),
+                    Error::<T>::TooManyJobTemplates
+                );
+                *count += 1;
+                Ok::<(), Error<T>>(())
+            })?;
+            let template_id = <JobTemplateIdSequence<T>>::mutate(|template_id| {
+                template_id.add_assign(1);
+                *template_id
+            });
+            <StoredJobTemplate<T>>::insert(
+                template_id,
+                JobTemplate {
+                    owner: who.clone(),
+                    name: name.clone(),
+                    registration,
+                },
+            );
+            Self::deposit_event(Event::JobTemplateCreated(template_id, who, name));
+            Ok(().into())
+        }
+
+        /// Removes a job template. Jobs registered from the template are not affected.
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_job_template())]
+        pub fn remove_job_template(
+            origin: OriginFor<T>,
+            template_id: JobTemplateId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let template =
+                <StoredJobTemplate<T>>::get(template_id).ok_or(Error::<T>::JobTemplateNotFound)?;
+            ensure!(template.owner == who, Error::<T>::NotJobTemplateOwner);
+            <StoredJobTemplate<T>>::remove(template_id);
+            <JobTemplateCount<T>>::mutate(&who, |count| *count = count.saturating_sub(1));
+            Self::deposit_event(Event::JobTemplateRemoved(template_id));
+            Ok(().into())
+        }
+
+        /// Registers a job from a job template owned by the caller, with the given `schedule` and optionally overriding
+        /// the template's reward.
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::register_from_template())]
+        pub fn register_from_template(
+            origin: OriginFor<T>,
+            template_id: JobTemplateId,
+            schedule: Schedule,
+            reward: Option<RewardFor<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let template =
+                <StoredJobTemplate<T>>::get(template_id).ok_or(Error::<T>::JobTemplateNotFound)?;
+            ensure!(template.owner == who, Error::<T>::NotJobTemplateOwner);
+
+            let mut registration = template.registration;
+            registration.schedule = schedule;
+            if let Some(reward) = reward {
+                registration.extra.set_reward(reward);
+            }
+
+            let job_id = (MultiOrigin::Acurast(who), Self::next_job_id());
+            Self::register_for(job_id.clone(), registration)?;
+            Self::deposit_event(Event::JobRegisteredFromTemplate(job_id, template_id));
+            Ok(().into())
+        }
+
+        /// Adds or removes app ids App Attest attestations are accepted for.
+        ///
+        /// Already stored attestations of removed app ids are not re-evaluated.
+        /// The `AllowedAppIdsUpdateOrigin` configured in [Config] determines who can execute this action.
+        #[pallet::call_index(19)]
+        #[pallet::weight(< T as Config >::WeightInfo::update_allowed_app_ids(updates.len() as u32))]
+        pub fn update_allowed_app_ids(
+            origin: OriginFor<T>,
+            updates: BoundedVec<AppIdUpdate, T::MaxAllowedAppIdUpdates>,
+        ) -> DispatchResultWithPostInfo {
+            T::AllowedAppIdsUpdateOrigin::ensure_origin(origin)?;
+            for update in updates {
+                match update.operation {
+                    ListUpdateOperation::Add => {
+                        <AllowedAppIds<T>>::insert(&update.item, ());
+                        Self::deposit_event(Event::AllowedAppIdAdded(update.item));
+                    }
+                    ListUpdateOperation::Remove => {
+                        ensure!(
+                            <AllowedAppIds<T>>::contains_key(&update.item),
+                            Error::<T>::AllowedAppIdNotFound
+                        );
+                        <AllowedAppIds<T>>::remove(&update.item);
+                        Self::deposit_event(Event::AllowedAppIdRemoved(update.item));
+                    }
+                }
+            }
+            Ok(().into())
         }
     }
 
@@ -525,6 +1015,17 @@ pub mod pallet {
             job_id: JobId<T::AccountId>,
             registration: JobRegistrationFor<T>,
         ) -> DispatchResultWithPostInfo {
+            Self::ensure_valid_registration(&registration)?;
+
+            <StoredJobRegistration<T>>::insert(&job_id.0, &job_id.1, registration.clone());
+
+            <T as Config>::JobHooks::register_hook(&job_id.0, &job_id, &registration)?;
+
+            Self::deposit_event(Event::JobRegistrationStored(registration, job_id.clone()));
+            Ok(().into())
+        }
+
+        fn ensure_valid_registration(registration: &JobRegistrationFor<T>) -> Result<(), Error<T>> {
             ensure!(
                 is_valid_script(&registration.script),
                 Error::<T>::InvalidScriptValue
@@ -537,37 +1038,300 @@ pub mod pallet {
                     Error::<T>::TooManyAllowedSources
                 );
             }
+            Ok(())
+        }
 
-            <StoredJobRegistration<T>>::insert(&job_id.0, &job_id.1, registration.clone());
+        /// Updates a job registered by [`multi_origin`].
+        ///
+        /// It assumes the caller was already authorized and is intended to be used from
+        /// * The [`Self::update_registration`] extrinsic of this pallet
+        /// * An inter-chain communication protocol like Hyperdrive
+        pub fn update_registration_for(
+            job_id: JobId<T::AccountId>,
+            update: JobRegistrationUpdate,
+        ) -> DispatchResultWithPostInfo {
+            let previous = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(Error::<T>::JobRegistrationNotFound)?;
+            let registration = previous.updated(&update);
 
-            <T as Config>::JobHooks::register_hook(&job_id.0, &job_id, &registration)?;
+            <T as Config>::JobHooks::update_registration_hook(
+                &job_id.0,
+                &job_id,
+                &previous,
+                &registration,
+            )?;
 
-            Self::deposit_event(Event::JobRegistrationStored(registration, job_id.clone()));
+            <StoredJobRegistration<T>>::insert(&job_id.0, &job_id.1, registration);
+
+            Self::deposit_event(Event::JobRegistrationUpdated(job_id, previous, update));
             Ok(().into())
         }
 
         pub fn deregister_for(job_id: JobId<T::AccountId>) -> DispatchResultWithPostInfo {
             <T as Config>::JobHooks::deregister_hook(&job_id)?;
-            Self::clear_environment_for(&job_id);
-            <StoredJobRegistration<T>>::remove(&job_id.0, &job_id.1);
+            Self::remove_registration_for(&job_id);
             Self::deposit_event(Event::JobRegistrationRemoved(job_id));
             Ok(().into())
         }
 
+        /// Transfers a job to `new_owner`, moving its registration and execution environments to the job id
+        /// `(new_owner, job_id.1)`.
+        ///
+        /// It assumes the transfer was already authorized by the current and the new owner.
+        pub fn transfer_job_for(
+            job_id: JobId<T::AccountId>,
+            new_owner: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let new_job_id: JobId<T::AccountId> = (MultiOrigin::Acurast(new_owner), job_id.1);
+            ensure!(new_job_id != job_id, Error::<T>::JobTransferToOwner);
+            ensure!(
+                !<StoredJobRegistration<T>>::contains_key(&new_job_id.0, &new_job_id.1),
+                Error::<T>::JobTransferJobIdTaken
+            );
+            let registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(Error::<T>::JobRegistrationNotFound)?;
+
+            <T as Config>::JobHooks::transfer_hook(&job_id, &new_job_id)?;
+
+            <StoredJobRegistration<T>>::remove(&job_id.0, &job_id.1);
+            <StoredJobRegistration<T>>::insert(&new_job_id.0, &new_job_id.1, registration);
+            let environments = <ExecutionEnvironment<T>>::drain_prefix(&job_id).collect::<Vec<_>>();
+            for (source, versions) in environments {
+                <ExecutionEnvironment<T>>::insert(&new_job_id, source, versions);
+            }
+            <JobTransfers<T>>::remove(&job_id);
+
+            Self::deposit_event(Event::JobTransferred(job_id, new_job_id));
+            Ok(().into())
+        }
+
         pub fn set_environment_for(
             job_id: JobId<T::AccountId>,
             source: T::AccountId,
             environment: EnvironmentFor<T>,
+            effective_from: Option<u64>,
         ) -> DispatchResultWithPostInfo {
             let _registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
                 .ok_or(Error::<T>::JobRegistrationNotFound)?;
-            <ExecutionEnvironment<T>>::insert(&job_id, source.clone(), environment);
-            Self::deposit_event(Event::ExecutionEnvironmentUpdated(job_id, source));
+            let now = now::<T>()?;
+            let effective_from = effective_from.unwrap_or(now);
+            ensure!(
+                effective_from >= now,
+                Error::<T>::EnvironmentEffectiveFromInPast
+            );
+            <T as Config>::JobHooks::set_environment_hook(&job_id, &source, &environment)?;
+            Self::insert_environment_version(&job_id, &source, now, effective_from, environment)?;
+            Self::deposit_event(Event::ExecutionEnvironmentUpdated(
+                job_id,
+                source,
+                effective_from,
+            ));
             Ok(().into())
         }
 
+        /// Sets the execution environments of a job for multiple sources, e.g. all processors assigned to the job,
+        /// effective for executions starting at or after `effective_from` or immediately if [None].
+        pub fn set_environments_for(
+            job_id: JobId<T::AccountId>,
+            environments: BoundedVec<(T::AccountId, EnvironmentFor<T>), T::MaxSlots>,
+            effective_from: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            let _registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(Error::<T>::JobRegistrationNotFound)?;
+            let now = now::<T>()?;
+            let effective_from = effective_from.unwrap_or(now);
+            ensure!(
+                effective_from >= now,
+                Error::<T>::EnvironmentEffectiveFromInPast
+            );
+            for (source, environment) in environments {
+                <T as Config>::JobHooks::set_environment_hook(&job_id, &source, &environment)?;
+                Self::insert_environment_version(
+                    &job_id,
+                    &source,
+                    now,
+                    effective_from,
+                    environment,
+                )?;
+                Self::deposit_event(Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    source,
+                    effective_from,
+                ));
+            }
+            Ok(().into())
+        }
+
+        /// Inserts a version of the environment of `source`, replacing a version with the same `effective_from`
+        /// and removing versions superseded before `now`.
+        fn insert_environment_version(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            now: u64,
+            effective_from: u64,
+            environment: EnvironmentFor<T>,
+        ) -> Result<(), Error<T>> {
+            <ExecutionEnvironment<T>>::try_mutate(job_id, source, |versions| {
+                let mut updated = versions.take().unwrap_or_default().into_inner();
+                // keep the version currently effective since executions started before `effective_from` still use it
+                let effective = updated
+                    .iter()
+                    .rposition(|version| version.effective_from <= now);
+                if let Some(effective) = effective {
+                    updated.drain(..effective);
+                }
+                updated.retain(|version| version.effective_from != effective_from);
+                let index =
+                    updated.partition_point(|version| version.effective_from < effective_from);
+                updated.insert(
+                    index,
+                    EnvironmentVersion {
+                        effective_from,
+                        environment,
+                    },
+                );
+                *versions = Some(
+                    updated
+                        .try_into()
+                        .map_err(|_| Error::<T>::TooManyEnvironmentVersions)?,
+                );
+                Ok(())
+            })
+        }
+
+        /// Returns the environment of `source` effective for an execution of the job starting at `execution_start`.
+        pub fn environment_at(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            execution_start: u64,
+        ) -> Option<EnvironmentFor<T>> {
+            let versions = <ExecutionEnvironment<T>>::get(job_id, source)?;
+            effective_environment(&versions, execution_start).cloned()
+        }
+
         pub fn clear_environment_for(job_id: &JobId<T::AccountId>) {
             let _ = <ExecutionEnvironment<T>>::clear_prefix(job_id, T::MaxSlots::get(), None);
         }
+
+        /// Removes the registration of a job together with its execution environments and pending transfer.
+        ///
+        /// The state kept by [Config::JobHooks] is not touched, it is the responsibility of the caller.
+        pub fn remove_registration_for(job_id: &JobId<T::AccountId>) {
+            Self::clear_environment_for(job_id);
+            <JobTransfers<T>>::remove(job_id);
+            <StoredJobRegistration<T>>::remove(&job_id.0, &job_id.1);
+        }
+
+        /// Returns up to `limit` job registrations of `origin`, continuing after the job `start_after` if provided.
+        ///
+        /// The registrations are ordered by the hash of their [JobIdSequence] and `limit` is capped at [MAX_JOB_REGISTRATIONS_PAGE_SIZE].
+        pub fn job_registrations(
+            origin: &MultiOrigin<T::AccountId>,
+            start_after: Option<JobIdSequence>,
+            limit: u32,
+        ) -> Vec<(JobIdSequence, JobRegistrationFor<T>)> {
+            let registrations = match start_after {
+                Some(job_id_seq) => <StoredJobRegistration<T>>::iter_prefix_from(
+                    origin,
+                    <StoredJobRegistration<T>>::hashed_key_for(origin, job_id_seq),
+                ),
+                None => <StoredJobRegistration<T>>::iter_prefix(origin),
+            };
+            registrations
+                .take(limit.min(MAX_JOB_REGISTRATIONS_PAGE_SIZE) as usize)
+                .collect()
+        }
+
+        /// Returns the attestation of `source` together with its decoded security levels and validity.
+        pub fn attestation_info(source: &T::AccountId) -> Option<AttestationInfo> {
+            <StoredAttestation<T>>::get(source).map(AttestationInfo::from)
+        }
+
+        /// Returns whether `source` has a valid (not expired and not revoked) attestation at the current time.
+        pub fn is_verified(source: &T::AccountId) -> Result<bool, RuntimeApiError> {
+            match ensure_source_verified::<T>(source) {
+                Ok(()) => Ok(true),
+                Err(Error::<T>::FailedTimestampConversion) => {
+                    Err(RuntimeApiError::IsVerified
+                        .log_error(Error::<T>::FailedTimestampConversion))
+                }
+                Err(_) => Ok(false),
+            }
+        }
+
+        /// Removes expired attestations and notifies about attestations expiring soon, visiting as many
+        /// [StoredAttestation]s as fit into `remaining_weight`.
+        ///
+        /// The sweep continues where the previous one stopped and starts over once all attestations were visited.
+        pub(crate) fn sweep_attestations(remaining_weight: Weight) -> Weight {
+            let mut consumed_weight = T::DbWeight::get().reads_writes(2, 1);
+            let sweep_weight = <T as Config>::WeightInfo::sweep_attestation();
+            if remaining_weight.any_lt(consumed_weight.saturating_add(sweep_weight)) {
+                return Weight::zero();
+            }
+            let now: u64 = match T::UnixTime::now().as_millis().try_into() {
+                Ok(now) => now,
+                Err(_) => return consumed_weight,
+            };
+
+            let mut attestations = match <AttestationSweepCursor<T>>::get() {
+                Some(cursor) => <StoredAttestation<T>>::iter_from(cursor.into_inner()),
+                None => <StoredAttestation<T>>::iter(),
+            };
+            let mut completed = false;
+            while !remaining_weight.any_lt(consumed_weight.saturating_add(sweep_weight)) {
+                match attestations.next() {
+                    Some((who, attestation)) => {
+                        Self::sweep_attestation(now, &who, &attestation);
+                        consumed_weight.saturating_accrue(sweep_weight);
+                    }
+                    None => {
+                        completed = true;
+                        break;
+                    }
+                }
+            }
+
+            // a cursor exceeding the bound restarts the sweep from the beginning
+            match BoundedVec::try_from(attestations.last_raw_key().to_vec()) {
+                Ok(cursor) if !completed => <AttestationSweepCursor<T>>::put(cursor),
+                _ => <AttestationSweepCursor<T>>::kill(),
+            }
+            consumed_weight
+        }
+
+        /// Removes the attestation of `who` if expired at `now` or emits [Event::AttestationExpiringSoon]
+        /// once it enters the [Config::AttestationExpiryGracePeriod].
+        pub(crate) fn sweep_attestation(now: u64, who: &T::AccountId, attestation: &Attestation) {
+            let expiry = attestation_expiry(attestation);
+            if now >= expiry {
+                <StoredAttestation<T>>::remove(who);
+                <AttestationExpiryNotified<T>>::remove(who);
+                Self::deposit_event(Event::AttestationExpired(who.clone()));
+            } else if now >= expiry.saturating_sub(T::AttestationExpiryGracePeriod::get())
+                && !<AttestationExpiryNotified<T>>::contains_key(who)
+            {
+                <AttestationExpiryNotified<T>>::insert(who, ());
+                Self::deposit_event(Event::AttestationExpiringSoon(who.clone(), expiry));
+            }
+        }
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// API to query jobs and attestations of the Acurast pallet.
+    pub trait AcurastRuntimeApi<AccountId: codec::Codec, MaxAllowedSources: Get<u32>, Extra: codec::Codec> {
+        /// Returns up to `limit` job registrations of `origin`, continuing after the job `start_after` if provided.
+        fn job_registrations(
+            origin: MultiOrigin<AccountId>,
+            start_after: Option<JobIdSequence>,
+            limit: u32,
+        ) -> Vec<(JobIdSequence, JobRegistration<AccountId, MaxAllowedSources, Extra>)>;
+
+        /// Returns the attestation of `source` together with its decoded security levels and validity.
+        fn attestation(source: AccountId) -> Option<AttestationInfo>;
+
+        /// Returns whether `source` has a valid (not expired and not revoked) attestation at the current time.
+        fn is_verified(source: AccountId) -> Result<bool, RuntimeApiError>;
     }
 }
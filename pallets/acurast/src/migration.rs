@@ -7,10 +7,23 @@ use sp_core::Get;
 use super::*;
 
 pub mod v1 {
-    use acurast_common::{AllowedSources, Schedule, Script};
+    use acurast_common::AllowedSources;
     use frame_support::pallet_prelude::*;
     use sp_std::prelude::*;
 
+    /// The utf8 bytes of an `ipfs://` url, the script representation before the introduction of typed locators.
+    pub type Script = BoundedVec<u8, ConstU32<53>>;
+
+    /// The schedule before the introduction of schedule kinds, always repeating at `interval`.
+    #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+    pub struct Schedule {
+        pub duration: u64,
+        pub start_time: u64,
+        pub end_time: u64,
+        pub interval: u64,
+        pub max_start_delay: u64,
+    }
+
     #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
     pub struct JobRegistration<AccountId, MaxAllowedSources: Get<u32>, Extra> {
         /// The script to execute. It is a vector of bytes representing a utf8 string. The string needs to be a ipfs url that points to the script.
@@ -32,9 +45,140 @@ pub mod v1 {
     }
 }
 
+pub mod v3 {
+    use super::v1::{Schedule, Script};
+    use acurast_common::{AllowedSources, JobIdSequence, JobModules, MultiOrigin};
+    use frame_support::{pallet_prelude::*, storage_alias};
+    use sp_std::prelude::*;
+
+    /// The job registration since the introduction of required modules, with the script and schedule of version 1.
+    #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+    pub struct JobRegistration<AccountId, MaxAllowedSources: Get<u32>, Extra> {
+        /// The script to execute. It is a vector of bytes representing a utf8 string. The string needs to be a ipfs url that points to the script.
+        pub script: Script,
+        /// An optional array of the [AccountId]s allowed to fulfill the job. If the array is [None], then all sources are allowed.
+        pub allowed_sources: Option<AllowedSources<AccountId, MaxAllowedSources>>,
+        /// A boolean indicating if only verified sources can fulfill the job. A verified source is one that has provided a valid key attestation.
+        pub allow_only_verified_sources: bool,
+        /// The schedule describing the desired (multiple) execution(s) of the script.
+        pub schedule: Schedule,
+        /// Maximum memory bytes used during a single execution of the job.
+        pub memory: u32,
+        /// Maximum network request used during a single execution of the job.
+        pub network_requests: u32,
+        /// Maximum storage bytes used during the whole period of the job's executions.
+        pub storage: u32,
+        /// The modules required for the job.
+        pub required_modules: JobModules,
+        /// Extra parameters. This type can be configured through [Config::RegistrationExtra].
+        pub extra: Extra,
+    }
+
+    pub type JobRegistrationFor<T> = JobRegistration<
+        <T as frame_system::Config>::AccountId,
+        <T as crate::Config>::MaxAllowedSources,
+        <T as crate::Config>::RegistrationExtra,
+    >;
+
+    #[storage_alias]
+    pub type StoredJobRegistration<T: crate::Config> = StorageDoubleMap<
+        crate::Pallet<T>,
+        Blake2_128Concat,
+        MultiOrigin<<T as frame_system::Config>::AccountId>,
+        Blake2_128Concat,
+        JobIdSequence,
+        JobRegistrationFor<T>,
+    >;
+}
+
+pub mod v4 {
+    use acurast_common::{AttestationValidity, BoundedKeyDescription, ValidatingCertIds};
+    use frame_support::pallet_prelude::*;
+
+    /// The attestation before the introduction of Apple App Attest, always holding an Android key description.
+    #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+    pub struct Attestation {
+        pub cert_ids: ValidatingCertIds,
+        pub key_description: BoundedKeyDescription,
+        pub validity: AttestationValidity,
+    }
+}
+
+pub mod v7 {
+    use acurast_common::{CertificateRevocation, SerialNumber};
+    use frame_support::{pallet_prelude::*, storage_alias};
+
+    /// The certificate revocation list before revocations were identified by issuer and serial number.
+    #[storage_alias]
+    pub type StoredRevokedCertificate<T: crate::Config> =
+        StorageMap<crate::Pallet<T>, Blake2_128Concat, SerialNumber, CertificateRevocation>;
+}
+
+pub mod v9 {
+    use acurast_common::{JobId, ParameterBound, PubKeyBytes};
+    use frame_support::{pallet_prelude::*, storage_alias};
+
+    /// The environment before the public key was tagged with its curve.
+    #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+    pub struct Environment<
+        MaxEnvVars: ParameterBound,
+        KeyMaxSize: ParameterBound,
+        ValueMaxSize: ParameterBound,
+    > {
+        pub public_key: PubKeyBytes,
+        pub variables:
+            BoundedVec<(BoundedVec<u8, KeyMaxSize>, BoundedVec<u8, ValueMaxSize>), MaxEnvVars>,
+    }
+
+    #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+    pub struct EnvironmentVersion<
+        MaxEnvVars: ParameterBound,
+        KeyMaxSize: ParameterBound,
+        ValueMaxSize: ParameterBound,
+    > {
+        pub effective_from: u64,
+        pub environment: Environment<MaxEnvVars, KeyMaxSize, ValueMaxSize>,
+    }
+
+    pub type EnvironmentFor<T> = Environment<
+        <T as crate::Config>::MaxEnvVars,
+        <T as crate::Config>::EnvKeyMaxSize,
+        <T as crate::Config>::EnvValueMaxSize,
+    >;
+
+    pub type EnvironmentVersionsFor<T> = BoundedVec<
+        EnvironmentVersion<
+            <T as crate::Config>::MaxEnvVars,
+            <T as crate::Config>::EnvKeyMaxSize,
+            <T as crate::Config>::EnvValueMaxSize,
+        >,
+        <T as crate::Config>::MaxEnvironmentVersions,
+    >;
+
+    #[storage_alias]
+    pub type ExecutionEnvironment<T: crate::Config> = StorageDoubleMap<
+        crate::Pallet<T>,
+        Blake2_128Concat,
+        JobId<<T as frame_system::Config>::AccountId>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        EnvironmentVersionsFor<T>,
+    >;
+}
+
 pub fn migrate<T: Config>() -> Weight {
-    let migrations: [(u16, &dyn Fn() -> Weight); 2] =
-        [(2, &migrate_to_v2::<T>), (3, &migrate_to_v3::<T>)];
+    // the job registrations changed with versions 4 and 11, they are migrated from version 3 at once with version 11
+    let migrations: [(u16, &dyn Fn() -> Weight); 9] = [
+        (2, &migrate_to_v2::<T>),
+        (3, &migrate_to_v3::<T>),
+        (5, &migrate_to_v5::<T>),
+        (6, &migrate_to_v6::<T>),
+        (7, &migrate_to_v7::<T>),
+        (8, &migrate_to_v8::<T>),
+        (9, &migrate_to_v9::<T>),
+        (10, &migrate_to_v10::<T>),
+        (11, &migrate_to_v11::<T>),
+    ];
 
     let onchain_version = Pallet::<T>::on_chain_storage_version();
     let mut weight: Weight = Default::default();
@@ -49,11 +193,11 @@ pub fn migrate<T: Config>() -> Weight {
 }
 
 fn migrate_to_v2<T: Config>() -> Weight {
-    StoredJobRegistration::<T>::translate::<
+    v3::StoredJobRegistration::<T>::translate::<
         v1::JobRegistration<T::AccountId, T::MaxAllowedSources, T::RegistrationExtra>,
         _,
     >(|_k1, _k2, job| {
-        Some(JobRegistration {
+        Some(v3::JobRegistration {
             script: job.script,
             allowed_sources: job.allowed_sources,
             allow_only_verified_sources: job.allow_only_verified_sources,
@@ -65,7 +209,7 @@ fn migrate_to_v2<T: Config>() -> Weight {
             extra: job.extra,
         })
     });
-    let count = StoredJobRegistration::<T>::iter().count() as u64;
+    let count = v3::StoredJobRegistration::<T>::iter().count() as u64;
     T::DbWeight::get().reads_writes(count + 1, count + 1)
 }
 
@@ -76,3 +220,143 @@ fn migrate_to_v3<T: Config>() -> Weight {
 
     T::DbWeight::get().writes((count + 1).into())
 }
+
+fn migrate_to_v5<T: Config>() -> Weight {
+    let mut count = 0u64;
+    StoredAttestation::<T>::translate::<v4::Attestation, _>(|_k, attestation| {
+        count += 1;
+        Some(Attestation {
+            cert_ids: attestation.cert_ids,
+            content: AttestationContent::KeyDescription(attestation.key_description),
+            validity: attestation.validity,
+        })
+    });
+    T::DbWeight::get().reads_writes(count + 1, count + 1)
+}
+
+fn migrate_to_v6<T: Config>() -> Weight {
+    // the trusted root certificates used to be a constant, initialize the storage with the same set
+    for certificate in TRUSTED_ROOT_CERTS {
+        TrustedRootCertificates::<T>::insert(
+            certificate_fingerprint(certificate),
+            CertificateInput::truncate_from(certificate.to_vec()),
+        );
+    }
+    T::DbWeight::get().writes(TRUSTED_ROOT_CERTS.len() as u64 + 1)
+}
+
+fn migrate_to_v7<T: Config>() -> Weight {
+    let mut count = 0u64;
+    v7::StoredRevokedCertificate::<T>::translate::<(), _>(|_k, _| {
+        count += 1;
+        Some(CertificateRevocation {
+            status: RevocationStatus::Revoked,
+            reason: RevocationReason::Unspecified,
+        })
+    });
+    T::DbWeight::get().reads_writes(count + 1, count + 1)
+}
+
+fn migrate_to_v8<T: Config>() -> Weight {
+    let mut count = 0u64;
+    // the issuer of existing revocations is unknown, they keep applying to all certificates with the serial number
+    for (serial_number, revocation) in v7::StoredRevokedCertificate::<T>::drain() {
+        count += 1;
+        StoredRevokedSerialNumber::<T>::insert(
+            serial_number,
+            RevocationEntry {
+                revocation,
+                revoked_at: None,
+            },
+        );
+    }
+    T::DbWeight::get().reads_writes(count + 1, 2 * count + 1)
+}
+
+fn migrate_to_v9<T: Config>() -> Weight {
+    let mut count = 0u64;
+    // the single environment stored before versioning applies to all executions
+    v9::ExecutionEnvironment::<T>::translate::<v9::EnvironmentFor<T>, _>(
+        |_k1, _k2, environment| {
+            count += 1;
+            Some(BoundedVec::truncate_from(vec![v9::EnvironmentVersion {
+                effective_from: 0,
+                environment,
+            }]))
+        },
+    );
+    T::DbWeight::get().reads_writes(count + 1, count + 1)
+}
+
+fn migrate_to_v10<T: Config>() -> Weight {
+    let mut count = 0u64;
+    ExecutionEnvironment::<T>::translate::<v9::EnvironmentVersionsFor<T>, _>(
+        |_k1, _k2, versions| {
+            count += 1;
+            Some(BoundedVec::truncate_from(
+                versions
+                    .into_iter()
+                    .map(|version| EnvironmentVersion {
+                        effective_from: version.effective_from,
+                        environment: Environment {
+                            // the curve was not recorded so far, the processors' environment keys are SECP256r1 keys
+                            public_key: PubKey::SECP256r1(version.environment.public_key),
+                            variables: version.environment.variables,
+                        },
+                    })
+                    .collect::<Vec<_>>(),
+            ))
+        },
+    );
+    T::DbWeight::get().reads_writes(count + 1, count + 1)
+}
+
+fn migrate_to_v11<T: Config>() -> Weight {
+    let mut count = 0u64;
+    StoredJobRegistration::<T>::translate::<
+        v3::JobRegistration<
+            T::AccountId,
+            T::MaxAllowedSources,
+            <T::RegistrationExtraMigration as RegistrationExtraMigration<T>>::OldExtra,
+        >,
+        _,
+    >(|k1, k2, job| {
+        count += 1;
+        // registrations whose url does not hold a valid locator keep their url to not orphan their matches and budgets
+        let script = match Script::from_url(&job.script) {
+            Ok(script) => script,
+            Err(error) => {
+                log::warn!(
+                    target: "runtime::acurast",
+                    "keeping registration {:?} with invalid script as legacy url: {:?}",
+                    (k1, k2),
+                    error
+                );
+                Script::Legacy(job.script)
+            }
+        };
+        Some(JobRegistration {
+            script,
+            allowed_sources: job.allowed_sources,
+            allow_only_verified_sources: job.allow_only_verified_sources,
+            // all schedules registered so far repeat at their interval
+            schedule: Schedule {
+                duration: job.schedule.duration,
+                start_time: job.schedule.start_time,
+                end_time: job.schedule.end_time,
+                interval: job.schedule.interval,
+                max_start_delay: job.schedule.max_start_delay,
+                kind: ScheduleKind::Interval,
+            },
+            memory: job.memory,
+            network_requests: job.network_requests,
+            storage: job.storage,
+            required_modules: job.required_modules,
+            // the extra is configured by the runtime and might have changed along with the registration
+            extra: <T::RegistrationExtraMigration as RegistrationExtraMigration<T>>::migrate(
+                job.extra,
+            )?,
+        })
+    });
+    T::DbWeight::get().reads_writes(count + 1, count + 1)
+}
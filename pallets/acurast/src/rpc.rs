@@ -0,0 +1,141 @@
+//! Node-specific RPC methods for interaction with pallet-acurast.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use crate::{
+    AcurastRuntimeApi, AttestationInfo, JobIdSequence, JobRegistration, MultiOrigin,
+    RuntimeApiError,
+};
+use codec::Codec;
+use frame_support::pallet_prelude::Get;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use sp_runtime::traits::{HashFor, MaybeSerializeDeserialize};
+
+const RUNTIME_ERROR: i32 = 8001;
+const ACURAST_ERROR: i32 = 8021;
+
+/// Acurast RPC methods.
+#[rpc(client, server)]
+pub trait AcurastApi<
+    BlockHash,
+    AccountId: MaybeSerializeDeserialize,
+    MaxAllowedSources: Get<u32>,
+    Extra: MaybeSerializeDeserialize,
+>
+{
+    /// Returns up to `limit` job registrations of `origin`, continuing after the job `start_after` if provided.
+    #[method(name = "jobRegistrations")]
+    fn job_registrations(
+        &self,
+        origin: MultiOrigin<AccountId>,
+        start_after: Option<JobIdSequence>,
+        limit: u32,
+    ) -> RpcResult<
+        Vec<(
+            JobIdSequence,
+            JobRegistration<AccountId, MaxAllowedSources, Extra>,
+        )>,
+    >;
+
+    /// Returns the attestation of `source` together with its decoded security levels and validity.
+    #[method(name = "attestation")]
+    fn attestation(&self, source: AccountId) -> RpcResult<Option<AttestationInfo>>;
+
+    /// Returns whether `source` has a valid (not expired and not revoked) attestation at the current time.
+    #[method(name = "isVerified")]
+    fn is_verified(&self, source: AccountId) -> RpcResult<bool>;
+}
+
+/// RPC methods.
+pub struct Acurast<Client, B> {
+    client: Arc<Client>,
+    _marker: PhantomData<B>,
+}
+
+impl<C, B> Acurast<C, B> {
+    /// Create new `Acurast` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl<Client, Block, AccountId, MaxAllowedSources, Extra>
+    AcurastApiServer<HashFor<Block>, AccountId, MaxAllowedSources, Extra>
+    for Acurast<Client, (Block, AccountId, Extra)>
+where
+    Block: BlockT,
+    Client: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    Client::Api: AcurastRuntimeApi<Block, AccountId, MaxAllowedSources, Extra>,
+    AccountId: MaybeSerializeDeserialize + Codec + Send + Sync + 'static,
+    MaxAllowedSources: Get<u32>,
+    Extra: MaybeSerializeDeserialize + Codec + Send + Sync + 'static,
+{
+    fn job_registrations(
+        &self,
+        origin: MultiOrigin<AccountId>,
+        start_after: Option<JobIdSequence>,
+        limit: u32,
+    ) -> RpcResult<
+        Vec<(
+            JobIdSequence,
+            JobRegistration<AccountId, MaxAllowedSources, Extra>,
+        )>,
+    > {
+        let api = self.client.runtime_api();
+        let registrations = api
+            .job_registrations(self.client.info().best_hash, origin, start_after, limit)
+            .map_err(runtime_error_into_rpc_error)?;
+        Ok(registrations)
+    }
+
+    fn attestation(&self, source: AccountId) -> RpcResult<Option<AttestationInfo>> {
+        let api = self.client.runtime_api();
+        let attestation = api
+            .attestation(self.client.info().best_hash, source)
+            .map_err(runtime_error_into_rpc_error)?;
+        Ok(attestation)
+    }
+
+    fn is_verified(&self, source: AccountId) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let verified = api
+            .is_verified(self.client.info().best_hash, source)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(acurast_error_into_rpc_error)?;
+        Ok(verified)
+    }
+}
+
+/// Converts an acurast-specific error into a [`CallError`].
+fn acurast_error_into_rpc_error(err: RuntimeApiError) -> CallError {
+    let error_code = ACURAST_ERROR
+        + match err {
+            RuntimeApiError::IsVerified => 1,
+        };
+
+    CallError::Custom(ErrorObject::owned(
+        error_code,
+        err.to_string(),
+        Some(format!("{:?}", err)),
+    ))
+}
+
+/// Converts a runtime trap into a [`CallError`].
+fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> CallError {
+    CallError::Custom(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Runtime trapped",
+        Some(format!("{:?}", err)),
+    ))
+}
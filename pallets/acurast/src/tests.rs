@@ -1,13 +1,33 @@
 #![cfg(test)]
 
 use crate::{
-    mock::*, utils::validate_and_extract_attestation, AllowedSourcesUpdate, AttestationChain,
-    CertificateRevocationListUpdate, Error, ListUpdateOperation, SerialNumber,
+    mock::*,
+    utils::{
+        ensure_source_verified, ensure_source_verified_at, source_revoked_at,
+        validate_and_extract_attestation,
+    },
+    AllowedSourcesUpdate, AppId, AppIdUpdate, AttestationChain, AttestationKeyBinding, CertId,
+    CertificateRevocation, CertificateRevocationListUpdate, Error, JobRegistration, JobTemplate,
+    JobTemplateName, ListUpdateOperation, PublicKeyHashBinding, RevocationEntry, RevocationReason,
+    RevocationStatus, RevocationStatusListSnapshot, SerialNumber, TrustedRootCertificateUpdate,
+};
+use acurast_common::{
+    builder::{AttestationBuilder, TestCertificateAuthority},
+    certificate_fingerprint, validate_certificate_chain, AppAttestEnvironment, Attestation,
+    AttestationContent, AttestationPolicy, AttestationSecurityLevel, AttestationValidity,
+    BoundedAppAttest, CertificateChainInput, Environment, JobRegistrationUpdate, MultiOrigin,
+    PubKey, Schedule, ScheduleKind,
+};
+use codec::Encode;
+use frame_support::{
+    assert_err, assert_ok, bounded_vec,
+    traits::{GetStorageVersion, Hooks, StorageVersion},
+    weights::Weight,
+    BoundedVec,
 };
-use acurast_common::{Environment, MultiOrigin};
-use frame_support::{assert_err, assert_ok, bounded_vec, BoundedVec};
 use hex_literal::hex;
-use sp_runtime::AccountId32;
+use sp_core::{ed25519, Pair};
+use sp_runtime::{AccountId32, DispatchError};
 
 #[test]
 fn test_job_registration() {
@@ -146,6 +166,150 @@ fn test_job_registration_failure_3() {
     });
 }
 
+#[test]
+fn test_job_template() {
+    let registration = job_registration(None, false);
+    let schedule = Schedule {
+        duration: 3000,
+        start_time: 1_671_890_400_000, // 24.12.2022 14:00
+        end_time: 1_671_894_000_000,   // 24.12.2022 15:00 (one hour later)
+        interval: 600_000,             // 10min
+        max_start_delay: 0,
+        kind: ScheduleKind::Interval,
+    };
+    let name: JobTemplateName = BoundedVec::truncate_from(b"price feed".to_vec());
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        assert_err!(
+            Acurast::create_job_template(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                name.clone(),
+                invalid_job_registration_1()
+            ),
+            Error::<Test>::InvalidScriptValue
+        );
+        assert_ok!(Acurast::create_job_template(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            name.clone(),
+            registration.clone()
+        ));
+        let template_id = Acurast::job_template_id_sequence();
+        assert_eq!(
+            Acurast::stored_job_template(template_id),
+            Some(JobTemplate {
+                owner: alice_account_id(),
+                name: name.clone(),
+                registration: registration.clone()
+            })
+        );
+
+        assert_ok!(Acurast::register_from_template(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            template_id,
+            schedule.clone(),
+            Some(())
+        ));
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        let expected_registration = JobRegistration {
+            schedule: schedule.clone(),
+            ..registration.clone()
+        };
+        assert_eq!(
+            Acurast::stored_job_registration(&job_id.0, job_id.1),
+            Some(expected_registration.clone())
+        );
+
+        assert_err!(
+            Acurast::register_from_template(
+                RuntimeOrigin::signed(bob_account_id()).into(),
+                template_id,
+                schedule.clone(),
+                None
+            ),
+            Error::<Test>::NotJobTemplateOwner
+        );
+        assert_err!(
+            Acurast::remove_job_template(
+                RuntimeOrigin::signed(bob_account_id()).into(),
+                template_id
+            ),
+            Error::<Test>::NotJobTemplateOwner
+        );
+        assert_ok!(Acurast::remove_job_template(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            template_id
+        ));
+        assert_err!(
+            Acurast::register_from_template(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                template_id,
+                schedule.clone(),
+                None
+            ),
+            Error::<Test>::JobTemplateNotFound
+        );
+        // jobs registered from the template are kept
+        assert_eq!(
+            Acurast::stored_job_registration(&job_id.0, job_id.1),
+            Some(expected_registration.clone())
+        );
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::JobTemplateCreated(
+                    template_id,
+                    alice_account_id(),
+                    name.clone()
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobRegistrationStored(
+                    expected_registration,
+                    job_id.clone()
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobRegisteredFromTemplate(
+                    job_id.clone(),
+                    template_id
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobTemplateRemoved(template_id)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_job_template_limit() {
+    let registration = job_registration(None, false);
+    let name: JobTemplateName = BoundedVec::truncate_from(b"price feed".to_vec());
+    ExtBuilder::default().build().execute_with(|| {
+        let create = |who: AccountId32| {
+            Acurast::create_job_template(
+                RuntimeOrigin::signed(who).into(),
+                name.clone(),
+                registration.clone(),
+            )
+        };
+
+        assert_ok!(create(alice_account_id()));
+        assert_ok!(create(alice_account_id()));
+        assert_eq!(Acurast::job_template_count(alice_account_id()), 2);
+        assert_err!(
+            create(alice_account_id()),
+            Error::<Test>::TooManyJobTemplates
+        );
+        // the limit applies per owner
+        assert_ok!(create(bob_account_id()));
+
+        // removing a template allows to create another one
+        assert_ok!(Acurast::remove_job_template(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            1
+        ));
+        assert_eq!(Acurast::job_template_count(alice_account_id()), 1);
+        assert_ok!(create(alice_account_id()));
+    });
+}
+
 #[test]
 fn test_update_allowed_sources() {
     ExtBuilder::default().build().execute_with(|| {
@@ -281,6 +445,65 @@ fn test_update_allowed_sources_failure() {
     });
 }
 
+#[test]
+fn test_update_registration() {
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        let registration = job_registration(None, false);
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+
+        let update = JobRegistrationUpdate {
+            memory: Some(10_000u32),
+            end_time: Some(1_671_807_600_000), // 23.12.2022 15:00 (one hour later)
+            ..Default::default()
+        };
+        assert_ok!(Acurast::update_registration(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            initial_job_id + 1,
+            update.clone(),
+        ));
+
+        let mut updated_registration = registration.clone();
+        updated_registration.memory = 10_000u32;
+        updated_registration.schedule.end_time = 1_671_807_600_000;
+        assert_eq!(
+            Some(updated_registration),
+            Acurast::stored_job_registration(
+                MultiOrigin::Acurast(alice_account_id()),
+                initial_job_id + 1
+            )
+        );
+
+        assert_err!(
+            Acurast::update_registration(
+                RuntimeOrigin::signed(bob_account_id()).into(),
+                initial_job_id + 1,
+                update.clone(),
+            ),
+            Error::<Test>::JobRegistrationNotFound
+        );
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::JobRegistrationStored(
+                    registration.clone(),
+                    (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1)
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobRegistrationUpdated(
+                    (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1),
+                    registration,
+                    update
+                )),
+            ]
+        );
+    });
+}
+
 #[test]
 fn test_submit_attestation() {
     ExtBuilder::default().build().execute_with(|| {
@@ -309,10 +532,63 @@ fn test_submit_attestation() {
     });
 }
 
+#[test]
+fn test_runtime_api() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+        let origin = MultiOrigin::Acurast(alice_account_id());
+        let registration = job_registration(None, false);
+        for _ in 0..3 {
+            assert_ok!(Acurast::register(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                registration.clone()
+            ));
+        }
+
+        let first_page = Acurast::job_registrations(&origin, None, 2);
+        assert_eq!(2, first_page.len());
+        let second_page = Acurast::job_registrations(&origin, Some(first_page[1].0), 2);
+        assert_eq!(1, second_page.len());
+        let mut job_ids = first_page
+            .iter()
+            .chain(second_page.iter())
+            .map(|(job_id_seq, job_registration)| {
+                assert_eq!(&registration, job_registration);
+                *job_id_seq
+            })
+            .collect::<Vec<_>>();
+        job_ids.sort();
+        assert_eq!(vec![1, 2, 3], job_ids);
+        assert!(
+            Acurast::job_registrations(&MultiOrigin::Acurast(bob_account_id()), None, 2).is_empty()
+        );
+
+        assert_eq!(None, Acurast::attestation_info(&processor_account_id()));
+        assert_eq!(Ok(false), Acurast::is_verified(&processor_account_id()));
+
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
+        let info = Acurast::attestation_info(&processor_account_id()).unwrap();
+        assert_eq!(
+            Some(AttestationSecurityLevel::StrongBox),
+            info.attestation_security_level
+        );
+        assert_eq!(
+            Some(AttestationSecurityLevel::StrongBox),
+            info.key_mint_security_level
+        );
+        assert_eq!(info.attestation.validity, info.validity);
+        assert_eq!(1842739199000, info.expiry);
+        assert_eq!(Ok(true), Acurast::is_verified(&processor_account_id()));
+    });
+}
+
 #[test]
 fn test_submit_attestation_parse_issuer_name() {
     ExtBuilder::default().build().execute_with(|| {
-        let chain = AttestationChain {
+        let chain = AttestationChain::AndroidKeyAttestation {
             certificate_chain: vec![
                 hex!("3082056030820348a003020102020900e8fa196314d2fa18300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3136303532363136323835325a170d3236303532343136323835325a301b31193017060355040513106639323030396538353362366230343530820222300d06092a864886f70d01010105000382020f003082020a0282020100afb6c7822bb1a701ec2bb42e8bcc541663abef982f32c77f7531030c97524b1b5fe809fbc72aa9451f743cbd9a6f1335744aa55e77f6b6ac3535ee17c25e639517dd9c92e6374a53cbfe258f8ffbb6fd129378a22a4ca99c452d47a59f3201f44197ca1ccd7e762fb2f53151b6feb2fffd2b6fe4fe5bc6bd9ec34bfe08239daafceb8eb5a8ed2b3acd9c5e3a7790e1b51442793159859811ad9eb2a96bbdd7a57c93a91c41fccd27d67fd6f671aa0b815261ad384fa37944864604ddb3d8c4f920a19b1656c2f14ad6d03c56ec060899041c1ed1a5fe6d3440b556bad1d0a152589c53e55d370762f0122eef91861b1b0e6c4c80927499c0e9bec0b83e3bc1f93c72c049604bbd2f1345e62c3f8e26dbec06c94766f3c128239d4f4312fad8123887e06becf567583bf8355a81feeabaf99a83c8df3e2a322afc672bf120b135158b6821ceaf309b6eee77f98833b018daa10e451f06a374d50781f359082966bb778b9308942698e74e0bcd24628a01c2cc03e51f0b3e5b4ac1e4df9eaf9ff6a492a77c1483882885015b422ce67b80b88c9b48e13b607ab545c723ff8c44f8f2d368b9f6520d31145ebf9e862ad71df6a3bfd2450959d653740d97a12f368b13ef66d5d0a54a6e2f5d9a6fef446832bc67844725861f093dd0e6f3405da89643ef0f4d69b6420051fdb93049673e36950580d3cdf4fbd08bc58483952600630203010001a381a63081a3301d0603551d0e041604143661e1007c880509518b446c47ff1a4cc9ea4f12301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff04040302018630400603551d1f043930373035a033a031862f68747470733a2f2f616e64726f69642e676f6f676c65617069732e636f6d2f6174746573746174696f6e2f63726c2f300d06092a864886f70d01010b0500038202010020c8c38d4bdca9571b468c892fff72aac6f844a11d41a8f0736cc37d16d6426d8e7e9407044cea39e68b07c13dbf1503dd5c85bdafb2c02d5f6cdb4efa8127df8b04f182770fc4e7745b7fceaa87129a8801ce8e9bc0cb96379b4d26a82d30fd9c2f8eed6dc1be2f84b689e4d914258b144bbae624a1c70671132e2f0616a884b2a4d6a46ffa89b602bfbad80c1243711f56eb6056f637c8a0141cc54094268b8c3c7db994b35c0dcd6cb2abc2dafee252023d2dea0cd6c368bea3e6414886f6b1e58b5bd7c730b268c4e3c1fb6424b91febbdb80c586e2ae8368c84d5d10917bda2561789d4687393340e2e254f560ef64b2358fcdc0fbfc6700952e708bffcc627500c1f66e81ea17c098d7a2e9b18801b7ab4ac71587d345dcc8309d5b62a50427aa6d03dcb05996c96ba0c5d71e92162c016ca849ff35f0d52c65d05605a47f3ae917acd2df910efd2326688596ef69b3bf5fe3154f7aeb880a0a73ca04d94c2ce8317eeb43d5eff5883e336f5f249daaca4899237bf267e5c43ab02ea44162403723be6aa692c61bdae9ed409d463c4c97c64306577eef2bc7560b75715cc9c7dc67c86082db751a89c30349762b0782385875cf1a3c6166e0ae3c12d374e2d4f1846f318744bd879b587329bf018217a6c0c77241a4878e435c03079cb451289c5776206069a2f8d65f840e1445287bed877abae24e24435168d553ce4").to_vec().try_into().unwrap(),
                 hex!("3082039930820181a0030201020210060d896bdc60a576a5947be0895f5989300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3230303931313138303232315a170d3330303930393138303232315a303f31123010060355040c0c095374726f6e67426f78312930270603550405132066336466313937623134316339333437633764616630333735656330663934393076301006072a8648ce3d020106052b81040022036200047246606805047a2007191896564ddc2931e0de34aa60fbd8b84ec6b544ef722a843b8fee768f2a611d7dc1785389736ff17314f67f7ec1e6484fc34b01e8493dc0c50c2af60d31c7f9b5a7f6963d5abc45ca36ba14a0b272cc6c6cf6f15ea363a3633061301d0603551d0e041604146ee611df7046d5bb346d8d2d8e06371f5271ab4d301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300d06092a864886f70d01010b050003820201003828d80663e5d9b0df41803e774fffe44e5c9ab0de9b067c9a8d569cdcef69bd880b6abbac853cb3f317dbb62a37584cb9e3ce108962a15db5531858edf57787d1dac77b675804aae8631586b08d0d758dedac0d6e331f34a1686f12e171bc040757ffd41ddd1840256e5e30d04cff0576bf27f1e60f05de5fc50a25fa9598d148db3e0a5fdcfcf92a4241129e5a8a3a0bbab94034bd75775a57f1fa2d0822e1874fd002b9856c0c53390ac221123edb25f40f50f06b3796ad92d20ff84f90cd7fb849a5784e395c4517cb630b762c6d12223acb2f80ff040c8b332856156e5d55196620cb1cfb4d4e3c50b396570f20f2c6925ad17e567246edb6a46e12077a88c59586131ab5f57b7bc8d79adef6049cd26563807f6854d977acea82068c56a1e8020570ca2a29f0730a5ac46e48844bea8b497b818c33642378d7714efbe4e23f43e80ad7dc83b7347ec25d6e3fc8af1a7462c5373deabfe2e00c13ca28a3bd65ba41070a0304c94a7883e4b4b957f8c1e6697aa17dba7c5850b5e44f02c2d741f0f5d5a76487bb2eaae58bdd957518d487fbcf6b6c6b4ed704a5f7c7079d4bb7d1d5d2c470403caf8ddd2eeccfc61a335081a5be1e5028fa01247a1ebfd4c56f461276a32ead8a3d87fc32a1b410283141ff4f0c10644e765f14c46ce3f14db5a52ed7474e8e3ee5b447eb4b4ac78e0604ca4accf5698e46591493d36ca1").to_vec().try_into().unwrap(),
@@ -416,25 +692,163 @@ fn test_submit_attestation_failure_3() {
     });
 }
 
+#[test]
+fn test_submit_app_attestation_failure() {
+    ExtBuilder::default().build().execute_with(|| {
+        let chain = AttestationChain::AppleAppAttest {
+            attestation_object: hex!("a363666d746f6170706c652d617070617474657374676174745374")
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            key_id: [0u8; 32],
+            app_id: b"TEAMID1234.com.acurast.processor"
+                .to_vec()
+                .try_into()
+                .unwrap(),
+        };
+
+        // the app id is checked before the attestation object
+        assert_err!(
+            Acurast::submit_attestation(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                chain.clone()
+            ),
+            Error::<Test>::AppIdNotAllowed
+        );
+
+        assert_ok!(Acurast::update_allowed_app_ids(
+            RuntimeOrigin::root(),
+            bounded_vec![AppIdUpdate {
+                operation: ListUpdateOperation::Add,
+                item: b"TEAMID1234.com.acurast.processor"
+                    .to_vec()
+                    .try_into()
+                    .unwrap(),
+            }],
+        ));
+        assert_err!(
+            Acurast::submit_attestation(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                chain.clone()
+            ),
+            Error::<Test>::AppAttestationValidationFailed
+        );
+
+        assert_eq!(None, Acurast::stored_attestation(processor_account_id()));
+    });
+}
+
+#[test]
+fn test_update_allowed_app_ids() {
+    ExtBuilder::default().build().execute_with(|| {
+        let app_id: AppId = b"TEAMID1234.com.acurast.processor"
+            .to_vec()
+            .try_into()
+            .unwrap();
+        let add: BoundedVec<_, _> = bounded_vec![AppIdUpdate {
+            operation: ListUpdateOperation::Add,
+            item: app_id.clone(),
+        }];
+        let remove: BoundedVec<_, _> = bounded_vec![AppIdUpdate {
+            operation: ListUpdateOperation::Remove,
+            item: app_id.clone(),
+        }];
+
+        assert_err!(
+            Acurast::update_allowed_app_ids(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                add.clone(),
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_err!(
+            Acurast::update_allowed_app_ids(RuntimeOrigin::root(), remove.clone()),
+            Error::<Test>::AllowedAppIdNotFound
+        );
+
+        assert_ok!(Acurast::update_allowed_app_ids(RuntimeOrigin::root(), add));
+        assert_eq!(Some(()), Acurast::allowed_app_id(&app_id));
+        assert_ok!(Acurast::update_allowed_app_ids(
+            RuntimeOrigin::root(),
+            remove
+        ));
+        assert_eq!(None, Acurast::allowed_app_id(&app_id));
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::AllowedAppIdAdded(app_id.clone())),
+                RuntimeEvent::Acurast(crate::Event::AllowedAppIdRemoved(app_id)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_attestation_key_binding() {
+    ExtBuilder::default().build().execute_with(|| {
+        // a chain ending at the first intermediate certificate attests a P-384 key
+        let chain: CertificateChainInput = vec![
+            ROOT_CERT.to_vec().try_into().unwrap(),
+            INT_CERT_1.to_vec().try_into().unwrap(),
+        ]
+        .try_into()
+        .unwrap();
+        let (_, _, p384_key) = validate_certificate_chain(&chain).unwrap();
+        // the self-signed root certificate attests an RSA key
+        let chain: CertificateChainInput = vec![ROOT_CERT.to_vec().try_into().unwrap()]
+            .try_into()
+            .unwrap();
+        let (_, _, rsa_key) = validate_certificate_chain(&chain).unwrap();
+
+        for key in [p384_key, rsa_key] {
+            let source: AccountId32 =
+                sp_io::hashing::blake2_256(&key.encode_canonical().unwrap()).into();
+
+            assert_ok!(
+                <PublicKeyHashBinding as AttestationKeyBinding<Test>>::ensure_bound(&source, &key)
+            );
+            assert_err!(
+                <PublicKeyHashBinding as AttestationKeyBinding<Test>>::ensure_bound(
+                    &processor_account_id(),
+                    &key
+                ),
+                Error::<Test>::AttestationPublicKeyDoesNotMatchSource
+            );
+            // the default binding binds keys the same way
+            assert_ok!(<() as AttestationKeyBinding<Test>>::ensure_bound(
+                &source, &key
+            ));
+        }
+    });
+}
+
 #[test]
 fn test_update_revocation_list() {
     ExtBuilder::default().build().execute_with(|| {
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
         let updates_1 = vec![CertificateRevocationListUpdate {
             operation: ListUpdateOperation::Add,
-            item: cert_serial_number(),
+            item: cert_id(),
         }];
         assert_ok!(Acurast::update_certificate_revocation_list(
             RuntimeOrigin::signed(alice_account_id()).into(),
             updates_1.clone().try_into().unwrap(),
         ));
         assert_eq!(
-            Some(()),
-            Acurast::stored_revoked_certificate::<SerialNumber>(cert_serial_number())
+            Some(RevocationEntry {
+                revocation: CertificateRevocation {
+                    status: RevocationStatus::Revoked,
+                    reason: RevocationReason::Unspecified,
+                },
+                revoked_at: Some(1657363915001),
+            }),
+            Acurast::stored_revoked_certificate::<CertId>(cert_id())
         );
 
         let updates_2 = vec![CertificateRevocationListUpdate {
             operation: ListUpdateOperation::Remove,
-            item: cert_serial_number(),
+            item: cert_id(),
         }];
         assert_ok!(Acurast::update_certificate_revocation_list(
             RuntimeOrigin::signed(alice_account_id()).into(),
@@ -442,7 +856,7 @@ fn test_update_revocation_list() {
         ));
         assert_eq!(
             None,
-            Acurast::stored_revoked_certificate::<SerialNumber>(cert_serial_number())
+            Acurast::stored_revoked_certificate::<CertId>(cert_id())
         );
 
         assert_err!(
@@ -454,7 +868,7 @@ fn test_update_revocation_list() {
         );
         assert_eq!(
             None,
-            Acurast::stored_revoked_certificate::<SerialNumber>(cert_serial_number())
+            Acurast::stored_revoked_certificate::<CertId>(cert_id())
         );
 
         assert_eq!(
@@ -478,7 +892,7 @@ fn test_update_revocation_list_submit_attestation() {
     ExtBuilder::default().build().execute_with(|| {
         let updates = vec![CertificateRevocationListUpdate {
             operation: ListUpdateOperation::Add,
-            item: cert_serial_number(),
+            item: cert_id(),
         }];
         assert_ok!(Acurast::update_certificate_revocation_list(
             RuntimeOrigin::signed(alice_account_id()).into(),
@@ -492,7 +906,10 @@ fn test_update_revocation_list_submit_attestation() {
                 RuntimeOrigin::signed(processor_account_id()).into(),
                 chain.clone()
             ),
-            Error::<Test>::RevokedCertificate
+            Error::<Test>::RevokedCertificate(CertificateRevocation {
+                status: RevocationStatus::Revoked,
+                reason: RevocationReason::Unspecified,
+            })
         );
 
         assert_eq!(
@@ -507,13 +924,72 @@ fn test_update_revocation_list_submit_attestation() {
     });
 }
 
+#[test]
+fn test_update_revocation_list_issuer_and_time() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
+
+        // the same serial number of another issuer does not revoke the certificate
+        let (_, serial_number) = cert_id();
+        let other_cert_id: CertId = (hex!("3000").to_vec().try_into().unwrap(), serial_number);
+        assert_ok!(Acurast::update_certificate_revocation_list(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            vec![CertificateRevocationListUpdate {
+                operation: ListUpdateOperation::Add,
+                item: other_cert_id,
+            }]
+            .try_into()
+            .unwrap(),
+        ));
+        assert_ok!(ensure_source_verified::<Test>(&processor_account_id()));
+        assert_eq!(source_revoked_at::<Test>(&processor_account_id()), Ok(None));
+
+        assert_ok!(Acurast::update_certificate_revocation_list(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            vec![CertificateRevocationListUpdate {
+                operation: ListUpdateOperation::Add,
+                item: cert_id(),
+            }]
+            .try_into()
+            .unwrap(),
+        ));
+        let revoked = || {
+            Error::<Test>::RevokedCertificate(CertificateRevocation {
+                status: RevocationStatus::Revoked,
+                reason: RevocationReason::Unspecified,
+            })
+        };
+        assert_err!(
+            ensure_source_verified::<Test>(&processor_account_id()),
+            revoked()
+        );
+        // work performed before the revocation is still honored
+        assert_ok!(ensure_source_verified_at::<Test>(
+            &processor_account_id(),
+            1657363915000
+        ));
+        assert_err!(
+            ensure_source_verified_at::<Test>(&processor_account_id(), 1657363915001),
+            revoked()
+        );
+        assert_eq!(
+            source_revoked_at::<Test>(&processor_account_id()),
+            Ok(Some(1657363915001))
+        );
+    });
+}
+
 #[test]
 fn test_update_revocation_list_assign_job() {
     ExtBuilder::default().build().execute_with(|| {
         let initial_job_id = Acurast::job_id_sequence();
         let updates = vec![CertificateRevocationListUpdate {
             operation: ListUpdateOperation::Add,
-            item: cert_serial_number(),
+            item: cert_id(),
         }];
         let chain = attestation_chain();
         let registration = job_registration(None, true);
@@ -555,41 +1031,451 @@ fn test_update_revocation_list_assign_job() {
 }
 
 #[test]
-fn test_set_environment() {
-    let registration = job_registration(
-        Some(bounded_vec![
-            alice_account_id(),
-            bob_account_id(),
-            charlie_account_id(),
-            dave_account_id(),
-        ]),
-        false,
-    );
+fn test_update_trusted_root_certificates() {
     ExtBuilder::default().build().execute_with(|| {
-        let initial_job_id = Acurast::job_id_sequence();
+        let root_fingerprint = certificate_fingerprint(&ROOT_CERT);
+        let remove: BoundedVec<_, _> =
+            bounded_vec![TrustedRootCertificateUpdate::Remove(root_fingerprint)];
+        let add: BoundedVec<_, _> = bounded_vec![TrustedRootCertificateUpdate::Add(
+            ROOT_CERT.to_vec().try_into().unwrap()
+        )];
+
+        assert!(Acurast::trusted_root_certificate(root_fingerprint).is_some());
+        assert_err!(
+            Acurast::update_trusted_root_certificates(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                remove.clone(),
+            ),
+            DispatchError::BadOrigin
+        );
 
-        assert_ok!(Acurast::register(
-            RuntimeOrigin::signed(alice_account_id()).into(),
-            registration.clone(),
+        assert_ok!(Acurast::update_trusted_root_certificates(
+            RuntimeOrigin::root(),
+            remove.clone(),
         ));
-        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        assert_eq!(None, Acurast::trusted_root_certificate(root_fingerprint));
+        assert_err!(
+            Acurast::update_trusted_root_certificates(RuntimeOrigin::root(), remove),
+            Error::<Test>::TrustedRootCertificateNotFound
+        );
 
-        let env = Environment {
-            public_key: BoundedVec::truncate_from(
-                hex!("000000000000000000000000000000000000000000000000000000000000000000").into(),
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+        assert_err!(
+            Acurast::submit_attestation(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                attestation_chain()
             ),
-            variables: bounded_vec![(
-                BoundedVec::truncate_from(hex!("AAAA").into()),
-                BoundedVec::truncate_from(hex!("BBBB").into())
-            )],
-        };
-        assert_ok!(Acurast::set_environment(
-            RuntimeOrigin::signed(alice_account_id()).into(),
-            initial_job_id + 1,
-            bob_account_id(),
-            env
-        ));
-
+            Error::<Test>::RootCertificateValidationFailed
+        );
+
+        assert_err!(
+            Acurast::update_trusted_root_certificates(
+                RuntimeOrigin::root(),
+                bounded_vec![TrustedRootCertificateUpdate::Add(
+                    LEAF_CERT.to_vec().try_into().unwrap()
+                )],
+            ),
+            Error::<Test>::InvalidTrustedRootCertificate
+        );
+
+        assert_ok!(Acurast::update_trusted_root_certificates(
+            RuntimeOrigin::root(),
+            add
+        ));
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
+
+        let attestation =
+            validate_and_extract_attestation::<Test>(&processor_account_id(), &attestation_chain())
+                .unwrap();
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::TrustedRootCertificateRemoved(
+                    root_fingerprint
+                )),
+                RuntimeEvent::Acurast(crate::Event::TrustedRootCertificateAdded(root_fingerprint)),
+                RuntimeEvent::Acurast(crate::Event::AttestationStored(
+                    attestation,
+                    processor_account_id()
+                )),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_attestation_policy_app_attest() {
+    let attestation = Attestation {
+        cert_ids: Default::default(),
+        content: AttestationContent::AppAttest(BoundedAppAttest {
+            app_id: b"TEAMID1234.com.acurast.processor"
+                .to_vec()
+                .try_into()
+                .unwrap(),
+            key_id: [0u8; 32],
+            counter: 0,
+            environment: AppAttestEnvironment::Production,
+        }),
+        validity: AttestationValidity {
+            not_before: 0,
+            not_after: u64::MAX,
+        },
+    };
+
+    assert!(AttestationPolicy::default().accepts(&attestation));
+    assert!(!AttestationPolicy {
+        require_verified_boot: true,
+        ..Default::default()
+    }
+    .accepts(&attestation));
+    assert!(!AttestationPolicy {
+        min_os_patch_level: Some(202206),
+        ..Default::default()
+    }
+    .accepts(&attestation));
+}
+
+#[test]
+fn test_attestation_policy() {
+    ExtBuilder::default().build().execute_with(|| {
+        let accepting_policy = AttestationPolicy {
+            min_security_level: Some(AttestationSecurityLevel::TrustedEnvironemnt),
+            require_verified_boot: true,
+            require_locked_bootloader: true,
+            min_os_patch_level: Some(202206),
+            min_vendor_patch_level: Some(20220605),
+            allowed_package_names: Some(bounded_vec![
+                b"com.ubinetic.attested.executor.test.testnet"
+                    .to_vec()
+                    .try_into()
+                    .unwrap()
+            ]),
+            allowed_signature_digests: Some(bounded_vec![hex!(
+                "bdcb4560f6b3c41dad920668169c28be1ef9ea49f23d98cd8eb2f37ae4488ff9"
+            )
+            .to_vec()
+            .try_into()
+            .unwrap()]),
+        };
+        let rejecting_policy = AttestationPolicy {
+            min_os_patch_level: Some(202207),
+            ..accepting_policy.clone()
+        };
+
+        assert_err!(
+            Acurast::set_attestation_policy(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                Some(rejecting_policy.clone()),
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Acurast::set_attestation_policy(
+            RuntimeOrigin::root(),
+            Some(rejecting_policy.clone()),
+        ));
+        assert_eq!(
+            Some(rejecting_policy.clone()),
+            Acurast::attestation_policy()
+        );
+
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+        assert_err!(
+            Acurast::submit_attestation(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                attestation_chain()
+            ),
+            Error::<Test>::AttestationRejected
+        );
+
+        assert_ok!(Acurast::set_attestation_policy(
+            RuntimeOrigin::root(),
+            Some(accepting_policy.clone()),
+        ));
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
+
+        assert_ok!(Acurast::set_attestation_policy(RuntimeOrigin::root(), None));
+        assert_eq!(None, Acurast::attestation_policy());
+
+        let attestation =
+            validate_and_extract_attestation::<Test>(&processor_account_id(), &attestation_chain())
+                .unwrap();
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::AttestationPolicyUpdated(Some(
+                    rejecting_policy
+                ))),
+                RuntimeEvent::Acurast(crate::Event::AttestationPolicyUpdated(Some(
+                    accepting_policy
+                ))),
+                RuntimeEvent::Acurast(crate::Event::AttestationStored(
+                    attestation,
+                    processor_account_id()
+                )),
+                RuntimeEvent::Acurast(crate::Event::AttestationPolicyUpdated(None)),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_submit_generated_attestation() {
+    ExtBuilder::default().build().execute_with(|| {
+        let authority = TestCertificateAuthority::new("Acurast Test Root", [1u8; 32]);
+        let builder = AttestationBuilder::new()
+            .os_patch_level(202306)
+            .vendor_patch_level(20230605)
+            .package(b"com.acurast.attested.executor.test", 1)
+            .signature_digest(&[2u8; 32])
+            .validity(1_672_527_600_000, 1_767_222_000_000);
+        let chain = builder.certificate_chain(&authority, [3u8; 32]);
+        let (_, _, key) = validate_certificate_chain(&chain).unwrap();
+        let source: AccountId32 =
+            sp_io::hashing::blake2_256(&key.encode_canonical().unwrap()).into();
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1_688_162_400_000);
+
+        assert_err!(
+            Acurast::submit_attestation(
+                RuntimeOrigin::signed(source.clone()).into(),
+                AttestationChain::AndroidKeyAttestation {
+                    certificate_chain: chain.clone()
+                }
+            ),
+            Error::<Test>::RootCertificateValidationFailed
+        );
+
+        assert_ok!(Acurast::update_trusted_root_certificates(
+            RuntimeOrigin::root(),
+            bounded_vec![TrustedRootCertificateUpdate::Add(
+                authority.root_certificate().clone()
+            )],
+        ));
+        assert_ok!(Acurast::set_attestation_policy(
+            RuntimeOrigin::root(),
+            Some(AttestationPolicy {
+                min_security_level: Some(AttestationSecurityLevel::TrustedEnvironemnt),
+                require_verified_boot: true,
+                require_locked_bootloader: true,
+                min_os_patch_level: Some(202306),
+                min_vendor_patch_level: Some(20230605),
+                allowed_package_names: Some(bounded_vec![b"com.acurast.attested.executor.test"
+                    .to_vec()
+                    .try_into()
+                    .unwrap()]),
+                allowed_signature_digests: Some(bounded_vec![[2u8; 32]
+                    .to_vec()
+                    .try_into()
+                    .unwrap()]),
+            }),
+        ));
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(source.clone()).into(),
+            AttestationChain::AndroidKeyAttestation {
+                certificate_chain: chain
+            }
+        ));
+
+        let attestation = Acurast::stored_attestation(&source).unwrap();
+        assert_eq!(2, attestation.cert_ids.len());
+        assert_eq!(
+            Attestation {
+                cert_ids: attestation.cert_ids.clone(),
+                ..builder.build()
+            },
+            attestation
+        );
+    });
+}
+
+#[test]
+fn test_attestation_expiry_sweep() {
+    ExtBuilder::default().build().execute_with(|| {
+        // not_after of the attestation certificate, 2028-05-23T23:59:59Z
+        let expiry = 1842739199000;
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
+        let _ = events();
+
+        assert_eq!(Weight::zero(), Acurast::on_idle(1, Weight::zero()));
+        assert_ne!(Weight::zero(), Acurast::on_idle(1, Weight::MAX));
+        assert!(Acurast::stored_attestation(processor_account_id()).is_some());
+        assert_eq!(events(), []);
+
+        Timestamp::set_timestamp(expiry - AttestationExpiryGracePeriod::get());
+        Acurast::on_idle(1, Weight::MAX);
+        Acurast::on_idle(1, Weight::MAX);
+        assert!(Acurast::stored_attestation(processor_account_id()).is_some());
+        assert_eq!(
+            events(),
+            [RuntimeEvent::Acurast(
+                crate::Event::AttestationExpiringSoon(processor_account_id(), expiry)
+            )]
+        );
+
+        Timestamp::set_timestamp(expiry);
+        Acurast::on_idle(1, Weight::MAX);
+        assert_eq!(None, Acurast::stored_attestation(processor_account_id()));
+        assert_eq!(
+            events(),
+            [RuntimeEvent::Acurast(crate::Event::AttestationExpired(
+                processor_account_id()
+            ))]
+        );
+    });
+}
+
+#[test]
+fn test_submit_revocation_status_list() {
+    ExtBuilder::default().build().execute_with(|| {
+        let snapshot = |sequence: u64, status_list: &[u8]| RevocationStatusListSnapshot {
+            sequence,
+            status_list: status_list.to_vec().try_into().unwrap(),
+        };
+        let snapshot_1 = snapshot(
+            1,
+            br#"{"entries": {
+                "15905857467176635834": {"status": "REVOKED", "reason": "KEY_COMPROMISE"},
+                "2c8cdddfd5e03bfc": {"status": "SUSPENDED", "reason": "SOFTWARE_FLAW"}
+            }}"#,
+        );
+        let snapshot_2 = snapshot(
+            2,
+            br#"{"entries": {"2c8cdddfd5e03bfc": {"status": "REVOKED", "reason": "SOFTWARE_FLAW"}}}"#,
+        );
+        let sign = |snapshot: &RevocationStatusListSnapshot<_>| {
+            revocation_status_list_signer().sign(&snapshot.encode())
+        };
+        let submit = |snapshot: RevocationStatusListSnapshot<_>, signature| {
+            Acurast::submit_revocation_status_list(
+                RuntimeOrigin::signed(bob_account_id()).into(),
+                snapshot,
+                signature,
+            )
+        };
+
+        assert_err!(
+            submit(
+                snapshot_1.clone(),
+                ed25519::Pair::from_seed(&[2; 32]).sign(&snapshot_1.encode())
+            ),
+            Error::<Test>::InvalidRevocationStatusListSignature
+        );
+        let invalid_snapshot = snapshot(1, br#"{"entries": {"xyz": {"status": "REVOKED"}}}"#);
+        assert_err!(
+            submit(invalid_snapshot.clone(), sign(&invalid_snapshot)),
+            Error::<Test>::InvalidRevocationStatusList
+        );
+
+        assert_ok!(submit(snapshot_1.clone(), sign(&snapshot_1)));
+        assert_eq!(1, Acurast::revocation_status_list_sequence());
+        assert_err!(
+            submit(snapshot_1.clone(), sign(&snapshot_1)),
+            Error::<Test>::OutdatedRevocationStatusList
+        );
+
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+        assert_err!(
+            Acurast::submit_attestation(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                attestation_chain()
+            ),
+            Error::<Test>::RevokedCertificate(CertificateRevocation {
+                status: RevocationStatus::Revoked,
+                reason: RevocationReason::KeyCompromise,
+            })
+        );
+
+        assert_ok!(submit(snapshot_2.clone(), sign(&snapshot_2)));
+        assert_eq!(
+            None,
+            Acurast::stored_revoked_serial_number::<SerialNumber>(cert_serial_number())
+        );
+        // the changed status keeps the time of the first revocation
+        assert_eq!(
+            Some(RevocationEntry {
+                revocation: CertificateRevocation {
+                    status: RevocationStatus::Revoked,
+                    reason: RevocationReason::SoftwareFlaw,
+                },
+                revoked_at: Some(0),
+            }),
+            Acurast::stored_revoked_serial_number::<SerialNumber>(
+                hex!("2c8cdddfd5e03bfc").to_vec().try_into().unwrap()
+            )
+        );
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
+
+        let attestation =
+            validate_and_extract_attestation::<Test>(&processor_account_id(), &attestation_chain())
+                .unwrap();
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::RevocationStatusListApplied(1, 2, 0)),
+                RuntimeEvent::Acurast(crate::Event::RevocationStatusListApplied(2, 1, 1)),
+                RuntimeEvent::Acurast(crate::Event::AttestationStored(
+                    attestation,
+                    processor_account_id()
+                )),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_set_environment() {
+    let registration = job_registration(
+        Some(bounded_vec![
+            alice_account_id(),
+            bob_account_id(),
+            charlie_account_id(),
+            dave_account_id(),
+        ]),
+        false,
+    );
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        let env = Environment {
+            public_key: PubKey::SECP256r1(BoundedVec::truncate_from(
+                hex!("000000000000000000000000000000000000000000000000000000000000000000").into(),
+            )),
+            variables: bounded_vec![(
+                BoundedVec::truncate_from(hex!("AAAA").into()),
+                BoundedVec::truncate_from(hex!("BBBB").into())
+            )],
+        };
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            initial_job_id + 1,
+            bob_account_id(),
+            env.clone(),
+            None
+        ));
+        assert_eq!(
+            Acurast::environment_at(&job_id, &bob_account_id(), 0),
+            Some(env)
+        );
+
         assert_eq!(
             events(),
             [
@@ -599,9 +1485,350 @@ fn test_set_environment() {
                 )),
                 RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
                     job_id.clone(),
-                    bob_account_id()
+                    bob_account_id(),
+                    0
                 )),
             ]
         );
     });
 }
+
+#[test]
+fn test_set_environment_versions() {
+    let registration = job_registration(None, false);
+    let env = |value: u8| Environment {
+        public_key: PubKey::SECP256r1(BoundedVec::truncate_from(
+            hex!("000000000000000000000000000000000000000000000000000000000000000000").into(),
+        )),
+        variables: bounded_vec![(
+            BoundedVec::truncate_from(hex!("AAAA").into()),
+            BoundedVec::truncate_from(vec![value])
+        )],
+    };
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Timestamp::set(RuntimeOrigin::none(), 1000);
+        let initial_job_id = Acurast::job_id_sequence();
+
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            initial_job_id + 1,
+            bob_account_id(),
+            env(1),
+            None
+        ));
+        // stage new versions for executions starting in the future
+        assert_ok!(Acurast::set_environments(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            initial_job_id + 1,
+            bounded_vec![(bob_account_id(), env(2)), (charlie_account_id(), env(2))],
+            Some(2000)
+        ));
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            initial_job_id + 1,
+            bob_account_id(),
+            env(3),
+            Some(3000)
+        ));
+        // replaces the version staged with the same effective-from timestamp
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            initial_job_id + 1,
+            bob_account_id(),
+            env(4),
+            Some(3000)
+        ));
+
+        assert_eq!(
+            Acurast::environment_at(&job_id, &bob_account_id(), 999),
+            None
+        );
+        assert_eq!(
+            Acurast::environment_at(&job_id, &bob_account_id(), 1000),
+            Some(env(1))
+        );
+        assert_eq!(
+            Acurast::environment_at(&job_id, &bob_account_id(), 1999),
+            Some(env(1))
+        );
+        assert_eq!(
+            Acurast::environment_at(&job_id, &bob_account_id(), 2000),
+            Some(env(2))
+        );
+        assert_eq!(
+            Acurast::environment_at(&job_id, &bob_account_id(), 3500),
+            Some(env(4))
+        );
+        assert_eq!(
+            Acurast::environment_at(&job_id, &charlie_account_id(), 1500),
+            None
+        );
+        assert_eq!(
+            Acurast::environment_at(&job_id, &charlie_account_id(), 2500),
+            Some(env(2))
+        );
+        assert_eq!(
+            Acurast::execution_environment(&job_id, &bob_account_id())
+                .unwrap()
+                .len(),
+            3
+        );
+
+        assert_err!(
+            Acurast::set_environment(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                initial_job_id + 1,
+                bob_account_id(),
+                env(5),
+                Some(999)
+            ),
+            Error::<Test>::EnvironmentEffectiveFromInPast
+        );
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            initial_job_id + 1,
+            bob_account_id(),
+            env(5),
+            Some(4000)
+        ));
+        assert_err!(
+            Acurast::set_environment(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                initial_job_id + 1,
+                bob_account_id(),
+                env(6),
+                Some(5000)
+            ),
+            Error::<Test>::TooManyEnvironmentVersions
+        );
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::JobRegistrationStored(
+                    registration.clone(),
+                    job_id.clone()
+                )),
+                RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    bob_account_id(),
+                    1000
+                )),
+                RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    bob_account_id(),
+                    2000
+                )),
+                RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    charlie_account_id(),
+                    2000
+                )),
+                RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    bob_account_id(),
+                    3000
+                )),
+                RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    bob_account_id(),
+                    3000
+                )),
+                RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    bob_account_id(),
+                    4000
+                )),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_transfer_job() {
+    let registration = job_registration(None, false);
+    let env = Environment {
+        public_key: PubKey::SECP256r1(BoundedVec::truncate_from(
+            hex!("000000000000000000000000000000000000000000000000000000000000000000").into(),
+        )),
+        variables: bounded_vec![(
+            BoundedVec::truncate_from(hex!("AAAA").into()),
+            BoundedVec::truncate_from(hex!("BBBB").into())
+        )],
+    };
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        let new_job_id = (MultiOrigin::Acurast(bob_account_id()), initial_job_id + 1);
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            charlie_account_id(),
+            env.clone(),
+            None
+        ));
+
+        assert_err!(
+            Acurast::transfer_job(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                alice_account_id()
+            ),
+            Error::<Test>::JobTransferToOwner
+        );
+        assert_err!(
+            Acurast::transfer_job(
+                RuntimeOrigin::signed(bob_account_id()).into(),
+                job_id.1,
+                charlie_account_id()
+            ),
+            Error::<Test>::JobRegistrationNotFound
+        );
+        assert_err!(
+            Acurast::cancel_job_transfer(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1
+            ),
+            Error::<Test>::JobTransferNotFound
+        );
+
+        assert_ok!(Acurast::transfer_job(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            charlie_account_id()
+        ));
+        assert_ok!(Acurast::cancel_job_transfer(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1
+        ));
+        assert_err!(
+            Acurast::accept_job_transfer(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id.clone()
+            ),
+            Error::<Test>::JobTransferNotFound
+        );
+
+        assert_ok!(Acurast::transfer_job(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            bob_account_id()
+        ));
+        // only the proposed new owner can accept
+        assert_err!(
+            Acurast::accept_job_transfer(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id.clone()
+            ),
+            Error::<Test>::JobTransferNotFound
+        );
+        assert_ok!(Acurast::accept_job_transfer(
+            RuntimeOrigin::signed(bob_account_id()).into(),
+            job_id.clone()
+        ));
+
+        assert_eq!(Acurast::stored_job_registration(&job_id.0, job_id.1), None);
+        assert_eq!(
+            Acurast::stored_job_registration(&new_job_id.0, new_job_id.1),
+            Some(registration.clone())
+        );
+        assert_eq!(
+            Acurast::environment_at(&job_id, &charlie_account_id(), 0),
+            None
+        );
+        assert_eq!(
+            Acurast::environment_at(&new_job_id, &charlie_account_id(), 0),
+            Some(env)
+        );
+        assert_eq!(Acurast::job_transfer(&job_id), None);
+
+        // the new owner manages the job under the new job id
+        assert_ok!(Acurast::deregister(
+            RuntimeOrigin::signed(bob_account_id()).into(),
+            new_job_id.1
+        ));
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::Acurast(crate::Event::JobRegistrationStored(
+                    registration.clone(),
+                    job_id.clone()
+                )),
+                RuntimeEvent::Acurast(crate::Event::ExecutionEnvironmentUpdated(
+                    job_id.clone(),
+                    charlie_account_id(),
+                    0
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobTransferProposed(
+                    job_id.clone(),
+                    Some(charlie_account_id())
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobTransferProposed(job_id.clone(), None)),
+                RuntimeEvent::Acurast(crate::Event::JobTransferProposed(
+                    job_id.clone(),
+                    Some(bob_account_id())
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobTransferred(
+                    job_id.clone(),
+                    new_job_id.clone()
+                )),
+                RuntimeEvent::Acurast(crate::Event::JobRegistrationRemoved(new_job_id.clone())),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_migrate_v3_registrations() {
+    let registration = job_registration(None, false);
+    let v3_registration = crate::migration::v3::JobRegistration {
+        script: SCRIPT_BYTES.to_vec().try_into().unwrap(),
+        allowed_sources: registration.allowed_sources.clone(),
+        allow_only_verified_sources: registration.allow_only_verified_sources,
+        schedule: crate::migration::v1::Schedule {
+            duration: registration.schedule.duration,
+            start_time: registration.schedule.start_time,
+            end_time: registration.schedule.end_time,
+            interval: registration.schedule.interval,
+            max_start_delay: registration.schedule.max_start_delay,
+        },
+        memory: registration.memory,
+        network_requests: registration.network_requests,
+        storage: registration.storage,
+        required_modules: registration.required_modules.clone(),
+        extra: (),
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let origin = MultiOrigin::Acurast(alice_account_id());
+        StorageVersion::new(3).put::<Acurast>();
+        frame_support::storage::unhashed::put_raw(
+            &crate::StoredJobRegistration::<Test>::hashed_key_for(&origin, 1),
+            &v3_registration.encode(),
+        );
+
+        Acurast::on_runtime_upgrade();
+
+        // the unchanged extra is kept by the default extra migration
+        assert_eq!(
+            Some(registration),
+            Acurast::stored_job_registration(&origin, 1)
+        );
+        assert_eq!(
+            Acurast::current_storage_version(),
+            Acurast::on_chain_storage_version()
+        );
+    });
+}
@@ -1,10 +1,13 @@
-use acurast_common::{Attestation, JobId, MultiOrigin};
+use acurast_common::{Attestation, AttestationPolicy, JobId, MultiOrigin, PublicKey};
+use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchResultWithPostInfo;
+use frame_support::pallet_prelude::{MaxEncodedLen, Member, Parameter};
 use frame_support::weights::Weight;
 use sp_std::prelude::*;
 
 use crate::{
-    AllowedSourcesUpdate, CertificateRevocationListUpdate, Config, Error, JobRegistrationFor,
+    AllowedSourcesUpdate, CertificateRevocationListUpdate, Config, EnvironmentFor, Error,
+    JobRegistrationFor, StoredAttestationPolicy,
 };
 
 /// Allows to customize who can perform an update to the certificate revocation list.
@@ -35,6 +38,86 @@ impl<T: Config> KeyAttestationBarrier<T> for () {
     }
 }
 
+/// Accepts attestations fulfilling the [AttestationPolicy] stored in [crate::StoredAttestationPolicy].
+///
+/// All attestations are accepted as long as no policy is set.
+pub struct AttestationPolicyBarrier;
+
+impl<T: Config> KeyAttestationBarrier<T> for AttestationPolicyBarrier {
+    fn accept_attestation_for_origin(_origin: &T::AccountId, attestation: &Attestation) -> bool {
+        <StoredAttestationPolicy<T>>::get().map_or(true, |policy| policy.accepts(attestation))
+    }
+}
+
+/// Allows to customize how the public key attested by a key attestation is bound to the account submitting it.
+pub trait AttestationKeyBinding<T: Config> {
+    fn ensure_bound(source: &T::AccountId, public_key: &PublicKey) -> Result<(), Error<T>>;
+}
+
+/// Binds keys as [PublicKeyHashBinding] does.
+impl<T: Config> AttestationKeyBinding<T> for () {
+    fn ensure_bound(source: &T::AccountId, public_key: &PublicKey) -> Result<(), Error<T>> {
+        <PublicKeyHashBinding as AttestationKeyBinding<T>>::ensure_bound(source, public_key)
+    }
+}
+
+/// Binds P-256, P-384 and RSA keys by requiring the account id to be the blake2_256 hash of the key's
+/// [PublicKey::encode_canonical] encoding. This is the recommended binding.
+///
+/// The hashed bytes are, depending on the key type:
+/// * P-256: the 33 bytes of the compressed SEC1 point, i.e. `0x02` or `0x03` followed by the big endian x coordinate.
+/// * P-384: the 97 bytes of the uncompressed SEC1 point, i.e. `0x04` followed by the big endian x and y coordinates.
+/// * RSA: the DER encoding of the PKCS#1 `RSAPublicKey ::= SEQUENCE { modulus INTEGER, publicExponent INTEGER }`, with
+///   both integers minimally encoded as positive numbers.
+pub struct PublicKeyHashBinding;
+
+impl<T: Config> AttestationKeyBinding<T> for PublicKeyHashBinding {
+    fn ensure_bound(source: &T::AccountId, public_key: &PublicKey) -> Result<(), Error<T>> {
+        let encoded_public_key = public_key
+            .encode_canonical()
+            .map_err(|_| Error::<T>::UnsupportedAttestationPublicKeyType)?;
+        let encoded_source = source.encode();
+
+        if encoded_source != sp_io::hashing::blake2_256(&encoded_public_key).to_vec() {
+            return Err(Error::<T>::AttestationPublicKeyDoesNotMatchSource);
+        }
+        Ok(())
+    }
+}
+
+/// Allows to override the reward offered by a [Config::RegistrationExtra], e.g. when registering a job from a template.
+pub trait RegistrationExtraReward {
+    type Reward: Parameter + Member + MaxEncodedLen;
+
+    fn set_reward(&mut self, reward: Self::Reward);
+}
+
+/// An extra without reward.
+impl RegistrationExtraReward for () {
+    type Reward = ();
+
+    fn set_reward(&mut self, _reward: Self::Reward) {}
+}
+
+/// Converts the [Config::RegistrationExtra] of job registrations stored before storage version 11, when the registrations
+/// get migrated to the current layout.
+pub trait RegistrationExtraMigration<T: Config> {
+    /// The extra as stored before storage version 11.
+    type OldExtra: Decode;
+
+    /// Converts `extra`, removing its registration if [None] is returned.
+    fn migrate(extra: Self::OldExtra) -> Option<T::RegistrationExtra>;
+}
+
+/// Keeps the extra unchanged.
+impl<T: Config> RegistrationExtraMigration<T> for () {
+    type OldExtra = T::RegistrationExtra;
+
+    fn migrate(extra: Self::OldExtra) -> Option<T::RegistrationExtra> {
+        Some(extra)
+    }
+}
+
 /// Weight functions needed for pallet_acurast.
 pub trait WeightInfo {
     fn register() -> Weight;
@@ -43,6 +126,19 @@ pub trait WeightInfo {
     fn submit_attestation() -> Weight;
     fn update_certificate_revocation_list() -> Weight;
     fn set_environment(x: u32) -> Weight;
+    fn update_registration() -> Weight;
+    fn update_trusted_root_certificates(x: u32) -> Weight;
+    fn set_attestation_policy() -> Weight;
+    fn sweep_attestation() -> Weight;
+    fn submit_revocation_status_list(x: u32, y: u32) -> Weight;
+    fn set_environments(x: u32) -> Weight;
+    fn transfer_job() -> Weight;
+    fn cancel_job_transfer() -> Weight;
+    fn accept_job_transfer() -> Weight;
+    fn create_job_template() -> Weight;
+    fn remove_job_template() -> Weight;
+    fn register_from_template() -> Weight;
+    fn update_allowed_app_ids(x: u32) -> Weight;
 }
 
 /// Allows to hook additional logic for various job related extrinsics.
@@ -60,6 +156,30 @@ pub trait JobHooks<T: Config> {
         job_id: &JobId<<T as frame_system::Config>::AccountId>,
         updates: &Vec<AllowedSourcesUpdate<<T as frame_system::Config>::AccountId>>,
     ) -> DispatchResultWithPostInfo;
+    /// Called before the `registration` replaces the `previous` registration in storage.
+    ///
+    /// Implementations can reject the update depending on the job's current state.
+    fn update_registration_hook(
+        who: &MultiOrigin<T::AccountId>,
+        job_id: &JobId<<T as frame_system::Config>::AccountId>,
+        previous: &JobRegistrationFor<T>,
+        registration: &JobRegistrationFor<T>,
+    ) -> DispatchResultWithPostInfo;
+    /// Called before the `environment` of `source` is stored for a job.
+    ///
+    /// Implementations can reject environments not encrypted for a key the source revealed for the job.
+    fn set_environment_hook(
+        job_id: &JobId<<T as frame_system::Config>::AccountId>,
+        source: &<T as frame_system::Config>::AccountId,
+        environment: &EnvironmentFor<T>,
+    ) -> DispatchResultWithPostInfo;
+    /// Called when the job `job_id` is transferred to a new owner and continues as `new_job_id`.
+    ///
+    /// Implementations have to move the state they keep for the job to `new_job_id` or fail to reject the transfer.
+    fn transfer_hook(
+        job_id: &JobId<<T as frame_system::Config>::AccountId>,
+        new_job_id: &JobId<<T as frame_system::Config>::AccountId>,
+    ) -> DispatchResultWithPostInfo;
 }
 
 impl<T: Config> JobHooks<T> for () {
@@ -82,6 +202,27 @@ impl<T: Config> JobHooks<T> for () {
     ) -> DispatchResultWithPostInfo {
         Ok(().into())
     }
+    fn update_registration_hook(
+        _who: &MultiOrigin<T::AccountId>,
+        _job_id: &JobId<<T as frame_system::Config>::AccountId>,
+        _previous: &JobRegistrationFor<T>,
+        _registration: &JobRegistrationFor<T>,
+    ) -> DispatchResultWithPostInfo {
+        Ok(().into())
+    }
+    fn set_environment_hook(
+        _job_id: &JobId<<T as frame_system::Config>::AccountId>,
+        _source: &<T as frame_system::Config>::AccountId,
+        _environment: &EnvironmentFor<T>,
+    ) -> DispatchResultWithPostInfo {
+        Ok(().into())
+    }
+    fn transfer_hook(
+        _job_id: &JobId<<T as frame_system::Config>::AccountId>,
+        _new_job_id: &JobId<<T as frame_system::Config>::AccountId>,
+    ) -> DispatchResultWithPostInfo {
+        Ok(().into())
+    }
 }
 
 impl<T: Config> From<()> for Error<T> {
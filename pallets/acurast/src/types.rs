@@ -0,0 +1,71 @@
+use core::fmt::Debug;
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use acurast_common::{
+    Attestation, AttestationContent, AttestationSecurityLevel, AttestationValidity,
+};
+
+use crate::utils::attestation_expiry;
+
+/// The maximum number of job registrations returned by a single call of [crate::AcurastRuntimeApi::job_registrations].
+pub const MAX_JOB_REGISTRATIONS_PAGE_SIZE: u32 = 100;
+
+/// A processor's [Attestation] together with its decoded security levels and validity.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+pub struct AttestationInfo {
+    /// The security level of the attestation, [None] for Apple App Attest attestations.
+    pub attestation_security_level: Option<AttestationSecurityLevel>,
+    /// The security level of the attested key, [None] for Apple App Attest attestations.
+    pub key_mint_security_level: Option<AttestationSecurityLevel>,
+    /// The validity of the attestation certificate.
+    pub validity: AttestationValidity,
+    /// The timestamp in milliseconds from which on the attestation is expired, considering the key's usage expiry.
+    pub expiry: u64,
+    /// The stored attestation.
+    pub attestation: Attestation,
+}
+
+impl From<Attestation> for AttestationInfo {
+    fn from(attestation: Attestation) -> Self {
+        let (attestation_security_level, key_mint_security_level) = match &attestation.content {
+            AttestationContent::KeyDescription(key_description) => (
+                Some(key_description.attestation_security_level.clone()),
+                Some(key_description.key_mint_security_level.clone()),
+            ),
+            AttestationContent::AppAttest(_) => (None, None),
+        };
+        Self {
+            attestation_security_level,
+            key_mint_security_level,
+            validity: attestation.validity,
+            expiry: attestation_expiry(&attestation),
+            attestation,
+        }
+    }
+}
+
+/// Runtime API error.
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+#[derive(RuntimeDebug, codec::Encode, codec::Decode, PartialEq, Eq, TypeInfo)]
+pub enum RuntimeApiError {
+    /// Error when checking if a source is verified failed.
+    #[cfg_attr(feature = "std", error("Checking if the source is verified failed."))]
+    IsVerified,
+}
+
+impl RuntimeApiError {
+    /// Consume given error `e` with `self` and generate a native log entry with error details.
+    pub fn log_error(self, e: impl Debug) -> Self {
+        log::error!(
+            target: "runtime::acurast",
+            "[{:?}] error: {:?}",
+            self,
+            e,
+        );
+        self
+    }
+}
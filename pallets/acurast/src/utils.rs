@@ -1,14 +1,17 @@
 use acurast_common::{
-    extract_attestation, validate_certificate_chain, validate_certificate_chain_root, ECDSACurve,
-    PublicKey,
+    app_attest::{validate_app_attestation, APPLE_APP_ATTESTATION_ROOT_CERT},
+    certificate_fingerprint, extract_attestation, validate_certificate_chain,
+    validate_certificate_chain_root, CertificateChainInput, CertificateId, CertificateInput,
 };
 use codec::Encode;
 use frame_support::{ensure, traits::UnixTime};
 use sp_std::prelude::*;
 
 use crate::{
-    Attestation, AttestationChain, AttestationValidity, CertId, Config, Error, IssuerName,
-    SerialNumber, StoredAttestation, StoredRevokedCertificate, ValidatingCertIds,
+    AllowedAppIds, AppAttestKeyId, AppAttestationObject, AppId, Attestation, AttestationChain,
+    AttestationContent, AttestationKeyBinding, AttestationValidity, BoundedAppAttest, CertId,
+    Config, Error, IssuerName, SerialNumber, StoredAttestation, StoredRevokedCertificate,
+    StoredRevokedSerialNumber, TrustedRootCertificates, ValidatingCertIds,
 };
 
 /// Validates and returns an [Attestation] from the provided chain.
@@ -16,14 +19,36 @@ pub fn validate_and_extract_attestation<T: Config>(
     source: &T::AccountId,
     attestation_chain: &AttestationChain,
 ) -> Result<Attestation, Error<T>> {
-    validate_certificate_chain_root(&attestation_chain.certificate_chain)
-        .map_err(|_| Error::<T>::RootCertificateValidationFailed)?;
+    match attestation_chain {
+        AttestationChain::AndroidKeyAttestation { certificate_chain } => {
+            validate_and_extract_key_attestation::<T>(source, certificate_chain)
+        }
+        AttestationChain::AppleAppAttest {
+            attestation_object,
+            key_id,
+            app_id,
+        } => validate_and_extract_app_attestation::<T>(source, attestation_object, key_id, app_id),
+    }
+}
+
+fn validate_and_extract_key_attestation<T: Config>(
+    source: &T::AccountId,
+    certificate_chain: &CertificateChainInput,
+) -> Result<Attestation, Error<T>> {
+    let trusted_root_certs = trusted_root_certificate_for::<T>(certificate_chain);
+    validate_certificate_chain_root(
+        certificate_chain,
+        &trusted_root_certs
+            .iter()
+            .map(|certificate| certificate.as_slice())
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|_| Error::<T>::RootCertificateValidationFailed)?;
 
-    let (cert_ids, cert, public_key) =
-        validate_certificate_chain(&attestation_chain.certificate_chain)
-            .map_err(|_| Error::<T>::CertificateChainValidationFailed)?;
+    let (cert_ids, cert, public_key) = validate_certificate_chain(certificate_chain)
+        .map_err(|_| Error::<T>::CertificateChainValidationFailed)?;
 
-    ensure_valid_public_key_for_source(source, &public_key)?;
+    T::AttestationKeyBinding::ensure_bound(source, &public_key)?;
 
     let attestation_validity = AttestationValidity {
         not_before: cert.validity.not_before.timestamp_millis(),
@@ -33,6 +58,68 @@ pub fn validate_and_extract_attestation<T: Config>(
     let key_description = extract_attestation(cert.extensions)
         .map_err(|_| Error::<T>::AttestationExtractionFailed)?;
 
+    Ok(Attestation {
+        cert_ids: bounded_cert_ids::<T>(cert_ids)?,
+        content: AttestationContent::KeyDescription(
+            key_description
+                .try_into()
+                .map_err(|_| Error::<T>::AttestationToBoundedTypeConversionFailed)?,
+        ),
+        validity: attestation_validity,
+    })
+}
+
+/// Returns the trusted root certificate matching the first certificate of the chain, if any.
+fn trusted_root_certificate_for<T: Config>(
+    certificate_chain: &CertificateChainInput,
+) -> Option<CertificateInput> {
+    let root = certificate_chain.first()?;
+    <TrustedRootCertificates<T>>::get(certificate_fingerprint(root))
+}
+
+/// Validates an App Attest attestation object.
+///
+/// The attestation has to be created for one of the [AllowedAppIds] with the sha256 hash of the SCALE encoded `source`
+/// as client data hash, binding the attested key to the account.
+fn validate_and_extract_app_attestation<T: Config>(
+    source: &T::AccountId,
+    attestation_object: &AppAttestationObject,
+    key_id: &AppAttestKeyId,
+    app_id: &AppId,
+) -> Result<Attestation, Error<T>> {
+    ensure!(
+        <AllowedAppIds<T>>::contains_key(app_id),
+        Error::<T>::AppIdNotAllowed
+    );
+
+    let client_data_hash = sp_io::hashing::sha2_256(&source.encode());
+    let app_attestation = validate_app_attestation(
+        attestation_object,
+        APPLE_APP_ATTESTATION_ROOT_CERT,
+        key_id,
+        app_id,
+        &client_data_hash,
+    )
+    .map_err(|_| Error::<T>::AppAttestationValidationFailed)?;
+
+    Ok(Attestation {
+        cert_ids: bounded_cert_ids::<T>(app_attestation.cert_ids)?,
+        content: AttestationContent::AppAttest(BoundedAppAttest {
+            app_id: app_id.clone(),
+            key_id: *key_id,
+            counter: app_attestation.counter,
+            environment: app_attestation.environment.into(),
+        }),
+        validity: AttestationValidity {
+            not_before: app_attestation.validity.not_before.timestamp_millis(),
+            not_after: app_attestation.validity.not_after.timestamp_millis(),
+        },
+    })
+}
+
+fn bounded_cert_ids<T: Config>(
+    cert_ids: Vec<CertificateId>,
+) -> Result<ValidatingCertIds, Error<T>> {
     let cert_ids_bounded = cert_ids
         .into_iter()
         .map(|cert_id| {
@@ -44,16 +131,7 @@ pub fn validate_and_extract_attestation<T: Config>(
             Ok((iss_bounded, sn_bounded))
         })
         .collect::<Result<Vec<CertId>, Error<T>>>()?;
-    let cert_ids_bounded_vec = ValidatingCertIds::try_from(cert_ids_bounded)
-        .map_err(|_| Error::<T>::CannotGetCertificateId)?;
-
-    Ok(Attestation {
-        cert_ids: cert_ids_bounded_vec,
-        key_description: key_description
-            .try_into()
-            .map_err(|_| Error::<T>::AttestationToBoundedTypeConversionFailed)?,
-        validity: attestation_validity,
-    })
+    ValidatingCertIds::try_from(cert_ids_bounded).map_err(|_| Error::<T>::CannotGetCertificateId)
 }
 
 /// Ensures that the provided account id has a valid (not expired and not revoked) key attestation.
@@ -65,27 +143,57 @@ pub fn ensure_source_verified<T: Config>(source: &T::AccountId) -> Result<(), Er
     Ok(())
 }
 
-/// Ensures the attestation is not expired.
-pub(crate) fn ensure_not_expired<T: Config>(attestation: &Attestation) -> Result<(), Error<T>> {
-    let now: u64 = T::UnixTime::now()
+/// Ensures that the provided account id has a valid key attestation for work performed until timestamp `at` in milliseconds.
+///
+/// Same as [ensure_source_verified] but ignores revocations that took effect after `at`.
+pub fn ensure_source_verified_at<T: Config>(
+    source: &T::AccountId,
+    at: u64,
+) -> Result<(), Error<T>> {
+    let attestation =
+        <StoredAttestation<T>>::get(source).ok_or(Error::<T>::FulfillSourceNotVerified)?;
+    ensure_not_expired(&attestation)?;
+    ensure_not_revoked_at(&attestation, at)?;
+    Ok(())
+}
+
+/// Returns the timestamp in milliseconds from which on work of the provided account id is no longer covered by its key attestation
+/// because one of the attestation's certificates got revoked, or [None] if none of them is revoked.
+///
+/// Fails like [ensure_source_verified] if the account id has no valid (not expired) key attestation.
+pub fn source_revoked_at<T: Config>(source: &T::AccountId) -> Result<Option<u64>, Error<T>> {
+    let attestation =
+        <StoredAttestation<T>>::get(source).ok_or(Error::<T>::FulfillSourceNotVerified)?;
+    ensure_not_expired(&attestation)?;
+    Ok(attestation
+        .cert_ids
+        .iter()
+        .flat_map(|id| {
+            <StoredRevokedCertificate<T>>::get(id)
+                .into_iter()
+                .chain(<StoredRevokedSerialNumber<T>>::get(&id.1))
+        })
+        // revocations without a timestamp are effective for any work performed
+        .map(|entry| entry.revoked_at.unwrap_or(0))
+        .min())
+}
+
+/// Returns the current timestamp in milliseconds.
+pub(crate) fn now<T: Config>() -> Result<u64, Error<T>> {
+    T::UnixTime::now()
         .as_millis()
         .try_into()
-        .map_err(|_| Error::<T>::FailedTimestampConversion)?;
+        .map_err(|_| Error::<T>::FailedTimestampConversion)
+}
+
+/// Ensures the attestation is not expired.
+pub(crate) fn ensure_not_expired<T: Config>(attestation: &Attestation) -> Result<(), Error<T>> {
+    let now = now::<T>()?;
 
     if now >= attestation.validity.not_after || now < attestation.validity.not_before {
         return Err(Error::<T>::AttestationCertificateNotValid);
     }
-    let expire_date_time = attestation
-        .key_description
-        .tee_enforced
-        .usage_expire_date_time
-        .or({
-            attestation
-                .key_description
-                .software_enforced
-                .usage_expire_date_time
-        });
-    if let Some(expire_date_time) = expire_date_time {
+    if let Some(expire_date_time) = usage_expire_date_time(attestation) {
         if now >= expire_date_time {
             return Err(Error::<T>::AttestationUsageExpired);
         }
@@ -93,37 +201,44 @@ pub(crate) fn ensure_not_expired<T: Config>(attestation: &Attestation) -> Result
     Ok(())
 }
 
+/// Returns the timestamp in milliseconds from which on the attestation is expired,
+/// the earlier of the attestation certificate's `not_after` and the key's usage expiry.
+pub(crate) fn attestation_expiry(attestation: &Attestation) -> u64 {
+    let not_after = attestation.validity.not_after;
+    usage_expire_date_time(attestation).map_or(not_after, |expire_date_time| {
+        expire_date_time.min(not_after)
+    })
+}
+
+fn usage_expire_date_time(attestation: &Attestation) -> Option<u64> {
+    // App Attest keys do not carry a usage expiry, only the credential certificate's validity applies
+    match &attestation.content {
+        AttestationContent::KeyDescription(key_description) => key_description
+            .tee_enforced
+            .usage_expire_date_time
+            .or(key_description.software_enforced.usage_expire_date_time),
+        AttestationContent::AppAttest(_) => None,
+    }
+}
+
 /// Ensures the attestation is not signed by a revoked certificate.
 pub(crate) fn ensure_not_revoked<T: Config>(attestation: &Attestation) -> Result<(), Error<T>> {
-    let ids = &attestation.cert_ids;
-    for id in ids {
-        if <StoredRevokedCertificate<T>>::get(&id.1).is_some() {
-            return Err(Error::<T>::RevokedCertificate);
-        }
-    }
-    Ok(())
+    ensure_not_revoked_at(attestation, u64::MAX)
 }
 
-/// Ensures the provided public key correponds to the provided account id.
-fn ensure_valid_public_key_for_source<T: Config>(
-    source: &T::AccountId,
-    public_key: &PublicKey,
+/// Ensures the attestation is not signed by a certificate revoked for work performed at timestamp `at` in milliseconds.
+pub(crate) fn ensure_not_revoked_at<T: Config>(
+    attestation: &Attestation,
+    at: u64,
 ) -> Result<(), Error<T>> {
-    match public_key {
-        PublicKey::RSA(_) => Err(Error::<T>::UnsupportedAttestationPublicKeyType),
-        PublicKey::ECDSA(public_key) => match public_key {
-            ECDSACurve::CurveP256(public_key) => {
-                let encoded_source = source.encode();
-                let encoded_public_key =
-                    sp_io::hashing::blake2_256(&public_key.to_bytes()).to_vec();
-
-                ensure!(
-                    encoded_source == encoded_public_key,
-                    Error::<T>::AttestationPublicKeyDoesNotMatchSource
-                );
-                Ok(())
-            }
-            ECDSACurve::CurveP384(_) => Err(Error::<T>::UnsupportedAttestationPublicKeyType),
-        },
+    for id in &attestation.cert_ids {
+        let entry = <StoredRevokedCertificate<T>>::get(id)
+            .into_iter()
+            .chain(<StoredRevokedSerialNumber<T>>::get(&id.1))
+            .find(|entry| entry.is_effective_at(at));
+        if let Some(entry) = entry {
+            return Err(Error::<T>::RevokedCertificate(entry.revocation));
+        }
     }
+    Ok(())
 }
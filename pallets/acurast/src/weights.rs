@@ -102,45 +102,300 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Storage: Timestamp Now (r:1 w:0)
 	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	/// Storage: Acurast StoredRevokedCertificate (r:4 w:0)
-	/// Proof: Acurast StoredRevokedCertificate (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Proof: Acurast StoredRevokedCertificate (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredRevokedSerialNumber (r:4 w:0)
+	/// Proof: Acurast StoredRevokedSerialNumber (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Acurast TrustedRootCertificates (r:1 w:0)
+	/// Proof: Acurast TrustedRootCertificates (max_values: None, max_size: Some(3034), added: 5509, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredAttestationPolicy (r:1 w:0)
+	/// Proof: Acurast StoredAttestationPolicy (max_values: Some(1), max_size: Some(2746), added: 3241, mode: MaxEncodedLen)
 	/// Storage: Acurast StoredAttestation (r:0 w:1)
 	/// Proof: Acurast StoredAttestation (max_values: None, max_size: Some(11622), added: 14097, mode: MaxEncodedLen)
 	fn submit_attestation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `250`
-		//  Estimated: `12531`
-		// Minimum execution time: 9_665_000_000 picoseconds.
-		Weight::from_parts(9_709_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 12531))
-			.saturating_add(T::DbWeight::get().reads(5))
+		//  Estimated: `31929`
+		// Minimum execution time: 9_671_000_000 picoseconds.
+		Weight::from_parts(9_718_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 31929))
+			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Acurast RevocationStatusListEntries (r:1 w:0)
+	/// Proof: Acurast RevocationStatusListEntries (max_values: Some(1), max_size: Some(337), added: 832, mode: MaxEncodedLen)
 	/// Storage: Acurast StoredRevokedCertificate (r:0 w:1)
-	/// Proof: Acurast StoredRevokedCertificate (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Proof: Acurast StoredRevokedCertificate (max_values: None, max_size: Some(178), added: 2653, mode: MaxEncodedLen)
 	fn update_certificate_revocation_list() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1822`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1822))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredMatches (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
+	/// Storage: Acurast ExecutionEnvironment (r:1 w:1)
+	/// Proof: Acurast ExecutionEnvironment (max_values: None, max_size: Some(42655), added: 45130, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 10]`.
+	fn set_environment(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `552`
+		//  Estimated: `86599`
+		// Minimum execution time: 33_000_000 picoseconds.
+		Weight::from_parts(33_912_480, 0)
+			.saturating_add(Weight::from_parts(0, 86599))
+			// Standard Error: 4_870
+			.saturating_add(Weight::from_parts(512_338, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Acurast StoredJobRegistration (r:1 w:1)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredJobStatus (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn update_registration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `710`
+		//  Estimated: `38260`
+		// Minimum execution time: 45_000_000 picoseconds.
+		Weight::from_parts(46_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38260))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Acurast TrustedRootCertificates (r:1 w:1)
+	/// Proof: Acurast TrustedRootCertificates (max_values: None, max_size: Some(3034), added: 5509, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 10]`.
+	fn update_trusted_root_certificates(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 9_000_000 picoseconds.
+		// Minimum execution time: 2_430_000_000 picoseconds.
 		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 25_000_000
+			.saturating_add(Weight::from_parts(2_430_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 5509).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
+	/// Storage: Acurast StoredAttestationPolicy (r:0 w:1)
+	/// Proof: Acurast StoredAttestationPolicy (max_values: Some(1), max_size: Some(2746), added: 3241, mode: MaxEncodedLen)
+	fn set_attestation_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Acurast StoredAttestation (r:1 w:1)
+	/// Proof: Acurast StoredAttestation (max_values: None, max_size: Some(11622), added: 14097, mode: MaxEncodedLen)
+	/// Storage: Acurast AttestationExpiryNotified (r:1 w:1)
+	/// Proof: Acurast AttestationExpiryNotified (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	fn sweep_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2891`
+		//  Estimated: `16620`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 16620))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Acurast RevocationStatusListSequence (r:1 w:1)
+	/// Proof: Acurast RevocationStatusListSequence (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Acurast RevocationStatusListEntries (r:1 w:1)
+	/// Proof: Acurast RevocationStatusListEntries (max_values: Some(1), max_size: Some(337), added: 832, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredRevokedSerialNumber (r:16 w:32)
+	/// Proof: Acurast StoredRevokedSerialNumber (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 16]`.
+	/// The range of component `y` is `[0, 16]`.
+	fn submit_revocation_status_list(x: u32, y: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `116 + y * (42 ±0)`
+		//  Estimated: `2325 + x * (2523 ±0)`
+		// Minimum execution time: 98_000_000 picoseconds.
+		Weight::from_parts(92_413_000, 0)
+			.saturating_add(Weight::from_parts(0, 2325))
+			// Standard Error: 9_314
+			.saturating_add(Weight::from_parts(7_245_611, 0).saturating_mul(x.into()))
+			// Standard Error: 9_314
+			.saturating_add(Weight::from_parts(3_014_227, 0).saturating_mul(y.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(y.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(x.into()))
+	}
 	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
 	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
-	/// Storage: Acurast ExecutionEnvironment (r:0 w:1)
-	/// Proof: Acurast ExecutionEnvironment (max_values: None, max_size: Some(10743), added: 13218, mode: MaxEncodedLen)
-	/// The range of component `x` is `[1, 10]`.
-	fn set_environment(x: u32, ) -> Weight {
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredMatches (r:64 w:0)
+	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
+	/// Storage: Acurast ExecutionEnvironment (r:64 w:64)
+	/// Proof: Acurast ExecutionEnvironment (max_values: None, max_size: Some(42655), added: 45130, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 64]`.
+	fn set_environments(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `330 + x * (222 ±0)`
+		//  Estimated: `38763 + x * (47836 ±0)`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(18_103_552, 0)
+			.saturating_add(Weight::from_parts(0, 38763))
+			// Standard Error: 23_118
+			.saturating_add(Weight::from_parts(24_047_915, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 47836).saturating_mul(x.into()))
+	}
+	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: Acurast JobTransfers (r:0 w:1)
+	/// Proof: Acurast JobTransfers (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn transfer_job() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `330`
 		//  Estimated: `38260`
-		// Minimum execution time: 27_500_000 picoseconds.
-		Weight::from_parts(28_198_520, 0)
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 38260))
-			// Standard Error: 4_614
-			.saturating_add(Weight::from_parts(500_209, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Acurast JobTransfers (r:1 w:1)
+	/// Proof: Acurast JobTransfers (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	fn cancel_job_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `202`
+		//  Estimated: `3567`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3567))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Acurast JobTransfers (r:1 w:1)
+	/// Proof: Acurast JobTransfers (max_values: None, max_size: Some(102), added: 2577, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredJobRegistration (r:2 w:2)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredJobStatus (r:1 w:2)
+	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:2)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace AssignedProcessors (r:1 w:0)
+	/// Proof: AcurastMarketplace AssignedProcessors (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: Acurast ExecutionEnvironment (r:65 w:128)
+	/// Proof: Acurast ExecutionEnvironment (max_values: None, max_size: Some(42655), added: 45130, mode: MaxEncodedLen)
+	fn accept_job_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2754890`
+		//  Estimated: `2973046`
+		// Minimum execution time: 1_184_000_000 picoseconds.
+		Weight::from_parts(1_203_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2973046))
+			.saturating_add(T::DbWeight::get().reads(71))
+			.saturating_add(T::DbWeight::get().writes(135))
+	}
+	/// Storage: Acurast JobTemplateCount (r:1 w:1)
+	/// Proof: Acurast JobTemplateCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Acurast JobTemplateIdSequence (r:1 w:1)
+	/// Proof: Acurast JobTemplateIdSequence (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredJobTemplate (r:0 w:1)
+	/// Proof: Acurast StoredJobTemplate (max_values: None, max_size: Some(34806), added: 37281, mode: MaxEncodedLen)
+	fn create_job_template() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `5034`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5034))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Acurast StoredJobTemplate (r:1 w:1)
+	/// Proof: Acurast StoredJobTemplate (max_values: None, max_size: Some(34806), added: 37281, mode: MaxEncodedLen)
+	/// Storage: Acurast JobTemplateCount (r:1 w:1)
+	/// Proof: Acurast JobTemplateCount (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn remove_job_template() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `41788`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 41788))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Acurast StoredJobTemplate (r:1 w:0)
+	/// Proof: Acurast StoredJobTemplate (max_values: None, max_size: Some(34806), added: 37281, mode: MaxEncodedLen)
+	/// Storage: Acurast LocalJobIdSequence (r:1 w:1)
+	/// Proof: Acurast LocalJobIdSequence (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredJobStatus (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredAdvertisementRestriction (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredAdvertisementRestriction (max_values: None, max_size: Some(3830), added: 6305, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredAdvertisementPricing (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredAdvertisementPricing (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredStorageCapacity (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredStorageCapacity (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredMatches (r:2 w:1)
+	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredTotalAssignedV3 (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredTotalAssignedV3 (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace AssignedProcessors (r:0 w:1)
+	/// Proof: AcurastMarketplace AssignedProcessors (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredJobRegistration (r:0 w:1)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	fn register_from_template() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `972`
+		//  Estimated: `73089`
+		// Minimum execution time: 84_000_000 picoseconds.
+		Weight::from_parts(86_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 73089))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: Acurast AllowedAppIds (r:0 w:1)
+	/// Proof: Acurast AllowedAppIds (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 10]`.
+	fn update_allowed_app_ids(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 3_000_000
+			.saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
+	}
 }
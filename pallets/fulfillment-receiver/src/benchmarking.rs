@@ -15,7 +15,7 @@ benchmarks! {
         let caller: T::AccountId = bob_account_id().into();
         whitelist_account!(caller);
         let fulfillment = Fulfillment {
-            script: hex!("697066733A2F2F00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").to_vec().try_into().unwrap(),
+            script: hex!("697066733A2F2F516D4E4C656937387A576D7A556462655242334369556641697A5755726265655A68354B31726841514B43683531").to_vec().try_into().unwrap(),
             payload: hex!("00000000").to_vec(),
         };
     }: _(RawOrigin::Signed(caller), fulfillment)
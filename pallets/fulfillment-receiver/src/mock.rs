@@ -105,7 +105,7 @@ impl Default for ExtBuilder {
     }
 }
 
-pub const SCRIPT_BYTES: [u8; 53] = hex!("697066733A2F2F00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000");
+pub const SCRIPT_BYTES: [u8; 53] = hex!("697066733A2F2F516D4E4C656937387A576D7A556462655242334369556641697A5755726265655A68354B31726841514B43683531");
 
 pub fn script() -> Script {
     SCRIPT_BYTES.to_vec().try_into().unwrap()
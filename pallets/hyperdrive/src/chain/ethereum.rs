@@ -8,7 +8,7 @@ use frame_support::pallet_prelude::ConstU32;
 use frame_support::{BoundedVec, RuntimeDebug};
 use pallet_acurast::{
     AllowedSources, EthereumAddressBytes, JobModule, JobModules, JobRegistration, MultiOrigin,
-    ParameterBound, Schedule, Script,
+    ParameterBound, Schedule, ScheduleKind, Script,
 };
 use pallet_acurast_marketplace::{
     JobRequirements, PlannedExecution, PlannedExecutions, RegistrationExtra,
@@ -79,9 +79,11 @@ pub enum EthereumValidationError {
     CouldNotDecodeRegisterJobPayload,
     CouldNotDecodeDeregisterJobPayload,
     CouldNotDecodeFinalizeJobPayload,
+    CouldNotDecodeTriggerExecutionPayload,
     TooManyPlannedExecutions,
     TooManyAllowedSources,
     TooManyJobModules,
+    InvalidScript,
     InvalidRlpEncoding,
 }
 
@@ -236,8 +238,10 @@ where
                 let extra: Extra = RegistrationExtra {
                     requirements: JobRequirements {
                         slots: job_registration.requirements.slots.into(),
+                        min_slots: None,
                         reward: Balance::from(job_registration.requirements.reward),
                         min_reputation: Some(job_registration.requirements.minReputation),
+                        min_collateral: None,
                         instant_match: Some(executions),
                     },
                 }
@@ -265,7 +269,8 @@ where
                 )
                 .map_err(|_| EthereumValidationError::TooManyJobModules)?;
                 let registration = JobRegistration {
-                    script: Script::truncate_from(job_registration.script),
+                    script: Script::try_from(job_registration.script)
+                        .map_err(|_| EthereumValidationError::InvalidScript)?,
                     allowed_sources: Some(allowed_sources),
                     allow_only_verified_sources: job_registration.allowOnlyVerifiedSources,
                     schedule: Schedule {
@@ -274,6 +279,7 @@ where
                         end_time: job_registration.schedule.endTime,
                         interval: job_registration.schedule.interval,
                         max_start_delay: job_registration.schedule.maxStartDelay,
+                        kind: ScheduleKind::Interval,
                     },
                     memory: job_registration.memoryCapacity,
                     network_requests: job_registration.networkRequests,
@@ -299,6 +305,12 @@ where
 
                 Ok(ParsedAction::FinalizeJob(jobs))
             }
+            RawAction::TriggerExecution => {
+                let job_id = JobId::decode_single(&decoded.payload, true)
+                    .map_err(|_| EthereumValidationError::CouldNotDecodeTriggerExecutionPayload)?;
+
+                Ok(ParsedAction::TriggerExecution((origin, job_id)))
+            }
             RawAction::Noop => Ok(ParsedAction::Noop),
         }
     }
@@ -29,7 +29,7 @@ use tezos_michelson::{
 
 use pallet_acurast::{
     AllowedSources, JobIdSequence, JobModule, JobRegistration, MultiOrigin, ParameterBound,
-    Schedule, CU32,
+    Schedule, ScheduleKind, CU32,
 };
 use pallet_acurast_marketplace::{
     JobRequirements, PlannedExecution, PlannedExecutions, RegistrationExtra,
@@ -114,6 +114,15 @@ where
                         .collect(),
                 )
             }
+            RawAction::TriggerExecution => {
+                let payload: Vec<u8> = (&payload).into();
+                let job_id_sequence = parse_trigger_execution_payload(payload.as_slice())?;
+
+                ParsedAction::TriggerExecution((
+                    MultiOrigin::Tezos(bounded_address(&origin)?),
+                    job_id_sequence,
+                ))
+            }
             RawAction::Noop => ParsedAction::Noop,
         })
     }
@@ -493,7 +502,7 @@ where
             .into();
         script
             .try_into()
-            .map_err(|_| TezosValidationError::ScriptOutOfBounds)?
+            .map_err(|_| TezosValidationError::InvalidScript)?
     };
     let storage = {
         let v: Int = try_int(
@@ -506,8 +515,10 @@ where
     let extra: Extra = RegistrationExtra {
         requirements: JobRequirements {
             slots,
+            min_slots: None,
             reward,
             min_reputation,
+            min_collateral: None,
             instant_match,
         },
     }
@@ -524,6 +535,7 @@ where
                 end_time,
                 interval,
                 max_start_delay,
+                kind: ScheduleKind::Interval,
             },
             memory,
             network_requests,
@@ -544,6 +556,13 @@ fn parse_deregister_job_payload(encoded: &[u8]) -> Result<JobIdSequence, TezosVa
     Ok(v.to_integer()?)
 }
 
+/// Parses an encoded [`RawAction::TriggerExecution`] action's payload into [`JobIdSequence`].
+///
+/// The payload has the same structure as the one of a [`RawAction::DeregisterJob`] action.
+fn parse_trigger_execution_payload(encoded: &[u8]) -> Result<JobIdSequence, TezosValidationError> {
+    parse_deregister_job_payload(encoded)
+}
+
 /// Parses an encoded [`RawAction::FinalizeJob`] action's payload into [[`JobIdSequence`]].
 fn parse_finalize_job_payload(encoded: &[u8]) -> Result<Vec<JobIdSequence>, TezosValidationError> {
     let unpacked: Micheline = Micheline::unpack(encoded, Some(finalize_job_schema()))
@@ -583,7 +602,7 @@ pub enum TezosValidationError {
     ParsingFailure,
     InvalidMessage,
     InvalidAction,
-    ScriptOutOfBounds,
+    InvalidScript,
     InstantMatchPlannedExecutionsOutOfBounds,
     TezosAddressOutOfBounds,
     InvalidReward,
@@ -771,6 +790,15 @@ where
                         .collect(),
                 )
             }
+            RawAction::TriggerExecution => {
+                let payload: Vec<u8> = (&payload).into();
+                let job_id_sequence = parse_trigger_execution_payload(payload.as_slice())?;
+
+                ParsedAction::TriggerExecution((
+                    MultiOrigin::Tezos(bounded_address(&origin)?),
+                    job_id_sequence,
+                ))
+            }
             RawAction::Noop => ParsedAction::Noop,
         })
     }
@@ -840,6 +868,7 @@ mod tests {
                 end_time: 1678266546623,
                 interval: 31000,
                 max_start_delay: 0,
+                kind: ScheduleKind::Interval,
             },
             memory: 1,
             network_requests: 1,
@@ -848,8 +877,10 @@ mod tests {
             extra: RegistrationExtra {
                 requirements: JobRequirements {
                     slots: 1,
+                    min_slots: None,
                     reward: 1000,
                     min_reputation: None,
+                    min_collateral: None,
                     instant_match: Some(bounded_vec![PlannedExecution {
                         source: hex![
                             "1111111111111111111111111111111111111111111111111111111111111111"
@@ -921,6 +952,7 @@ mod tests {
                 end_time: 1687357200000,
                 interval: 300000,
                 max_start_delay: 10000,
+                kind: ScheduleKind::Interval,
             },
             memory: 100,
             network_requests: 1,
@@ -929,8 +961,10 @@ mod tests {
             extra: RegistrationExtra {
                 requirements: JobRequirements {
                     slots: 1,
+                    min_slots: None,
                     reward: 1000000000000,
                     min_reputation: Some(0),
+                    min_collateral: None,
                     instant_match: Some(bounded_vec![PlannedExecution {
                         source: hex![
                             "d80a8b0d800a3320528693947f7317871b2d51e5f3c8f3d0d4e4f7e6938ed68f"
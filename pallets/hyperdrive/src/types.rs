@@ -127,6 +127,8 @@ pub enum RawAction {
     DeregisterJob,
     #[strum(serialize = "FINALIZE_JOB")]
     FinalizeJob,
+    #[strum(serialize = "TRIGGER_EXECUTION")]
+    TriggerExecution,
     #[strum(serialize = "NOOP")]
     Noop = 255,
 }
@@ -140,6 +142,7 @@ impl TryFrom<u16> for RawAction {
             0 => Ok(RawAction::RegisterJob),
             1 => Ok(RawAction::DeregisterJob),
             2 => Ok(RawAction::FinalizeJob),
+            3 => Ok(RawAction::TriggerExecution),
             255 => Ok(RawAction::Noop),
             _ => Err(b"Unknown action index".to_vec()),
         }
@@ -154,6 +157,7 @@ impl<AccountId, MaxAllowedSources: Get<u32>, Extra>
             ParsedAction::RegisterJob(_, _) => RawAction::RegisterJob,
             ParsedAction::DeregisterJob(_) => RawAction::DeregisterJob,
             ParsedAction::FinalizeJob(_) => RawAction::FinalizeJob,
+            ParsedAction::TriggerExecution(_) => RawAction::TriggerExecution,
             ParsedAction::Noop => RawAction::Noop,
         }
     }
@@ -167,6 +171,7 @@ pub enum ParsedAction<AccountId, MaxAllowedSources: Get<u32>, Extra> {
     ),
     DeregisterJob(JobId<AccountId>),
     FinalizeJob(Vec<JobId<AccountId>>),
+    TriggerExecution(JobId<AccountId>),
     Noop,
 }
 
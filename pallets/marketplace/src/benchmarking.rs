@@ -1,5 +1,8 @@
-use frame_benchmarking::{benchmarks, whitelist_account};
-use frame_support::{assert_ok, traits::IsType};
+use frame_benchmarking::{benchmarks, whitelist_account, BenchmarkError};
+use frame_support::{
+    assert_ok,
+    traits::{EnsureOrigin, IsType},
+};
 use frame_system::RawOrigin;
 use sp_core::*;
 use sp_runtime::{
@@ -11,7 +14,7 @@ use sp_std::prelude::*;
 use crate::Config;
 use pallet_acurast::{
     JobId, JobIdSequence, JobModules, JobRegistrationFor, MultiOrigin, Pallet as Acurast, Schedule,
-    Script,
+    ScheduleKind, Script,
 };
 
 pub use crate::stub::*;
@@ -45,6 +48,7 @@ pub fn advertisement<T: Config>(
         max_memory: 100_000,
         network_request_quota: 100,
         available_modules: JobModules::default(),
+        collateral: 0u8.into(),
     }
 }
 
@@ -58,8 +62,10 @@ pub fn job_registration_with_reward<T: Config>(
     let reward: <T as Config>::Balance = reward_value.into();
     let r = JobRequirements {
         slots,
+        min_slots: None,
         reward,
         min_reputation: Some(0),
+        min_collateral: None,
         instant_match: instant_match_processor.map(|m| vec![m].try_into().unwrap()),
     };
     let r: <T as Config>::RegistrationExtra = <T as Config>::BenchmarkHelper::registration_extra(r);
@@ -74,6 +80,7 @@ pub fn job_registration_with_reward<T: Config>(
             end_time: 1689418800000,   // 31.12.2050 13:00 (one day later)
             interval: 180000,          // 30min
             max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
         },
         memory: 1_000u32,
         network_requests: 1,
@@ -209,6 +216,29 @@ where
     Ok((processor_id, job, job_id))
 }
 
+fn execution_report_helper<T: Config>(
+    verifier: Option<T::AccountId>,
+) -> Result<(T::AccountId, JobRegistrationFor<T>, JobId<T::AccountId>), DispatchError>
+where
+    T: pallet_balances::Config,
+{
+    let (processor, job, job_id) = acknowledge_match_submit_helper::<T>(None, None)?;
+    <StoredExecutionReports<T>>::insert(
+        &job_id,
+        (processor.clone(), 0u64),
+        ExecutionReport {
+            operation_hash: vec![0u8].try_into().unwrap(),
+            reported_at: job.schedule.start_time,
+            fee: 1_000_000u64.into(),
+            dispute: verifier.map(|verifier| Dispute {
+                verifier: Some(verifier),
+                disputed_at: job.schedule.start_time,
+            }),
+        },
+    );
+    Ok((processor, job, job_id))
+}
+
 benchmarks! {
     where_clause {  where
         T: pallet_acurast::Config + pallet_balances::Config + pallet_timestamp::Config<Moment = u64> + pallet_acurast_processor_manager::Config,
@@ -301,5 +331,152 @@ benchmarks! {
         pallet_timestamp::Pallet::<T>::set_timestamp((1689418800000u64 + 1).into());
     }: _(RawOrigin::Signed(consumer), job_ids.try_into().unwrap())
 
+    set_job_renewal {
+        let (caller, _, job_id) = register_submit_helper::<T>(0, 1);
+        let deposit: T::Balance = 1_000_000u64.into();
+    }: _(RawOrigin::Signed(caller.clone()), job_id, RenewalPolicy::Times(10), deposit)
+    verify {
+        assert_last_event::<T>(Event::JobRenewalSet(
+            (MultiOrigin::Acurast(caller), job_id),
+            JobRenewal {
+                policy: RenewalPolicy::Times(10),
+                deposit,
+                previous_sources: Default::default(),
+                slashed_collateral: 0u64.into(),
+            },
+        ).into());
+    }
+
+    trigger_execution {
+        let consumer: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(0, u32::MAX.into());
+        let processor: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(1, u32::MAX.into());
+        assert_ok!(AcurastMarketplace::<T>::advertise(
+            RawOrigin::Signed(processor.clone()).into(),
+            advertisement::<T>(1, 1_000_000),
+        ));
+        let mut job = job_registration_with_reward::<T>(
+            script(),
+            1,
+            100,
+            1_000_000,
+            Some(PlannedExecution {
+                source: processor.clone(),
+                start_delay: 0,
+            }),
+        );
+        job.schedule.kind = ScheduleKind::OnDemand;
+        assert_ok!(Acurast::<T>::register(RawOrigin::Signed(consumer.clone()).into(), job.clone()));
+        let job_id = Acurast::<T>::job_id_sequence();
+        let pub_keys: PubKeys = vec![PubKey::SECP256r1([0u8; 33].to_vec().try_into().unwrap())].try_into().unwrap();
+        assert_ok!(AcurastMarketplace::<T>::acknowledge_match(
+            RawOrigin::Signed(processor).into(),
+            (MultiOrigin::Acurast(consumer.clone()), job_id),
+            pub_keys,
+        ));
+        pallet_timestamp::Pallet::<T>::set_timestamp(job.schedule.start_time.into());
+    }: _(RawOrigin::Signed(consumer.clone()), job_id)
+    verify {
+        assert_last_event::<T>(Event::ExecutionTriggered(
+            (MultiOrigin::Acurast(consumer), job_id),
+            job.schedule.start_time,
+        ).into());
+    }
+
+    set_job_upstream {
+        let (caller, _, upstream_id) = register_submit_helper::<T>(0, 1);
+        let (_, mut job) = register_helper::<T>(0, 1);
+        job.schedule.kind = ScheduleKind::OnDemand;
+        assert_ok!(Acurast::<T>::register(RawOrigin::Signed(caller.clone()).into(), job));
+        let job_id = Acurast::<T>::job_id_sequence();
+        let upstream = Some((MultiOrigin::Acurast(caller.clone()), upstream_id));
+    }: _(RawOrigin::Signed(caller.clone()), job_id, upstream.clone())
+    verify {
+        assert_last_event::<T>(Event::JobUpstreamSet(
+            (MultiOrigin::Acurast(caller), job_id),
+            upstream,
+        ).into());
+    }
+
+    propose_replacement {
+        let (processor, job, job_id) = acknowledge_match_helper::<T>(None, None)?;
+        let (replacement, _) = advertise_helper::<T>(2, true);
+        let caller: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(3, u32::MAX.into());
+        whitelist_account!(caller);
+        pallet_timestamp::Pallet::<T>::set_timestamp((job.schedule.start_time - 1).into());
+        let planned_execution = PlannedExecution {
+            source: replacement.clone(),
+            start_delay: 0,
+        };
+    }: _(RawOrigin::Signed(caller), job_id.clone(), processor.clone(), planned_execution)
+    verify {
+        assert!(AcurastMarketplace::<T>::stored_matches(&processor, &job_id).is_none());
+        assert!(AcurastMarketplace::<T>::stored_matches(&replacement, &job_id).is_some());
+    }
+
+    dispute_execution {
+        let (processor, job, job_id) = execution_report_helper::<T>(None)?;
+        let (verifier, _) = advertise_helper::<T>(2, true);
+        <AssignedProcessors<T>>::insert(&job_id, &verifier, ());
+        // stay within the dispute window independent of its configured length
+        pallet_timestamp::Pallet::<T>::set_timestamp((job.schedule.start_time - 1).into());
+        let consumer = match &job_id.0 {
+            MultiOrigin::Acurast(consumer) => consumer.clone(),
+            _ => unreachable!(),
+        };
+    }: _(RawOrigin::Signed(consumer), job_id.1, processor.clone(), 0, vec![0u8].try_into().unwrap(), Some(verifier))
+    verify {
+        assert_last_event::<T>(Event::ExecutionDisputed(job_id, processor, 0).into());
+    }
+
+    resolve_dispute {
+        let (processor, _, job_id) = execution_report_helper::<T>(Some(<T as Config>::BenchmarkHelper::funded_account(2, u32::MAX.into())))?;
+        let origin = T::DisputeResolutionOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, job_id.clone(), processor.clone(), 0, false)
+    verify {
+        assert_last_event::<T>(Event::DisputeResolved(job_id, processor, 0, false).into());
+    }
+
+    report_re_execution {
+        let verifier: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(2, u32::MAX.into());
+        let (processor, _, job_id) = execution_report_helper::<T>(Some(verifier.clone()))?;
+    }: _(RawOrigin::Signed(verifier), job_id.clone(), processor.clone(), 0, vec![1u8].try_into().unwrap())
+    verify {
+        assert_last_event::<T>(Event::DisputeResolved(job_id, processor, 0, false).into());
+    }
+
+    settle_execution_reports {
+        let (processor, job, job_id) = acknowledge_match_submit_helper::<T>(None, None)?;
+        let manager: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(2, u32::MAX.into());
+        let (manager_id, _) = pallet_acurast_processor_manager::Pallet::<T>::do_get_or_create_manager_id(&manager)?;
+        pallet_acurast_processor_manager::Pallet::<T>::do_add_processor_manager_pairing(&processor, manager_id)?;
+        let caller: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(3, u32::MAX.into());
+        whitelist_account!(caller);
+        // worst case: the maximum number of reports settled at once, all with a timed out dispute
+        let fee = <StoredMatches<T>>::mutate(&processor, &job_id, |a| {
+            let a = a.as_mut().unwrap();
+            a.sla.met = MAX_SETTLED_EXECUTION_REPORTS;
+            a.fee_per_execution
+        });
+        for i in 0..MAX_SETTLED_EXECUTION_REPORTS {
+            <StoredExecutionReports<T>>::insert(
+                &job_id,
+                (processor.clone(), i),
+                ExecutionReport {
+                    operation_hash: vec![0u8].try_into().unwrap(),
+                    reported_at: job.schedule.start_time,
+                    fee,
+                    dispute: Some(Dispute {
+                        verifier: None,
+                        disputed_at: job.schedule.start_time,
+                    }),
+                },
+            );
+        }
+        pallet_timestamp::Pallet::<T>::set_timestamp((job.schedule.start_time + T::DisputeWindow::get() + T::DisputeTimeout::get()).into());
+    }: _(RawOrigin::Signed(caller), job_id.clone(), processor.clone())
+    verify {
+        assert_eq!(AcurastMarketplace::<T>::settled_execution_reports(&job_id, &processor), MAX_SETTLED_EXECUTION_REPORTS);
+    }
+
     impl_benchmark_test_suite!(AcurastMarketplace, mock::ExtBuilder::default().build(), mock::Test);
 }
@@ -0,0 +1,53 @@
+//! The keys used by the matcher offchain worker to sign [crate::Call::propose_matching] transactions.
+
+use core::marker::PhantomData;
+
+use frame_support::BoundedVec;
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+    app_crypto::{app_crypto, sr25519},
+    MultiSignature, MultiSigner,
+};
+
+use crate::{Call, Config, MatchFor, MatchSubmitter};
+
+/// The key type of matcher keys in a node's keystore.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"amat");
+
+app_crypto!(sr25519, KEY_TYPE);
+
+/// The matcher key, to be used with [SignedMatchSubmitter].
+pub struct MatcherAuthId;
+
+impl AppCrypto<MultiSigner, MultiSignature> for MatcherAuthId {
+    type RuntimeAppPublic = Public;
+    type GenericSignature = sp_core::sr25519::Signature;
+    type GenericPublic = sp_core::sr25519::Public;
+}
+
+/// Submits matches in [crate::Call::propose_matching] transactions signed with an `AuthorityId` key from the node's keystore.
+///
+/// Requires the runtime to implement [CreateSignedTransaction] for the pallet's calls.
+pub struct SignedMatchSubmitter<T, AuthorityId>(PhantomData<(T, AuthorityId)>);
+
+impl<T, AuthorityId> MatchSubmitter<T> for SignedMatchSubmitter<T, AuthorityId>
+where
+    T: Config + CreateSignedTransaction<Call<T>>,
+    AuthorityId: AppCrypto<T::Public, T::Signature>,
+{
+    fn can_submit() -> bool {
+        Signer::<T, AuthorityId>::any_account().can_sign()
+    }
+
+    fn submit(matches: BoundedVec<MatchFor<T>, T::MaxProposedMatches>) -> Result<(), ()> {
+        match Signer::<T, AuthorityId>::any_account().send_signed_transaction(|_| {
+            Call::propose_matching {
+                matches: matches.clone(),
+            }
+        }) {
+            Some((_, result)) => result,
+            None => Err(()),
+        }
+    }
+}
@@ -3,8 +3,9 @@ use reputation::BetaParameters;
 use sp_core::Get;
 
 use crate::{
-    AdvertisementFor, AdvertisementRestriction, Config, Error, Pallet, StoredAdvertisementPricing,
-    StoredAdvertisementRestriction, StoredReputation, StoredStorageCapacity,
+    AdvertisementFor, AdvertisementRestriction, Config, Error, Pallet, RewardManager,
+    StoredAdvertisementPricing, StoredAdvertisementRestriction, StoredCollateral, StoredReputation,
+    StoredStorageCapacity,
 };
 
 impl<T: Config> Pallet<T> {
@@ -24,6 +25,20 @@ impl<T: Config> Pallet<T> {
             );
         }
 
+        // bond the advertised collateral by locking or unlocking the difference to the currently bonded collateral
+        let collateral = <StoredCollateral<T>>::get(processor);
+        if advertisement.collateral > collateral {
+            T::RewardManager::lock_collateral(processor, advertisement.collateral - collateral)?;
+        } else if advertisement.collateral < collateral {
+            // prohibit reducing the collateral as long as jobs are matched
+            ensure!(
+                !Self::has_matches(processor),
+                Error::<T>::CannotReduceCollateralWhileMatched
+            );
+            T::RewardManager::unlock_collateral(processor, collateral - advertisement.collateral)?;
+        }
+        <StoredCollateral<T>>::insert(processor, advertisement.collateral);
+
         // update capacity to save on operations when checking available capacity
         if let Some(old) = <StoredAdvertisementRestriction<T>>::get(processor) {
             // allow capacity to become negative (in which case source remains assigned but does not receive new jobs assigned)
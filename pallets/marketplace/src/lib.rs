@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub use functions::*;
+pub use migration::MigrateJobRequirements;
 pub use pallet::*;
 pub use payments::*;
 pub use traits::*;
@@ -16,6 +17,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod crypto;
 mod functions;
 mod migration;
 pub mod payments;
@@ -39,22 +41,32 @@ pub use benchmarking::BenchmarkHelper;
 pub mod pallet {
     use frame_support::traits::tokens::Balance;
     use frame_support::{
-        dispatch::DispatchResultWithPostInfo, ensure, pallet_prelude::*, traits::UnixTime,
+        dispatch::{DispatchResultWithPostInfo, PostDispatchInfo},
+        ensure,
+        pallet_prelude::*,
+        storage::{with_storage_layer, with_transaction},
+        traits::UnixTime,
         Blake2_128, Blake2_128Concat, PalletId,
     };
     use frame_system::pallet_prelude::*;
     use itertools::Itertools;
     use reputation::{BetaParameters, BetaReputation, ReputationEngine};
-    use sp_runtime::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
-    use sp_runtime::{FixedPointOperand, FixedU128, Permill, SaturatedConversion};
+    use sp_runtime::offchain::storage::StorageValueRef;
+    use sp_runtime::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating};
+    use sp_runtime::{
+        DispatchResultWithInfo, FixedPointOperand, FixedU128, Percent, Permill,
+        SaturatedConversion, TransactionOutcome,
+    };
     use sp_std::iter::once;
     use sp_std::prelude::*;
 
-    use pallet_acurast::utils::ensure_source_verified;
+    use pallet_acurast::utils::{ensure_source_verified, source_revoked_at};
     use pallet_acurast::{
-        AllowedSourcesUpdate, JobHooks, JobId, JobIdSequence, JobRegistrationFor, MultiOrigin,
-        ParameterBound, Schedule, StoredJobRegistration,
+        AllowedSourcesUpdate, EnvironmentFor, ExecutionEnvironment, JobHooks, JobId, JobIdSequence,
+        JobModules, JobRegistrationFor, MultiOrigin, ParameterBound, Schedule, Script,
+        StoredJobRegistration,
     };
+    use pallet_acurast_fulfillment_receiver::{traits::OnFulfillment, Fulfillment};
 
     use crate::traits::*;
     use crate::types::*;
@@ -90,6 +102,33 @@ pub mod pallet {
         /// would be considered outide of the agreed schedule despite being within schedule.
         #[pallet::constant]
         type ReportTolerance: Get<u64>;
+        /// The time in milliseconds before a job's `schedule.start_time` until which matched processors have to acknowledge the job.
+        ///
+        /// Afterwards, processors that did not acknowledge can be replaced with [Call::propose_replacement] until the job starts.
+        #[pallet::constant]
+        type AcknowledgeDeadline: Get<u64>;
+        /// The share of a job's [JobRequirements::min_collateral] slashed from a source's collateral per execution it missed.
+        ///
+        /// The slash for a slot is capped at the job's `min_collateral`.
+        #[pallet::constant]
+        type CollateralSlash: Get<Percent>;
+        /// The share of slashed collateral transferred to the treasury. The rest is refunded to the job's creator.
+        #[pallet::constant]
+        type CollateralSlashTreasuryShare: Get<Percent>;
+        /// The ID of the treasury receiving its share of slashed collateral.
+        #[pallet::constant]
+        type TreasuryPalletId: Get<PalletId>;
+        /// The time in milliseconds after a successful execution was reported during which the job's creator can dispute it
+        /// with [Call::dispute_execution].
+        ///
+        /// The fee of a reported execution is escrowed until its dispute window passed.
+        #[pallet::constant]
+        type DisputeWindow: Get<u64>;
+        /// The time in milliseconds after which a dispute not yet resolved is upheld by default, paying the escrowed fee to the source.
+        #[pallet::constant]
+        type DisputeTimeout: Get<u64>;
+        /// The origin allowed to resolve disputed executions with [Call::resolve_dispute].
+        type DisputeResolutionOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
         type Balance: Parameter + From<u64> + IsType<u128> + Balance + FixedPointOperand;
         type ManagerProvider: ManagerProvider<Self>;
         type ProcessorLastSeenProvider: ProcessorLastSeenProvider<Self>;
@@ -97,13 +136,16 @@ pub mod pallet {
         type RewardManager: RewardManager<Self>;
         /// Hook to act on marketplace related state transitions.
         type MarketplaceHooks: MarketplaceHooks<Self>;
+        /// Submits the matches found by the matcher offchain worker, e.g. [crate::crypto::SignedMatchSubmitter]. Set to `()`
+        /// to disable the matcher.
+        type MatchSubmitter: MatchSubmitter<Self>;
         type WeightInfo: WeightInfo;
 
         #[cfg(feature = "runtime-benchmarks")]
         type BenchmarkHelper: crate::benchmarking::BenchmarkHelper<Self>;
     }
 
-    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -192,6 +234,104 @@ pub mod pallet {
     pub type JobBudgets<T: Config> =
         StorageMap<_, Blake2_128, JobId<T::AccountId>, T::Balance, ValueQuery>;
 
+    /// Renewals of recurring jobs as a map [`JobId`] -> [`JobRenewalFor<T>`].
+    #[pallet::storage]
+    #[pallet::getter(fn job_renewal)]
+    pub type JobRenewals<T: Config> =
+        StorageMap<_, Blake2_128Concat, JobId<T::AccountId>, JobRenewalFor<T>>;
+
+    /// Pipeline stages as a map [`JobId`] `(downstream)` -> [`JobId`] `(upstream)`.
+    #[pallet::storage]
+    #[pallet::getter(fn job_upstream)]
+    pub type JobUpstreams<T: Config> =
+        StorageMap<_, Blake2_128Concat, JobId<T::AccountId>, JobId<T::AccountId>>;
+
+    /// Pipeline stages as a map [`JobId`] `(upstream)` -> [`JobId`] `(downstream)` -> `()`.
+    ///
+    /// This map serves as a reverse index into `JobUpstreams`.
+    #[pallet::storage]
+    #[pallet::getter(fn job_downstreams)]
+    pub type JobDownstreams<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        JobId<T::AccountId>,
+        Blake2_128Concat,
+        JobId<T::AccountId>,
+        (),
+    >;
+
+    /// Number of fulfillments that triggered downstream jobs as a map [`JobId`] `(upstream)` -> [`AccountId`] `(source)` -> `u64`.
+    #[pallet::storage]
+    #[pallet::getter(fn upstream_fulfillments)]
+    pub type UpstreamFulfillments<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        JobId<T::AccountId>,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery,
+    >;
+
+    /// Collateral bonded by sources as a map [`AccountId`] `(source)` -> [`T::Balance`], reserved from the source's balance.
+    #[pallet::storage]
+    #[pallet::getter(fn stored_collateral)]
+    pub type StoredCollateral<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+    /// Collateral committed to assignments as a map [`AccountId`] `(source)` -> [`T::Balance`], the sum of the
+    /// [JobRequirements::min_collateral] of the jobs the source is matched with. Only collateral not yet committed counts
+    /// towards the `min_collateral` of further jobs.
+    #[pallet::storage]
+    #[pallet::getter(fn committed_collateral)]
+    pub type CommittedCollateral<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance, ValueQuery>;
+
+    /// Successful execution reports with their escrowed fee as a map [`JobId`] -> ([`AccountId`] `(source)`, `u64` `(execution index)`) -> [`ExecutionReportFor<T>`].
+    ///
+    /// The execution index is the 0-based index of the source's report for the job. Reports are removed once their fee is
+    /// paid out after the dispute window passed or once a dispute is resolved.
+    #[pallet::storage]
+    #[pallet::getter(fn stored_execution_report)]
+    pub type StoredExecutionReports<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        JobId<T::AccountId>,
+        Blake2_128Concat,
+        (T::AccountId, u64),
+        ExecutionReportFor<T>,
+    >;
+
+    /// The settlement progress of execution reports as a map [`JobId`] -> [`AccountId`] `(source)` -> `u64`, the index of
+    /// the source's next execution report to settle. All reports with a lower index are settled.
+    #[pallet::storage]
+    #[pallet::getter(fn settled_execution_reports)]
+    pub type SettledExecutionReports<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        JobId<T::AccountId>,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery,
+    >;
+
+    /// The number of rejected executions per source as a map [`JobId`] -> [`AccountId`] `(source)` -> `u64`.
+    ///
+    /// Rejected executions were reported and therefore counted in the source's [`SLA::met`], but are not accounted as met
+    /// when the source's reputation gets updated on finalization.
+    #[pallet::storage]
+    #[pallet::getter(fn rejected_executions)]
+    pub type RejectedExecutions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        JobId<T::AccountId>,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        ValueQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub (super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -211,6 +351,24 @@ pub mod pallet {
         ExecutionFailure(JobId<T::AccountId>, ExecutionFailureMessage),
         /// This event is emitted when a job is finalized.
         JobFinalized(JobId<T::AccountId>),
+        /// The renewal of a job was set. [JobId, JobRenewal]
+        JobRenewalSet(JobId<T::AccountId>, JobRenewalFor<T>),
+        /// A recurring job was re-opened with the next window of its schedule. [JobId, Schedule]
+        JobRenewed(JobId<T::AccountId>, Schedule),
+        /// An execution of an on-demand job was triggered, each assigned processor is expected to report it. [JobId, timestamp]
+        ExecutionTriggered(JobId<T::AccountId>, u64),
+        /// The upstream job of a pipeline stage was set or removed. [JobId, Option<JobId>]
+        JobUpstreamSet(JobId<T::AccountId>, Option<JobId<T::AccountId>>),
+        /// A registration was matched with some of its slots without reaching [JobRequirements::min_slots] yet. [Match]
+        JobRegistrationPartiallyMatched(MatchFor<T>),
+        /// A processor that did not acknowledge a job in time was replaced. [JobId, replaced, replacement]
+        ProcessorReplaced(JobId<T::AccountId>, T::AccountId, T::AccountId),
+        /// Collateral of a source was slashed for executions it missed. [JobId, SourceId, amount]
+        CollateralSlashed(JobId<T::AccountId>, T::AccountId, T::Balance),
+        /// A reported execution was disputed by the job's creator. [JobId, SourceId, execution_index]
+        ExecutionDisputed(JobId<T::AccountId>, T::AccountId, u64),
+        /// A disputed execution was resolved, either upheld or rejected. [JobId, SourceId, execution_index, upheld]
+        DisputeResolved(JobId<T::AccountId>, T::AccountId, u64, bool),
     }
 
     #[pallet::error]
@@ -225,7 +383,7 @@ pub mod pallet {
         JobRegistrationScheduleExceedsMaximumExecutions,
         /// The job registration must specify a schedule that contains at least one execution.
         JobRegistrationScheduleContainsZeroExecutions,
-        /// The job registration's must specify `duration` < `interval`.
+        /// The job registration's must specify `duration` < `interval` (the minimum interval between executions for schedules not of kind [pallet_acurast::ScheduleKind::Interval]).
         JobRegistrationDurationExceedsInterval,
         /// The job registration's must specify `start` in the future.
         JobRegistrationStartInPast,
@@ -303,6 +461,54 @@ pub mod pallet {
         JobNotAssigned,
         /// The job cannot be finalized yet.
         JobCannotBeFinalized,
+        /// The environment is not encrypted for a key the source revealed when acknowledging the job.
+        EnvironmentKeyNotAcknowledged,
+        /// The job registration's schedule kind is malformed, i.e. contains timestamps not strictly increasing or outside of `[start_time, end_time)` or an invalid cron pattern.
+        JobRegistrationInvalidScheduleKind,
+        /// Only jobs with a [pallet_acurast::ScheduleKind::OnDemand] schedule can be triggered.
+        JobNotOnDemand,
+        /// An execution can only be triggered for a job with at least one processor assigned to a slot.
+        CannotTriggerWhenNotMatched,
+        /// An execution can only be triggered within `[start_time, end_time)` of the job's schedule.
+        TriggerOutsideSchedule,
+        /// The upstream job of a pipeline stage is not registered.
+        UpstreamJobNotFound,
+        /// Setting the upstream job would create a cycle in the pipeline.
+        PipelineCycle,
+        /// The pipeline would exceed [MAX_PIPELINE_DEPTH] stages upstream of a job.
+        PipelineTooDeep,
+        /// The upstream job already triggers [MAX_DOWNSTREAM_JOBS] downstream jobs.
+        TooManyDownstreamJobs,
+        /// The job registration's must specify `min_slots` in `[1, slots]` if provided.
+        JobRegistrationInvalidMinSlots,
+        /// Processors can only be replaced for a job with status [JobStatus::Matched] or [JobStatus::Assigned].
+        CannotReplaceWhenNotMatched,
+        /// Processors can only be replaced once [Config::AcknowledgeDeadline] before the job's start passed.
+        AcknowledgeDeadlineNotPassed,
+        /// A processor that acknowledged the job cannot be replaced.
+        CannotReplaceAcknowledgedProcessor,
+        /// The collateral of an advertisement cannot be reduced while matched to at least one job.
+        CannotReduceCollateralWhileMatched,
+        /// Match is invalid due to insufficient collateral bonded by a proposed source.
+        InsufficientCollateralInMatch,
+        /// No report with an escrowed fee found for the execution.
+        ExecutionReportNotFound,
+        /// The operation hash does not match the reported one.
+        OperationHashMismatch,
+        /// The reported execution can no longer be disputed since its dispute window passed.
+        DisputeWindowPassed,
+        /// The reported execution is already disputed.
+        ExecutionAlreadyDisputed,
+        /// The reported execution is not disputed.
+        ExecutionNotDisputed,
+        /// The verifier of a dispute has to be another source assigned to the job.
+        InvalidDisputeVerifier,
+        /// Only the verifier of a dispute can report the re-execution of the disputed execution.
+        NotDisputeVerifier,
+        /// The job cannot be finalized while reported executions can still be disputed or are disputed.
+        ExecutionReportsPending,
+        /// A job with assigned processors cannot be transferred.
+        CannotTransferAssignedJob,
         /// Nested Acurast error.
         PalletAcurast(pallet_acurast::Error<T>),
     }
@@ -331,12 +537,13 @@ pub mod pallet {
                 Error::InsufficientReputationInMatch => true,
                 Error::ScheduleOverlapInMatch => true,
                 Error::ModuleNotAvailableInMatch => true,
+                Error::InsufficientCollateralInMatch => true,
                 Error::PalletAcurast(e) => match *e {
                     pallet_acurast::Error::FulfillSourceNotAllowed => true,
                     pallet_acurast::Error::FulfillSourceNotVerified => true,
                     pallet_acurast::Error::AttestationCertificateNotValid => true,
                     pallet_acurast::Error::AttestationUsageExpired => true,
-                    pallet_acurast::Error::RevokedCertificate => true,
+                    pallet_acurast::Error::RevokedCertificate(_) => true,
                     _ => false,
                 },
                 Error::CapacityNotFound => true,
@@ -369,6 +576,28 @@ pub mod pallet {
                 Error::ReputationNotFound => false,
                 Error::JobNotAssigned => false,
                 Error::JobCannotBeFinalized => false,
+                Error::EnvironmentKeyNotAcknowledged => false,
+                Error::JobRegistrationInvalidScheduleKind => false,
+                Error::JobNotOnDemand => false,
+                Error::CannotTriggerWhenNotMatched => false,
+                Error::TriggerOutsideSchedule => false,
+                Error::UpstreamJobNotFound => false,
+                Error::PipelineCycle => false,
+                Error::PipelineTooDeep => false,
+                Error::TooManyDownstreamJobs => false,
+                Error::JobRegistrationInvalidMinSlots => false,
+                Error::CannotReplaceWhenNotMatched => false,
+                Error::AcknowledgeDeadlineNotPassed => false,
+                Error::CannotReplaceAcknowledgedProcessor => false,
+                Error::CannotReduceCollateralWhileMatched => false,
+                Error::ExecutionReportNotFound => false,
+                Error::OperationHashMismatch => false,
+                Error::DisputeWindowPassed => false,
+                Error::ExecutionAlreadyDisputed => false,
+                Error::ExecutionNotDisputed => false,
+                Error::InvalidDisputeVerifier => false,
+                Error::NotDisputeVerifier => false,
+                Error::ExecutionReportsPending => false,
 
                 Error::__Ignore(_, _) => false,
             }
@@ -380,6 +609,11 @@ pub mod pallet {
         fn on_runtime_upgrade() -> frame_support::weights::Weight {
             crate::migration::migrate::<T>()
         }
+
+        /// Runs the matcher if configured for this node, see [MatcherConfig].
+        fn offchain_worker(block_number: BlockNumberFor<T>) {
+            Self::run_matcher(block_number);
+        }
     }
 
     #[pallet::call]
@@ -422,6 +656,9 @@ pub mod pallet {
             <StoredStorageCapacity<T>>::remove(&who);
             <StoredAdvertisementRestriction<T>>::remove(&who);
 
+            // release the bonded collateral
+            T::RewardManager::unlock_collateral(&who, <StoredCollateral<T>>::take(&who))?;
+
             Self::deposit_event(Event::AdvertisementRemoved(who));
             Ok(().into())
         }
@@ -475,7 +712,9 @@ pub mod pallet {
                     |s| -> Result<(), Error<T>> {
                         let status = s.ok_or(Error::<T>::JobStatusNotFound)?;
                         *s = Some(match status {
-                            JobStatus::Open => Err(Error::<T>::CannotAcknowledgeWhenNotMatched)?,
+                            JobStatus::Open | JobStatus::PartiallyMatched { .. } => {
+                                Err(Error::<T>::CannotAcknowledgeWhenNotMatched)?
+                            }
                             JobStatus::Matched => JobStatus::Assigned(1),
                             JobStatus::Assigned(count) => JobStatus::Assigned(count + 1),
                         });
@@ -562,31 +801,37 @@ pub mod pallet {
 
             // pay only after all other steps succeeded without errors because paying reward is not revertable
 
-            match T::ManagerProvider::manager_of(&who) {
-                Ok(manager) => {
-                    T::RewardManager::pay_reward(
+            match execution_result {
+                ExecutionResult::Success(operation_hash) => {
+                    // escrow the fee until the execution can no longer be disputed
+                    <StoredExecutionReports<T>>::insert(
                         &job_id,
-                        assignment.fee_per_execution.clone(),
-                        &manager,
-                    )?;
+                        (who.clone(), assignment.sla.met - 1),
+                        ExecutionReport {
+                            operation_hash: operation_hash.clone(),
+                            reported_at: now,
+                            fee: assignment.fee_per_execution,
+                            dispute: None,
+                        },
+                    );
+                    Self::process_settlement(&job_id, &who, assignment.sla.met, now)?;
 
-                    match execution_result {
-                        ExecutionResult::Success(operation_hash) => Self::deposit_event(
-                            Event::ExecutionSuccess(job_id.clone(), operation_hash),
-                        ),
-                        ExecutionResult::Failure(message) => {
-                            Self::deposit_event(Event::ExecutionFailure(job_id.clone(), message))
-                        }
-                    }
+                    Self::deposit_event(Event::ExecutionSuccess(job_id.clone(), operation_hash));
+                }
+                ExecutionResult::Failure(message) => {
+                    Self::pay_execution_fee(&job_id, &who, assignment.fee_per_execution)?;
 
-                    Self::deposit_event(Event::Reported(job_id, who, assignment.clone()));
-                    Ok(().into())
+                    Self::deposit_event(Event::ExecutionFailure(job_id.clone(), message));
                 }
-                Err(err_result) => Err(err_result.into()),
             }
+
+            Self::deposit_event(Event::Reported(job_id, who, assignment.clone()));
+            Ok(().into())
         }
 
         /// Called by processors when the assigned job can be finalized.
+        ///
+        /// Processors of a job that stayed [`JobStatus::PartiallyMatched`] could not acknowledge it and are not penalized.
         #[pallet::call_index(5)]
         #[pallet::weight(<T as Config>::WeightInfo::finalize_job())]
         pub fn finalize_job(
@@ -606,14 +851,39 @@ pub mod pallet {
                 Self::actual_schedule_ended(&registration.schedule, &assignment)?,
                 Error::<T>::JobCannotBeFinalized
             );
+            ensure!(
+                Self::process_settlement(&job_id, &who, assignment.sla.met, Self::now()?)?,
+                Error::<T>::ExecutionReportsPending
+            );
+            <SettledExecutionReports<T>>::remove(&job_id, &who);
+            // rejected executions were already penalized as unmet on dispute resolution
+            let rejected = <RejectedExecutions<T>>::take(&job_id, &who);
 
             let unmet: u64 = assignment.sla.total - assignment.sla.met;
+            let under_filled = matches!(
+                <StoredJobStatus<T>>::get(&job_id.0, &job_id.1),
+                Some(JobStatus::PartiallyMatched { .. })
+            );
 
             // update reputation since we don't expect further reports for this job
-            // (only update for attested devices!)
-            if ensure_source_verified::<T>(&who).is_ok() {
-                // skip reputation update if reward is 0
-                if assignment.fee_per_execution > 0u8.into() {
+            // (only update for attested devices, ignoring revocations after the work was performed!)
+            if let Some(revoked_at) = source_revoked_at::<T>(&who).ok().filter(|_| !under_filled) {
+                let (met, unmet) = match revoked_at {
+                    None => (assignment.sla.met.saturating_sub(rejected), unmet),
+                    // only account for the executions started before the revocation
+                    Some(revoked_at) => {
+                        let performed = registration
+                            .schedule
+                            .iter(assignment.start_delay)
+                            .ok_or(Error::<T>::CalculationOverflow)?
+                            .take_while(|start| *start < revoked_at)
+                            .count() as u64;
+                        let met = assignment.sla.met.min(performed);
+                        (met.saturating_sub(rejected), performed - met)
+                    }
+                };
+                // skip reputation update if reward is 0 or no execution is accounted for
+                if assignment.fee_per_execution > 0u8.into() && met + unmet > 0 {
                     let average_reward = <StoredAverageRewardV3<T>>::get().unwrap_or(0);
                     let total_assigned = <StoredTotalAssignedV3<T>>::get().unwrap_or_default();
 
@@ -630,7 +900,7 @@ pub mod pallet {
 
                     beta_params = BetaReputation::update(
                         beta_params,
-                        assignment.sla.met,
+                        met,
                         unmet,
                         assignment.fee_per_execution,
                         average_reward.into(),
@@ -652,16 +922,43 @@ pub mod pallet {
                 }
             }
 
+            // slash collateral for missed executions (independent of attestation) if the source committed to the job
+            let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+            let requirements: JobRequirementsFor<T> = e.into();
+            if assignment.acknowledged && !under_filled {
+                Self::slash_collateral(&job_id, &who, &requirements, unmet)?;
+            }
+
             // only remove storage point indexed by a single processor (corresponding to the completed duties for the assigned slot)
             <StoredMatches<T>>::remove(&who, &job_id);
+            Self::release_collateral(&who, &requirements);
             <AssignedProcessors<T>>::remove(&job_id, &who);
+            <UpstreamFulfillments<T>>::remove(&job_id, &who);
 
             // increase capacity
             <StoredStorageCapacity<T>>::mutate(&who, |c| {
                 *c = c.unwrap_or(0).checked_add(registration.storage.into())
             });
 
-            Self::deposit_event(Event::JobFinalized(job_id));
+            // remember the processor to prefer it when matching the next window of a recurring job
+            <JobRenewals<T>>::mutate(&job_id, |renewal| {
+                if let Some(renewal) = renewal.as_mut() {
+                    let _ = renewal.previous_sources.try_push(PlannedExecution {
+                        source: who.clone(),
+                        start_delay: assignment.start_delay,
+                    });
+                }
+            });
+
+            Self::deposit_event(Event::JobFinalized(job_id.clone()));
+
+            // renew a recurring job once the last processor finalized its slot
+            if <AssignedProcessors<T>>::iter_prefix(&job_id)
+                .next()
+                .is_none()
+            {
+                Self::renew_job(&job_id, registration)?;
+            }
             Ok(().into())
         }
 
@@ -682,6 +979,220 @@ pub mod pallet {
                     .map(|job_id_seq| (MultiOrigin::Acurast(who.clone()), job_id_seq)),
             )
         }
+
+        /// Sets the renewal of a registered job, locking `deposit` to top up the job's budget for each renewal.
+        ///
+        /// When the job gets finalized after its schedule completed, it is re-opened with the next window instead of being
+        /// removed, as long as the `policy` allows another renewal and the remaining deposit covers the next window's budget.
+        /// A previously set renewal is replaced and its deposit refunded.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_job_renewal())]
+        pub fn set_job_renewal(
+            origin: OriginFor<T>,
+            job_id: JobIdSequence,
+            policy: RenewalPolicy,
+            deposit: T::Balance,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let job_id = (MultiOrigin::Acurast(who), job_id);
+
+            ensure!(
+                <StoredJobStatus<T>>::contains_key(&job_id.0, &job_id.1),
+                Error::<T>::JobStatusNotFound
+            );
+
+            let (previous_sources, slashed_collateral) = Self::remove_renewal(&job_id)?
+                .map(|renewal| (renewal.previous_sources, renewal.slashed_collateral))
+                .unwrap_or((Default::default(), 0u8.into()));
+            T::RewardManager::lock_deposit(&job_id, deposit)?;
+
+            let renewal = JobRenewal {
+                policy,
+                deposit,
+                previous_sources,
+                slashed_collateral,
+            };
+            <JobRenewals<T>>::insert(&job_id, &renewal);
+
+            Self::deposit_event(Event::JobRenewalSet(job_id, renewal));
+            Ok(().into())
+        }
+
+        /// Triggers an execution of an on-demand job, debiting the reward of one execution per assigned processor.
+        ///
+        /// For details see [`Pallet<T>::trigger_execution_for`].
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::trigger_execution())]
+        pub fn trigger_execution(
+            origin: OriginFor<T>,
+            job_id: JobIdSequence,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            Self::trigger_execution_for(&(MultiOrigin::Acurast(who), job_id))
+        }
+
+        /// Sets the job whose fulfillments trigger executions of the on-demand job `job_id`, chaining both jobs into a pipeline.
+        ///
+        /// Every fulfillment of `upstream` triggers an execution of the job, locking the reward for it from the job creator's
+        /// budget as for [`Pallet::trigger_execution`]. For details see [`Pallet::fulfill_upstream_for`].
+        /// Passing `None` removes the job from the pipeline of its previous upstream job.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_job_upstream())]
+        pub fn set_job_upstream(
+            origin: OriginFor<T>,
+            job_id: JobIdSequence,
+            upstream: Option<JobId<T::AccountId>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            let job_id = (MultiOrigin::Acurast(who), job_id);
+
+            let registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+            ensure!(
+                registration.schedule.is_on_demand(),
+                Error::<T>::JobNotOnDemand
+            );
+
+            Self::remove_upstream(&job_id);
+            if let Some(upstream) = &upstream {
+                ensure!(
+                    <StoredJobStatus<T>>::contains_key(&upstream.0, &upstream.1),
+                    Error::<T>::UpstreamJobNotFound
+                );
+                Self::ensure_acyclic(&job_id, upstream)?;
+                ensure!(
+                    <JobDownstreams<T>>::iter_key_prefix(upstream).count()
+                        < MAX_DOWNSTREAM_JOBS as usize,
+                    Error::<T>::TooManyDownstreamJobs
+                );
+
+                <JobUpstreams<T>>::insert(&job_id, upstream);
+                <JobDownstreams<T>>::insert(upstream, &job_id, ());
+            }
+
+            Self::deposit_event(Event::JobUpstreamSet(job_id, upstream));
+            Ok(().into())
+        }
+
+        /// Proposes a processor to take over the slot of a processor that did not acknowledge a matched job in time.
+        ///
+        /// The replaced processor's reputation is lowered as if it missed all executions of the job. The matcher is paid a
+        /// share of the fees saved by the replacement, if it is cheaper than the replaced processor.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::propose_replacement())]
+        pub fn propose_replacement(
+            origin: OriginFor<T>,
+            job_id: JobId<T::AccountId>,
+            replaced: T::AccountId,
+            replacement: PlannedExecution<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let savings = Self::process_replacement(&job_id, &replaced, &replacement)?;
+
+            // pay part of the savings of a cheaper replacement to matcher
+            T::RewardManager::pay_matcher_reward(vec![(job_id, savings)], &who)?;
+
+            Ok(().into())
+        }
+
+        /// Disputes a successful execution reported by `source`, escrowing the execution's fee until the dispute is resolved.
+        ///
+        /// For details see [`Pallet<T>::dispute_execution_for`].
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::dispute_execution())]
+        pub fn dispute_execution(
+            origin: OriginFor<T>,
+            job_id: JobIdSequence,
+            source: T::AccountId,
+            execution_index: u64,
+            operation_hash: ExecutionOperationHash,
+            verifier: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            Self::dispute_execution_for(
+                &(MultiOrigin::Acurast(who), job_id),
+                &source,
+                execution_index,
+                &operation_hash,
+                verifier,
+            )
+        }
+
+        /// Resolves a disputed execution, either upholding it and paying the escrowed fee to the source or rejecting it.
+        ///
+        /// For details see [`Pallet::process_dispute_resolution`].
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::resolve_dispute())]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            job_id: JobId<T::AccountId>,
+            source: T::AccountId,
+            execution_index: u64,
+            upheld: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::DisputeResolutionOrigin::ensure_origin(origin)?;
+
+            Self::process_dispute_resolution(&job_id, &source, execution_index, upheld)?;
+
+            Ok(().into())
+        }
+
+        /// Called by the verifier of a disputed execution with the operation hash of its re-execution.
+        ///
+        /// The disputed execution is upheld if the operation hash matches the reported one and rejected otherwise.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_re_execution())]
+        pub fn report_re_execution(
+            origin: OriginFor<T>,
+            job_id: JobId<T::AccountId>,
+            source: T::AccountId,
+            execution_index: u64,
+            operation_hash: ExecutionOperationHash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let report =
+                <StoredExecutionReports<T>>::get(&job_id, (source.clone(), execution_index))
+                    .ok_or(Error::<T>::ExecutionReportNotFound)?;
+            let dispute = report.dispute.ok_or(Error::<T>::ExecutionNotDisputed)?;
+            ensure!(
+                dispute.verifier.as_ref() == Some(&who),
+                Error::<T>::NotDisputeVerifier
+            );
+
+            Self::process_dispute_resolution(
+                &job_id,
+                &source,
+                execution_index,
+                report.operation_hash == operation_hash,
+            )?;
+
+            Ok(().into())
+        }
+
+        /// Settles the next execution reports of `source` for a job, paying the escrowed fees of reports whose dispute window
+        /// passed and upholding disputes that timed out.
+        ///
+        /// Settlement otherwise happens with every report of the source, but is limited per call to keep its weight bounded.
+        /// This call can be submitted by anyone to settle the remaining reports, e.g. before the source or the job gets finalized.
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::settle_execution_reports())]
+        pub fn settle_execution_reports(
+            origin: OriginFor<T>,
+            job_id: JobId<T::AccountId>,
+            source: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+
+            let assignment =
+                <StoredMatches<T>>::get(&source, &job_id).ok_or(Error::<T>::JobNotAssigned)?;
+            Self::process_settlement(&job_id, &source, assignment.sla.met, Self::now()?)?;
+
+            Ok(().into())
+        }
     }
 
     impl<T: Config> From<Error<T>> for pallet_acurast::Error<T> {
@@ -701,36 +1212,7 @@ pub mod pallet {
             let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
             let requirements: JobRequirementsFor<T> = e.into();
 
-            ensure!(
-                registration.schedule.duration > 0,
-                Error::<T>::JobRegistrationZeroDuration
-            );
-            let execution_count = registration.schedule.execution_count();
-            ensure!(
-                execution_count <= MAX_EXECUTIONS_PER_JOB,
-                Error::<T>::JobRegistrationScheduleExceedsMaximumExecutions
-            );
-            ensure!(
-                execution_count > 0,
-                Error::<T>::JobRegistrationScheduleContainsZeroExecutions
-            );
-            ensure!(
-                registration.schedule.duration < registration.schedule.interval,
-                Error::<T>::JobRegistrationDurationExceedsInterval
-            );
-            ensure!(
-                registration.schedule.start_time >= Self::now()?,
-                Error::<T>::JobRegistrationStartInPast
-            );
-            ensure!(
-                registration.schedule.start_time <= registration.schedule.end_time,
-                Error::<T>::JobRegistrationEndBeforeStart
-            );
-            ensure!(requirements.slots > 0, Error::<T>::JobRegistrationZeroSlots);
-            ensure!(
-                requirements.slots as u32 <= <T as pallet_acurast::Config>::MaxSlots::get(),
-                Error::<T>::TooManySlots
-            );
+            Self::ensure_valid_registration(registration)?;
 
             if let Some(job_status) = <StoredJobStatus<T>>::get(&job_id.0, &job_id.1) {
                 ensure!(
@@ -755,7 +1237,10 @@ pub mod pallet {
             // - lock only after all other steps succeeded without errors because locking reward is not revertable
             // - reward is understood per slot and execution, so calculate total_reward_amount first
             // - lock the complete reward inclusive the matcher share and potential gap to actual fee that will be refunded during job finalization
-            T::RewardManager::lock_reward(&job_id, Self::total_reward_amount(registration)?)?;
+            // - the reward of on-demand jobs is locked per triggered execution instead
+            if !registration.schedule.is_on_demand() {
+                T::RewardManager::lock_reward(&job_id, Self::total_reward_amount(registration)?)?;
+            }
 
             Ok(().into())
         }
@@ -767,22 +1252,41 @@ pub mod pallet {
         fn deregister_hook(job_id: &JobId<T::AccountId>) -> DispatchResultWithPostInfo {
             let job_status = <StoredJobStatus<T>>::get(&job_id.0, &job_id.1)
                 .ok_or(Error::<T>::JobStatusNotFound)?;
+            Self::remove_renewal(job_id)?;
+            Self::remove_pipeline(job_id);
+            // pay out escrowed fees of reported executions, forfeiting open disputes
+            for ((source, _), report) in <StoredExecutionReports<T>>::drain_prefix(job_id) {
+                Self::pay_execution_fee(job_id, &source, report.fee)?;
+            }
+            let _ = <SettledExecutionReports<T>>::clear_prefix(
+                job_id,
+                <T as pallet_acurast::Config>::MaxSlots::get(),
+                None,
+            );
+            let _ = <RejectedExecutions<T>>::clear_prefix(
+                job_id,
+                <T as pallet_acurast::Config>::MaxSlots::get(),
+                None,
+            );
             match job_status {
                 JobStatus::Open => {
                     T::MarketplaceHooks::finalize_job(job_id, T::RewardManager::refund(job_id)?)?;
 
                     <StoredJobStatus<T>>::remove(&job_id.0, &job_id.1);
                 }
-                JobStatus::Matched => {
+                JobStatus::PartiallyMatched { .. } | JobStatus::Matched => {
                     T::MarketplaceHooks::finalize_job(job_id, T::RewardManager::refund(job_id)?)?;
 
                     // Get the job requirements
                     let registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
                         .ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+                    let extra: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+                    let requirements: JobRequirementsFor<T> = extra.into();
 
                     // Remove matching data and increase processor capacity
                     for (p, _) in <AssignedProcessors<T>>::iter_prefix(&job_id) {
                         <StoredMatches<T>>::remove(&p, &job_id);
+                        Self::release_collateral(&p, &requirements);
                         // increase capacity
                         <StoredStorageCapacity<T>>::mutate(&p, |c| {
                             *c = c.unwrap_or(0).checked_add(registration.storage.into())
@@ -794,6 +1298,11 @@ pub mod pallet {
                         <T as pallet_acurast::Config>::MaxSlots::get(),
                         None,
                     );
+                    let _ = <UpstreamFulfillments<T>>::clear_prefix(
+                        &job_id,
+                        <T as pallet_acurast::Config>::MaxSlots::get(),
+                        None,
+                    );
                     <StoredJobStatus<T>>::remove(&job_id.0, &job_id.1);
                 }
                 JobStatus::Assigned(_) => {
@@ -827,6 +1336,7 @@ pub mod pallet {
                         }
                         // Remove match
                         <StoredMatches<T>>::remove(&processor, &job_id);
+                        Self::release_collateral(&processor, &requirements);
                         // increase capacity
                         <StoredStorageCapacity<T>>::mutate(&processor, |c| {
                             *c = c.unwrap_or(0).checked_add(registration.storage.into())
@@ -841,6 +1351,11 @@ pub mod pallet {
                         <T as pallet_acurast::Config>::MaxSlots::get(),
                         None,
                     );
+                    let _ = <UpstreamFulfillments<T>>::clear_prefix(
+                        &job_id,
+                        <T as pallet_acurast::Config>::MaxSlots::get(),
+                        None,
+                    );
                     <StoredJobStatus<T>>::remove(&job_id.0, &job_id.1);
                 }
             }
@@ -864,32 +1379,209 @@ pub mod pallet {
 
             Ok(().into())
         }
-    }
 
-    impl<T: Config> JobBudget<T> for Pallet<T> {
-        fn reserve(job_id: &JobId<T::AccountId>, reward: T::Balance) -> Result<(), ()> {
-            <JobBudgets<T>>::mutate(job_id, |amount| {
-                *amount = amount.checked_add(&reward).ok_or(())?;
-                Ok(())
-            })
-        }
+        /// Updates a job registration in the marketplace.
+        ///
+        /// While the job is [`JobStatus::Open`], every update resulting in a valid registration is accepted and the locked reward is adjusted.
+        /// Once the job got matched, only extensions of the schedule's `end_time` are accepted, given that all assigned processors can still
+        /// fit the additional executions. The reward for the additional executions is locked on top.
+        ///
+        /// The updated `registration` is stored by the caller after this hook succeeded, since this storage point is owned by pallet_acurast.
+        fn update_registration_hook(
+            _who: &MultiOrigin<T::AccountId>,
+            job_id: &JobId<T::AccountId>,
+            previous: &JobRegistrationFor<T>,
+            registration: &JobRegistrationFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let job_status = <StoredJobStatus<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(Error::<T>::JobStatusNotFound)?;
 
-        fn unreserve(job_id: &JobId<T::AccountId>, reward: T::Balance) -> Result<(), ()> {
-            <JobBudgets<T>>::mutate(job_id, |amount| {
-                if reward > *amount {
-                    return Err(());
-                }
-                *amount = amount.checked_sub(&reward).ok_or(())?;
-                Ok(())
-            })
-        }
+            let previous_reward_amount = Self::total_reward_amount(previous)?;
+            let reward_amount = Self::total_reward_amount(registration)?;
 
-        fn unreserve_remaining(job_id: &JobId<T::AccountId>) -> T::Balance {
-            <JobBudgets<T>>::mutate(job_id, |amount| {
-                let remaining = amount.clone();
-                *amount = 0u8.into();
-                remaining
-            })
+            match job_status {
+                JobStatus::Open => {
+                    Self::ensure_valid_registration(registration)?;
+
+                    if reward_amount > previous_reward_amount {
+                        T::RewardManager::lock_reward(
+                            job_id,
+                            reward_amount
+                                .checked_sub(&previous_reward_amount)
+                                .ok_or(Error::<T>::CalculationOverflow)?,
+                        )?;
+                    } else if reward_amount < previous_reward_amount {
+                        // there is no partial refund, so refund everything and lock the new total reward again
+                        T::RewardManager::refund(job_id)?;
+                        T::RewardManager::lock_reward(job_id, reward_amount)?;
+                    }
+                }
+                JobStatus::PartiallyMatched { .. }
+                | JobStatus::Matched
+                | JobStatus::Assigned(_) => {
+                    let mut extended = previous.clone();
+                    extended.schedule.end_time = registration.schedule.end_time;
+                    ensure!(
+                        &extended == registration
+                            && registration.schedule.end_time >= previous.schedule.end_time,
+                        Error::<T>::JobRegistrationUnmodifiable
+                    );
+                    ensure!(
+                        !Self::schedule_ended(&previous.schedule)?,
+                        Error::<T>::JobRegistrationUnmodifiable
+                    );
+
+                    let previous_execution_count = previous.schedule.execution_count();
+                    let execution_count = registration.schedule.execution_count();
+                    ensure!(
+                        execution_count <= MAX_EXECUTIONS_PER_JOB,
+                        Error::<T>::JobRegistrationScheduleExceedsMaximumExecutions
+                    );
+                    if execution_count == previous_execution_count {
+                        return Ok(().into());
+                    }
+
+                    // the additional executions start after the last execution of the previous schedule
+                    let extension = Schedule {
+                        start_time: previous
+                            .schedule
+                            .period()
+                            .and_then(|offset| previous.schedule.start_time.checked_add(offset))
+                            .ok_or(Error::<T>::CalculationOverflow)?,
+                        ..registration.schedule.clone()
+                    };
+
+                    let now = Self::now()?;
+                    for (processor, _) in <AssignedProcessors<T>>::iter_prefix(&job_id) {
+                        let assignment = <StoredMatches<T>>::get(&processor, &job_id)
+                            .ok_or(Error::<T>::JobNotAssigned)?;
+                        let pricing = <StoredAdvertisementPricing<T>>::get(&processor)
+                            .ok_or(Error::<T>::AdvertisementPricingNotFound)?;
+
+                        // CHECK the scheduling_window allow to schedule the extended job
+                        Self::check_scheduling_window(
+                            &pricing.scheduling_window,
+                            &registration.schedule,
+                            now,
+                            assignment.start_delay,
+                        )?;
+
+                        // CHECK additional executions fit into processor's schedule
+                        Self::fits_schedule(&processor, &extension, assignment.start_delay)?;
+
+                        <StoredMatches<T>>::mutate(&processor, &job_id, |a| {
+                            if let Some(a) = a.as_mut() {
+                                a.sla.total = execution_count;
+                            }
+                        });
+                    }
+
+                    T::RewardManager::lock_reward(
+                        job_id,
+                        reward_amount
+                            .checked_sub(&previous_reward_amount)
+                            .ok_or(Error::<T>::CalculationOverflow)?,
+                    )?;
+                }
+            }
+
+            Ok(().into())
+        }
+
+        /// Ensures the environment is encrypted for one of the keys revealed by `source` when acknowledging the job.
+        fn set_environment_hook(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            environment: &EnvironmentFor<T>,
+        ) -> DispatchResultWithPostInfo {
+            let assignment =
+                <StoredMatches<T>>::get(source, job_id).ok_or(Error::<T>::JobNotAssigned)?;
+            ensure!(
+                assignment.acknowledged && assignment.pub_keys.contains(&environment.public_key),
+                Error::<T>::EnvironmentKeyNotAcknowledged
+            );
+
+            Ok(().into())
+        }
+
+        /// Moves the job's status, budget, renewal and pipeline stages to `new_job_id`.
+        ///
+        /// Jobs with assigned processors cannot be transferred since the processors execute the job under its current job id.
+        /// The budget and renewal deposit stay locked on the pallet account, remaining rewards and deposit are refunded to the new owner on finalization.
+        fn transfer_hook(
+            job_id: &JobId<T::AccountId>,
+            new_job_id: &JobId<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            ensure!(
+                <AssignedProcessors<T>>::iter_prefix(job_id)
+                    .next()
+                    .is_none(),
+                Error::<T>::CannotTransferAssignedJob
+            );
+
+            let job_status = <StoredJobStatus<T>>::take(&job_id.0, &job_id.1)
+                .ok_or(Error::<T>::JobStatusNotFound)?;
+            <StoredJobStatus<T>>::insert(&new_job_id.0, &new_job_id.1, job_status);
+
+            <JobBudgets<T>>::insert(new_job_id, <JobBudgets<T>>::take(job_id));
+            if let Some(renewal) = <JobRenewals<T>>::take(job_id) {
+                <JobRenewals<T>>::insert(new_job_id, renewal);
+            }
+
+            if let Some(upstream) = <JobUpstreams<T>>::take(job_id) {
+                <JobDownstreams<T>>::remove(&upstream, job_id);
+                <JobDownstreams<T>>::insert(&upstream, new_job_id, ());
+                <JobUpstreams<T>>::insert(new_job_id, upstream);
+            }
+            let downstreams = <JobDownstreams<T>>::drain_prefix(job_id).collect::<Vec<_>>();
+            for (downstream, _) in downstreams {
+                <JobUpstreams<T>>::insert(&downstream, new_job_id);
+                <JobDownstreams<T>>::insert(new_job_id, &downstream, ());
+            }
+
+            Ok(().into())
+        }
+    }
+
+    impl<T: Config> ExecutionTrigger<T> for Pallet<T> {
+        fn trigger_execution(job_id: &JobId<T::AccountId>) -> DispatchResultWithPostInfo {
+            Self::trigger_execution_for(job_id)
+        }
+    }
+
+    impl<T: Config + pallet_acurast_fulfillment_receiver::Config> OnFulfillment<T> for Pallet<T> {
+        fn on_fulfillment(
+            from: T::AccountId,
+            fulfillment: Fulfillment,
+        ) -> DispatchResultWithInfo<PostDispatchInfo> {
+            Self::fulfill_upstream_for(&from, &fulfillment.script)
+        }
+    }
+
+    impl<T: Config> JobBudget<T> for Pallet<T> {
+        fn reserve(job_id: &JobId<T::AccountId>, reward: T::Balance) -> Result<(), ()> {
+            <JobBudgets<T>>::mutate(job_id, |amount| {
+                *amount = amount.checked_add(&reward).ok_or(())?;
+                Ok(())
+            })
+        }
+
+        fn unreserve(job_id: &JobId<T::AccountId>, reward: T::Balance) -> Result<(), ()> {
+            <JobBudgets<T>>::mutate(job_id, |amount| {
+                if reward > *amount {
+                    return Err(());
+                }
+                *amount = amount.checked_sub(&reward).ok_or(())?;
+                Ok(())
+            })
+        }
+
+        fn unreserve_remaining(job_id: &JobId<T::AccountId>) -> T::Balance {
+            <JobBudgets<T>>::mutate(job_id, |amount| {
+                let remaining = amount.clone();
+                *amount = 0u8.into();
+                remaining
+            })
         }
 
         fn reserved(job_id: &JobId<T::AccountId>) -> T::Balance {
@@ -900,7 +1592,10 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Checks if a Processor - Job match is possible and returns the remaining job rewards by `job_id`.
         ///
-        /// If the job is no longer in status [`JobStatus::Open`], the matching is skipped without returning an error.
+        /// A match fills free slots of the job, one per source. The job stays [`JobStatus::PartiallyMatched`] until at least
+        /// [`JobRequirements::min_slots`] slots are filled and becomes [`JobStatus::Matched`] afterwards.
+        ///
+        /// If the job is no longer in status [`JobStatus::Open`] or [`JobStatus::PartiallyMatched`], the matching is skipped without returning an error.
         /// **The returned vector does not include an entry for skipped matches.**
         ///
         /// Every other invalidity in a provided [`Match`] fails the entire call.
@@ -913,10 +1608,12 @@ pub mod pallet {
                 let job_status = <StoredJobStatus<T>>::get(&m.job_id.0, &m.job_id.1)
                     .ok_or(Error::<T>::JobStatusNotFound)?;
 
-                if job_status != JobStatus::Open {
+                let filled = match job_status {
+                    JobStatus::Open => 0,
+                    JobStatus::PartiallyMatched { filled, .. } => filled,
                     // skip but don't fail this match
-                    continue;
-                }
+                    _ => continue,
+                };
 
                 let registration = <StoredJobRegistration<T>>::get(&m.job_id.0, &m.job_id.1)
                     .ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
@@ -931,170 +1628,510 @@ pub mod pallet {
                 let l: u8 = m.sources.len().try_into().unwrap_or(0);
                 ensure!(
                     // NOTE: we are checking for duplicates while inserting/mutating StoredMatches below
-                    l == requirements.slots,
+                    l > 0 && (filled as u32 + l as u32) <= requirements.slots as u32,
                     Error::<T>::IncorrectSourceCountInMatch
                 );
+                let filled = filled + l;
+
+                // slots are usually filled in order, but we don't rely on it
+                let taken_slots = <AssignedProcessors<T>>::iter_key_prefix(&m.job_id)
+                    .filter_map(|p| <StoredMatches<T>>::get(&p, &m.job_id))
+                    .map(|assignment| assignment.slot)
+                    .collect::<Vec<_>>();
+                let mut free_slots =
+                    (0..requirements.slots).filter(|slot| !taken_slots.contains(slot));
 
                 let reward_amount: <T as Config>::Balance = requirements.reward;
 
                 // keep track of total fee in assignments to check later if it exceeds reward
                 let mut total_fee: <T as Config>::Balance = 0u8.into();
 
-                // `slot` is used for detecting duplicate source proposed for distinct slots
                 // TODO: add global (configurable) maximum of jobs assigned. This would limit the weight of `propose_matching` to a constant, since it depends on the number of active matches.
-                for (slot, planned_execution) in m.sources.iter().enumerate() {
-                    // CHECK attestation
-                    ensure!(
-                        !registration.allow_only_verified_sources
-                            || ensure_source_verified::<T>(&planned_execution.source).is_ok(),
-                        Error::<T>::UnverifiedSourceInMatch
+                for planned_execution in m.sources.iter() {
+                    let slot = free_slots
+                        .next()
+                        .ok_or(Error::<T>::IncorrectSourceCountInMatch)?;
+
+                    total_fee = total_fee
+                        .checked_add(&Self::assign_slot(
+                            &m.job_id,
+                            &registration,
+                            &requirements,
+                            now,
+                            slot,
+                            planned_execution,
+                        )?)
+                        .ok_or(Error::<T>::CalculationOverflow)?;
+                }
+
+                // CHECK total fee is not exceeding reward of the slots filled by this match
+                let slots_reward_amount = reward_amount
+                    .checked_mul(&((l as u128).into()))
+                    .ok_or(Error::<T>::CalculationOverflow)?
+                    .checked_mul(&registration.schedule.execution_count().into())
+                    .ok_or(Error::<T>::CalculationOverflow)?;
+                let diff = slots_reward_amount
+                    .checked_sub(&total_fee)
+                    .ok_or(Error::<T>::InsufficientRewardInMatch)?;
+                // We better check for diff positive <=> total_fee <= total_reward_amount
+                // because we cannot assume that asset amount is an unsigned integer for all future
+                ensure!(diff >= 0u32.into(), Error::<T>::InsufficientRewardInMatch);
+
+                remaining_rewards.push((m.job_id.clone(), diff));
+
+                if filled < requirements.min_slots.unwrap_or(requirements.slots) {
+                    <StoredJobStatus<T>>::insert(
+                        &m.job_id.0,
+                        &m.job_id.1,
+                        JobStatus::PartiallyMatched {
+                            filled,
+                            total: requirements.slots,
+                        },
                     );
+                    Self::deposit_event(Event::JobRegistrationPartiallyMatched(m.clone()));
+                    continue;
+                }
 
-                    let ad = <StoredAdvertisementRestriction<T>>::get(&planned_execution.source)
-                        .ok_or(Error::<T>::AdvertisementNotFound)?;
+                <StoredTotalAssignedV3<T>>::mutate(|t| {
+                    *t = Some(t.unwrap_or(0u128).saturating_add(1));
+                });
 
-                    for required_module in &registration.required_modules {
-                        ensure!(
-                            ad.available_modules.contains(required_module),
-                            Error::<T>::ModuleNotAvailableInMatch
-                        );
-                    }
+                <StoredJobStatus<T>>::insert(&m.job_id.0, &m.job_id.1, JobStatus::Matched);
+                Self::deposit_event(Event::JobRegistrationMatched(m.clone()));
+            }
+            return Ok(remaining_rewards);
+        }
 
-                    let pricing = <StoredAdvertisementPricing<T>>::get(&planned_execution.source)
-                        .ok_or(Error::<T>::AdvertisementPricingNotFound)?;
+        /// Replaces a processor that did not acknowledge a job with `replacement` and returns the fees saved by the replacement.
+        ///
+        /// Valid from [`Config::AcknowledgeDeadline`] before the job's start until the job starts, if the job is [`JobStatus::Matched`]
+        /// or [`JobStatus::Assigned`]. The replaced processor's slot is cleared and its storage capacity restored before the slot
+        /// is assigned to the replacement, subject to the same checks as in [`Pallet::process_matching`].
+        fn process_replacement(
+            job_id: &JobId<T::AccountId>,
+            replaced: &T::AccountId,
+            replacement: &PlannedExecution<T::AccountId>,
+        ) -> Result<T::Balance, DispatchError> {
+            let job_status = <StoredJobStatus<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(Error::<T>::JobStatusNotFound)?;
+            ensure!(
+                matches!(job_status, JobStatus::Matched | JobStatus::Assigned(_)),
+                Error::<T>::CannotReplaceWhenNotMatched
+            );
 
-                    // CHECK the scheduling_window allow to schedule this job
-                    Self::check_scheduling_window(
-                        &pricing.scheduling_window,
-                        &registration.schedule,
-                        now,
-                        planned_execution.start_delay,
-                    )?;
+            let registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+            let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+            let requirements: JobRequirementsFor<T> = e.into();
 
-                    // CHECK memory sufficient
-                    ensure!(
-                        ad.max_memory >= registration.memory,
-                        Error::<T>::MaxMemoryExceededInMatch
-                    );
+            let now = Self::now()?;
+            ensure!(
+                now < registration.schedule.start_time,
+                Error::<T>::OverdueMatch
+            );
+            ensure!(
+                now >= registration
+                    .schedule
+                    .start_time
+                    .saturating_sub(T::AcknowledgeDeadline::get()),
+                Error::<T>::AcknowledgeDeadlineNotPassed
+            );
 
-                    // CHECK network request quota sufficient
-                    Self::check_network_request_quota_sufficient(
-                        &ad,
-                        &registration.schedule,
-                        registration.network_requests,
-                    )?;
+            let assignment =
+                <StoredMatches<T>>::get(replaced, job_id).ok_or(Error::<T>::JobNotAssigned)?;
+            ensure!(
+                !assignment.acknowledged,
+                Error::<T>::CannotReplaceAcknowledgedProcessor
+            );
+            ensure!(
+                &replacement.source != replaced,
+                Error::<T>::DuplicateSourceInMatch
+            );
 
-                    // CHECK remaining storage capacity sufficient
-                    let capacity = <StoredStorageCapacity<T>>::get(&planned_execution.source)
-                        .ok_or(Error::<T>::CapacityNotFound)?;
-                    ensure!(
-                        capacity >= registration.storage as i64,
-                        Error::<T>::InsufficientStorageCapacityInMatch
-                    );
+            // free the slot of the replaced processor
+            <StoredMatches<T>>::remove(replaced, job_id);
+            Self::release_collateral(replaced, &requirements);
+            <AssignedProcessors<T>>::remove(job_id, replaced);
+            <StoredStorageCapacity<T>>::mutate(replaced, |c| {
+                *c = c.unwrap_or(0).checked_add(registration.storage.into())
+            });
+            Self::penalize_unmet(replaced, assignment.fee_per_execution, assignment.sla.total)?;
+
+            let fee = Self::assign_slot(
+                job_id,
+                &registration,
+                &requirements,
+                now,
+                assignment.slot,
+                replacement,
+            )?;
 
-                    // CHECK source is whitelisted
-                    ensure!(
-                        is_source_whitelisted::<T>(
-                            &planned_execution.source,
-                            &registration.allowed_sources
-                        ),
-                        Error::<T>::SourceNotAllowedInMatch
-                    );
+            // CHECK fee is not exceeding reward of the slot
+            let execution_count: T::Balance = registration.schedule.execution_count().into();
+            let slot_reward_amount = requirements
+                .reward
+                .checked_mul(&execution_count)
+                .ok_or(Error::<T>::CalculationOverflow)?;
+            ensure!(
+                fee <= slot_reward_amount,
+                Error::<T>::InsufficientRewardInMatch
+            );
 
-                    // CHECK consumer is whitelisted
-                    ensure!(
-                        is_consumer_whitelisted::<T>(&m.job_id.0, &ad.allowed_consumers),
-                        Error::<T>::ConsumerNotAllowedInMatch
-                    );
+            // the matcher of the replaced processor already got paid from the slot's remaining reward,
+            // so only the savings of a cheaper replacement are left for the matcher of the replacement
+            let replaced_fee = assignment
+                .fee_per_execution
+                .checked_mul(&execution_count)
+                .ok_or(Error::<T>::CalculationOverflow)?;
+            let diff = replaced_fee.saturating_sub(fee);
 
-                    // CHECK reputation sufficient
-                    Self::check_min_reputation(
-                        requirements.min_reputation,
-                        &planned_execution.source,
-                    )?;
+            Self::deposit_event(Event::ProcessorReplaced(
+                job_id.clone(),
+                replaced.clone(),
+                replacement.source.clone(),
+            ));
 
-                    // CHECK schedule
-                    Self::fits_schedule(
-                        &planned_execution.source,
-                        &registration.schedule,
-                        planned_execution.start_delay,
-                    )?;
+            Ok(diff)
+        }
 
-                    // calculate fee
-                    let fee_per_execution = Self::fee_per_execution(
-                        &registration.schedule,
-                        registration.storage,
-                        &pricing,
-                    )?;
+        /// Lowers the reputation of a processor for `unmet` executions, e.g. when it did not acknowledge its assignment or
+        /// an execution it reported got rejected in a dispute.
+        fn penalize_unmet(
+            processor: &T::AccountId,
+            fee_per_execution: T::Balance,
+            unmet: u64,
+        ) -> Result<(), Error<T>> {
+            // skip reputation update if reward is 0
+            if fee_per_execution == 0u8.into() {
+                return Ok(());
+            }
 
-                    // CHECK price not exceeding reward
-                    ensure!(
-                        fee_per_execution <= reward_amount,
-                        Error::<T>::InsufficientRewardInMatch
-                    );
+            let average_reward = <StoredAverageRewardV3<T>>::get().unwrap_or(0);
+            let beta_params =
+                <StoredReputation<T>>::get(processor).ok_or(Error::<T>::ReputationNotFound)?;
+            let beta_params = BetaReputation::update(
+                beta_params,
+                0,
+                unmet,
+                fee_per_execution,
+                average_reward.into(),
+            )
+            .ok_or(Error::<T>::CalculationOverflow)?;
 
-                    let execution_count = registration.schedule.execution_count();
+            <StoredReputation<T>>::insert(
+                processor,
+                BetaParameters {
+                    r: beta_params.r,
+                    s: beta_params.s,
+                },
+            );
+            Ok(())
+        }
 
-                    total_fee = total_fee
-                        .checked_add(
-                            &fee_per_execution
-                                .checked_mul(&execution_count.into())
-                                .ok_or(Error::<T>::CalculationOverflow)?,
-                        )
-                        .ok_or(Error::<T>::CalculationOverflow)?;
+        /// Pays the fee of an execution to the manager of `source`.
+        fn pay_execution_fee(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            fee: T::Balance,
+        ) -> Result<(), DispatchError> {
+            let manager = T::ManagerProvider::manager_of(source)?;
+            T::RewardManager::pay_reward(job_id, fee, &manager)
+        }
 
-                    // ASSIGN if not yet assigned (equals to CHECK that no duplicate source in a single mutate operation)
-                    <StoredMatches<T>>::try_mutate(
-                        &planned_execution.source,
-                        &m.job_id,
-                        |s| -> Result<(), Error<T>> {
-                            // NOTE: the None case is the "good case", used when there is *no entry yet and thus no duplicate assignment so far*.
-                            match s {
-                                Some(_) => Err(Error::<T>::DuplicateSourceInMatch),
-                                None => {
-                                    *s = Some(Assignment {
-                                        slot: slot as u8,
-                                        start_delay: planned_execution.start_delay,
-                                        fee_per_execution,
-                                        acknowledged: false,
-                                        sla: SLA {
-                                            total: execution_count,
-                                            met: 0,
-                                        },
-                                        pub_keys: PubKeys::default(),
-                                    });
-                                    Ok(())
-                                }
-                            }?;
-                            Ok(())
-                        },
-                    )?;
-                    <AssignedProcessors<T>>::insert(&m.job_id, &planned_execution.source, ());
-                    <StoredStorageCapacity<T>>::set(
-                        &planned_execution.source,
-                        capacity.checked_sub(registration.storage.into()),
-                    );
+        /// Settles the execution reports of `source` for a job in order of their index, starting at the index stored in
+        /// [`SettledExecutionReports`] and considering the first `reported` executions of the source.
+        ///
+        /// Undisputed reports whose dispute window passed are paid and disputes older than [`Config::DisputeTimeout`] are
+        /// upheld. Settlement stops at the first report still escrowed, or after [`MAX_SETTLED_EXECUTION_REPORTS`] reports.
+        ///
+        /// Returns `true` if all reported executions of `source` are settled.
+        fn process_settlement(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            reported: u64,
+            now: u64,
+        ) -> Result<bool, DispatchError> {
+            let start = <SettledExecutionReports<T>>::get(job_id, source);
+            let end = reported.min(start.saturating_add(MAX_SETTLED_EXECUTION_REPORTS));
+
+            let mut next = start;
+            while next < end {
+                // failed executions and resolved disputes leave no report to settle
+                if let Some(report) =
+                    <StoredExecutionReports<T>>::get(job_id, (source.clone(), next))
+                {
+                    match report.dispute {
+                        None => {
+                            if now < report.reported_at.saturating_add(T::DisputeWindow::get()) {
+                                break;
+                            }
+                            <StoredExecutionReports<T>>::remove(job_id, (source.clone(), next));
+                            Self::pay_execution_fee(job_id, source, report.fee)?;
+                        }
+                        Some(dispute) => {
+                            if now < dispute.disputed_at.saturating_add(T::DisputeTimeout::get()) {
+                                break;
+                            }
+                            Self::process_dispute_resolution(job_id, source, next, true)?;
+                        }
+                    }
                 }
+                next += 1;
+            }
 
-                // CHECK total fee is not exceeding reward
-                let total_reward_amount = Self::total_reward_amount(&registration)?;
-                let diff = total_reward_amount
-                    .checked_sub(&total_fee)
-                    .ok_or(Error::<T>::InsufficientRewardInMatch)?;
-                // We better check for diff positive <=> total_fee <= total_reward_amount
-                // because we cannot assume that asset amount is an unsigned integer for all future
-                ensure!(diff >= 0u32.into(), Error::<T>::InsufficientRewardInMatch);
+            if next != start {
+                <SettledExecutionReports<T>>::insert(job_id, source, next);
+            }
+            Ok(next >= reported)
+        }
 
-                remaining_rewards.push((m.job_id.clone(), diff));
+        /// Slashes the collateral of a source that missed `unmet` executions of a job with [JobRequirements::min_collateral].
+        ///
+        /// [Config::CollateralSlash] of the job's `min_collateral` is slashed per missed execution, at most `min_collateral` and
+        /// the source's remaining collateral. The [Config::CollateralSlashTreasuryShare] of the amount actually slashed goes to the
+        /// treasury, the rest is added to the job's budget to be refunded to the job's creator.
+        fn slash_collateral(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            requirements: &JobRequirementsFor<T>,
+            unmet: u64,
+        ) -> Result<(), DispatchError> {
+            let min_collateral = match requirements.min_collateral {
+                Some(min_collateral) => min_collateral,
+                None => return Ok(()),
+            };
+
+            let slash = T::CollateralSlash::get()
+                .mul_floor(min_collateral)
+                .saturating_mul(unmet.into())
+                .min(min_collateral)
+                .min(<StoredCollateral<T>>::get(source));
+            if slash == 0u8.into() {
+                return Ok(());
+            }
 
-                <StoredTotalAssignedV3<T>>::mutate(|t| {
-                    *t = Some(t.unwrap_or(0u128).saturating_add(1));
+            let treasury_share = T::CollateralSlashTreasuryShare::get();
+            let slashed =
+                T::RewardManager::slash_collateral(job_id, source, slash, treasury_share)?;
+            <StoredCollateral<T>>::mutate(source, |c| *c = c.saturating_sub(slashed));
+
+            // a recurring job refunds the slashed collateral on renewal instead of using it for the next window
+            let to_budget = slashed.saturating_sub(treasury_share.mul_floor(slashed));
+            <JobRenewals<T>>::mutate(job_id, |renewal| {
+                if let Some(renewal) = renewal.as_mut() {
+                    renewal.slashed_collateral =
+                        renewal.slashed_collateral.saturating_add(to_budget);
+                }
+            });
+
+            Self::deposit_event(Event::CollateralSlashed(
+                job_id.clone(),
+                source.clone(),
+                slashed,
+            ));
+            Ok(())
+        }
+
+        /// Checks if `planned_execution` can fill the free `slot` of a job and assigns the slot to its source.
+        ///
+        /// Returns the total fee of the source for all executions of the job.
+        fn assign_slot(
+            job_id: &JobId<T::AccountId>,
+            registration: &JobRegistrationFor<T>,
+            requirements: &JobRequirementsFor<T>,
+            now: u64,
+            slot: u8,
+            planned_execution: &PlannedExecution<T::AccountId>,
+        ) -> Result<T::Balance, DispatchError> {
+            // CHECK attestation
+            ensure!(
+                !registration.allow_only_verified_sources
+                    || ensure_source_verified::<T>(&planned_execution.source).is_ok(),
+                Error::<T>::UnverifiedSourceInMatch
+            );
+
+            let ad = <StoredAdvertisementRestriction<T>>::get(&planned_execution.source)
+                .ok_or(Error::<T>::AdvertisementNotFound)?;
+
+            Self::ensure_modules_available(&registration.required_modules, &ad.available_modules)?;
+
+            let pricing = <StoredAdvertisementPricing<T>>::get(&planned_execution.source)
+                .ok_or(Error::<T>::AdvertisementPricingNotFound)?;
+
+            // CHECK the scheduling_window allow to schedule this job
+            Self::check_scheduling_window(
+                &pricing.scheduling_window,
+                &registration.schedule,
+                now,
+                planned_execution.start_delay,
+            )?;
+
+            // CHECK memory sufficient
+            ensure!(
+                ad.max_memory >= registration.memory,
+                Error::<T>::MaxMemoryExceededInMatch
+            );
+
+            // CHECK network request quota sufficient
+            Self::check_network_request_quota_sufficient(
+                &ad,
+                &registration.schedule,
+                registration.network_requests,
+            )?;
+
+            // CHECK remaining storage capacity sufficient
+            let capacity = <StoredStorageCapacity<T>>::get(&planned_execution.source)
+                .ok_or(Error::<T>::CapacityNotFound)?;
+            ensure!(
+                capacity >= registration.storage as i64,
+                Error::<T>::InsufficientStorageCapacityInMatch
+            );
+
+            // CHECK source is whitelisted
+            ensure!(
+                is_source_whitelisted::<T>(
+                    &planned_execution.source,
+                    &registration.allowed_sources
+                ),
+                Error::<T>::SourceNotAllowedInMatch
+            );
+
+            // CHECK consumer is whitelisted
+            ensure!(
+                is_consumer_whitelisted::<T>(job_id.0, &ad.allowed_consumers),
+                Error::<T>::ConsumerNotAllowedInMatch
+            );
+
+            // CHECK reputation sufficient
+            Self::check_min_reputation(requirements.min_reputation, &planned_execution.source)?;
+
+            // CHECK collateral sufficient
+            Self::check_min_collateral(requirements.min_collateral, &planned_execution.source)?;
+
+            // CHECK schedule
+            Self::fits_schedule(
+                &planned_execution.source,
+                &registration.schedule,
+                planned_execution.start_delay,
+            )?;
+
+            // calculate fee
+            let fee_per_execution =
+                Self::fee_per_execution(&registration.schedule, registration.storage, &pricing)?;
+
+            // CHECK price not exceeding reward
+            ensure!(
+                fee_per_execution <= requirements.reward,
+                Error::<T>::InsufficientRewardInMatch
+            );
+
+            let execution_count = registration.schedule.execution_count();
+
+            let fee = fee_per_execution
+                .checked_mul(&execution_count.into())
+                .ok_or(Error::<T>::CalculationOverflow)?;
+
+            // ASSIGN if not yet assigned (equals to CHECK that no duplicate source in a single mutate operation)
+            <StoredMatches<T>>::try_mutate(
+                &planned_execution.source,
+                job_id,
+                |s| -> Result<(), Error<T>> {
+                    // NOTE: the None case is the "good case", used when there is *no entry yet and thus no duplicate assignment so far*.
+                    match s {
+                        Some(_) => Err(Error::<T>::DuplicateSourceInMatch),
+                        None => {
+                            *s = Some(Assignment {
+                                slot,
+                                start_delay: planned_execution.start_delay,
+                                fee_per_execution,
+                                acknowledged: false,
+                                sla: SLA {
+                                    total: execution_count,
+                                    met: 0,
+                                },
+                                pub_keys: PubKeys::default(),
+                            });
+                            Ok(())
+                        }
+                    }?;
+                    Ok(())
+                },
+            )?;
+            <AssignedProcessors<T>>::insert(job_id, &planned_execution.source, ());
+            <StoredStorageCapacity<T>>::set(
+                &planned_execution.source,
+                capacity.checked_sub(registration.storage.into()),
+            );
+            if let Some(min_collateral) = requirements.min_collateral {
+                <CommittedCollateral<T>>::mutate(&planned_execution.source, |c| {
+                    *c = c.saturating_add(min_collateral)
                 });
+            }
 
-                <StoredJobStatus<T>>::insert(&m.job_id.0, &m.job_id.1, JobStatus::Matched);
-                Self::deposit_event(Event::JobRegistrationMatched(m.clone()));
+            Ok(fee)
+        }
+
+        /// Ensures that a processor advertising `available_modules` provides every one of the `required_modules`.
+        fn ensure_modules_available(
+            required_modules: &JobModules,
+            available_modules: &JobModules,
+        ) -> Result<(), Error<T>> {
+            for required_module in required_modules {
+                ensure!(
+                    available_modules.contains(required_module),
+                    Error::<T>::ModuleNotAvailableInMatch
+                );
+            }
+            Ok(())
+        }
+
+        /// Ensures the given registration is valid, independent of the job's current state.
+        fn ensure_valid_registration(registration: &JobRegistrationFor<T>) -> Result<(), Error<T>> {
+            let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+            let requirements: JobRequirementsFor<T> = e.into();
+
+            ensure!(
+                registration.schedule.duration > 0,
+                Error::<T>::JobRegistrationZeroDuration
+            );
+            ensure!(
+                registration.schedule.is_valid_kind(),
+                Error::<T>::JobRegistrationInvalidScheduleKind
+            );
+            let execution_count = registration.schedule.execution_count();
+            ensure!(
+                execution_count <= MAX_EXECUTIONS_PER_JOB,
+                Error::<T>::JobRegistrationScheduleExceedsMaximumExecutions
+            );
+            ensure!(
+                execution_count > 0 || registration.schedule.is_on_demand(),
+                Error::<T>::JobRegistrationScheduleContainsZeroExecutions
+            );
+            ensure!(
+                registration.schedule.duration < registration.schedule.min_interval(),
+                Error::<T>::JobRegistrationDurationExceedsInterval
+            );
+            ensure!(
+                registration.schedule.start_time >= Self::now()?,
+                Error::<T>::JobRegistrationStartInPast
+            );
+            ensure!(
+                registration.schedule.start_time <= registration.schedule.end_time,
+                Error::<T>::JobRegistrationEndBeforeStart
+            );
+            ensure!(requirements.slots > 0, Error::<T>::JobRegistrationZeroSlots);
+            ensure!(
+                requirements.slots as u32 <= <T as pallet_acurast::Config>::MaxSlots::get(),
+                Error::<T>::TooManySlots
+            );
+            if let Some(min_slots) = requirements.min_slots {
+                ensure!(
+                    min_slots > 0 && min_slots <= requirements.slots,
+                    Error::<T>::JobRegistrationInvalidMinSlots
+                );
             }
-            return Ok(remaining_rewards);
+
+            Ok(())
         }
 
         fn check_scheduling_window(
@@ -1173,6 +2210,35 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Checks that the collateral of `source` not yet committed to other assignments covers `min_collateral`.
+        fn check_min_collateral(
+            min_collateral: Option<T::Balance>,
+            source: &T::AccountId,
+        ) -> Result<(), Error<T>> {
+            if let Some(min_collateral) = min_collateral {
+                let available = <StoredCollateral<T>>::get(source)
+                    .saturating_sub(<CommittedCollateral<T>>::get(source));
+                ensure!(
+                    available >= min_collateral,
+                    Error::<T>::InsufficientCollateralInMatch
+                );
+            }
+            Ok(())
+        }
+
+        /// Releases the collateral `source` committed to its assignment of a job with `requirements`.
+        fn release_collateral(source: &T::AccountId, requirements: &JobRequirementsFor<T>) {
+            if let Some(min_collateral) = requirements.min_collateral {
+                let committed =
+                    <CommittedCollateral<T>>::get(source).saturating_sub(min_collateral);
+                if committed == 0u8.into() {
+                    <CommittedCollateral<T>>::remove(source);
+                } else {
+                    <CommittedCollateral<T>>::insert(source, committed);
+                }
+            }
+        }
+
         /// Filters the given `sources` by those recently seen and matching partially specified `registration`
         /// and whitelisting `consumer` if specifying a whitelist.
         pub fn filter_matching_sources(
@@ -1224,12 +2290,7 @@ pub mod pallet {
             let ad = <StoredAdvertisementRestriction<T>>::get(&source)
                 .ok_or(Error::<T>::AdvertisementNotFound)?;
 
-            for required_module in &registration.required_modules {
-                ensure!(
-                    ad.available_modules.contains(required_module),
-                    Error::<T>::ModuleNotAvailableInMatch
-                );
-            }
+            Self::ensure_modules_available(&registration.required_modules, &ad.available_modules)?;
 
             let pricing = <StoredAdvertisementPricing<T>>::get(&source)
                 .ok_or(Error::<T>::AdvertisementPricingNotFound)?;
@@ -1297,11 +2358,14 @@ pub mod pallet {
             // CHECK reputation sufficient
             Self::check_min_reputation(registration.min_reputation, &source)?;
 
+            // CHECK collateral sufficient
+            Self::check_min_collateral(registration.min_collateral, &source)?;
+
             Ok(())
         }
 
         /// Returns true if the source has currently at least one match (not necessarily assigned).
-        fn has_matches(source: &T::AccountId) -> bool {
+        pub(crate) fn has_matches(source: &T::AccountId) -> bool {
             // NOTE we use a trick to check if map contains *any* secondary key: we use `any` to short-circuit
             // whenever we encounter the first - so at least one - element in the iterator.
             <StoredMatches<T>>::iter_prefix_values(&source).any(|_| true)
@@ -1426,7 +2490,7 @@ pub mod pallet {
         ///
         /// Only valid if for all given jobs provided,
         ///
-        /// * the job was **not** acknowledged by any processor (job is in state [`JobStatus::Matched`]) OR
+        /// * the job was **not** acknowledged by any processor (job is in state [`JobStatus::PartiallyMatched`] or [`JobStatus::Matched`]) OR
         /// * the job was acknowledged by **at least one** processor (job is in state [`JobStatus::Assigned`]) AND
         ///   * all processors have finalized their corresponding slot OR
         ///   * the latest possible reporting time has passed
@@ -1444,7 +2508,7 @@ pub mod pallet {
 
                 match job_status {
                     JobStatus::Open => Err(Error::<T>::CannotFinalizeJob(job_status))?,
-                    JobStatus::Matched => {
+                    JobStatus::PartiallyMatched { .. } | JobStatus::Matched => {
                         let match_overdue = Self::now()? >= registration.schedule.start_time;
                         if !match_overdue {
                             Err(Error::<T>::CannotFinalizeJob(job_status))?;
@@ -1461,9 +2525,33 @@ pub mod pallet {
                     }
                 }
 
+                let now = Self::now()?;
+                for (p, _) in <AssignedProcessors<T>>::iter_prefix(&job_id) {
+                    if let Some(assignment) = <StoredMatches<T>>::get(&p, &job_id) {
+                        ensure!(
+                            Self::process_settlement(&job_id, &p, assignment.sla.met, now)?,
+                            Error::<T>::ExecutionReportsPending
+                        );
+                    }
+                }
+
+                let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+                let requirements: JobRequirementsFor<T> = e.into();
+
                 // removed completed job from remaining storage points
                 for (p, _) in <AssignedProcessors<T>>::iter_prefix(&job_id) {
-                    <StoredMatches<T>>::remove(&p, &job_id);
+                    // processors that acknowledged but did not finalize their slot are slashed for all executions not reported
+                    if let Some(assignment) = <StoredMatches<T>>::take(&p, &job_id) {
+                        Self::release_collateral(&p, &requirements);
+                        if assignment.acknowledged {
+                            Self::slash_collateral(
+                                &job_id,
+                                &p,
+                                &requirements,
+                                assignment.sla.total - assignment.sla.met,
+                            )?;
+                        }
+                    }
 
                     // increase capacity
                     <StoredStorageCapacity<T>>::mutate(&p, |c| {
@@ -1475,12 +2563,33 @@ pub mod pallet {
                     <T as pallet_acurast::Config>::MaxSlots::get(),
                     None,
                 );
+                let _ = <UpstreamFulfillments<T>>::clear_prefix(
+                    &job_id,
+                    <T as pallet_acurast::Config>::MaxSlots::get(),
+                    None,
+                );
+                let _ = <SettledExecutionReports<T>>::clear_prefix(
+                    &job_id,
+                    <T as pallet_acurast::Config>::MaxSlots::get(),
+                    None,
+                );
+                let _ = <RejectedExecutions<T>>::clear_prefix(
+                    &job_id,
+                    <T as pallet_acurast::Config>::MaxSlots::get(),
+                    None,
+                );
+
+                // a recurring job is re-opened with the next window instead of being removed
+                if Self::renew_job(&job_id, registration)? {
+                    continue;
+                }
 
                 T::MarketplaceHooks::finalize_job(&job_id, T::RewardManager::refund(&job_id)?)?;
+                Self::remove_renewal(&job_id)?;
+                Self::remove_pipeline(&job_id);
 
-                pallet_acurast::Pallet::<T>::clear_environment_for(&job_id);
                 <StoredJobStatus<T>>::remove(&job_id.0, &job_id.1);
-                <StoredJobRegistration<T>>::remove(&job_id.0, &job_id.1);
+                pallet_acurast::Pallet::<T>::remove_registration_for(&job_id);
 
                 Self::deposit_event(Event::JobFinalized(job_id.clone()));
             }
@@ -1488,6 +2597,593 @@ pub mod pallet {
             Ok(().into())
         }
 
+        /// Disputes a successful execution reported by `source`.
+        ///
+        /// It assumes the caller was already authorized and is intended to be used from
+        /// * The [`Self::dispute_execution`] extrinsic of this pallet
+        /// * An inter-chain communication protocol like Hyperdrive
+        ///
+        /// The execution is identified by the 0-based `execution_index` of the source's reports for the job and has to match
+        /// the reported `operation_hash`. It can only be disputed within [`Config::DisputeWindow`] after it was reported and
+        /// its fee stays escrowed until the dispute is resolved, either by [`Config::DisputeResolutionOrigin`] or by the
+        /// optional `verifier`, another source assigned to the job, reporting its re-execution. Disputes not resolved within
+        /// [`Config::DisputeTimeout`] are upheld when the source's reports get settled.
+        pub fn dispute_execution_for(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            execution_index: u64,
+            operation_hash: &ExecutionOperationHash,
+            verifier: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let now = Self::now()?;
+
+            <StoredExecutionReports<T>>::try_mutate(
+                job_id,
+                (source.clone(), execution_index),
+                |r| -> Result<(), Error<T>> {
+                    let report = r.as_mut().ok_or(Error::<T>::ExecutionReportNotFound)?;
+                    ensure!(
+                        &report.operation_hash == operation_hash,
+                        Error::<T>::OperationHashMismatch
+                    );
+                    ensure!(
+                        report.dispute.is_none(),
+                        Error::<T>::ExecutionAlreadyDisputed
+                    );
+                    ensure!(
+                        now < report.reported_at.saturating_add(T::DisputeWindow::get()),
+                        Error::<T>::DisputeWindowPassed
+                    );
+                    if let Some(verifier) = verifier.as_ref() {
+                        ensure!(
+                            verifier != source
+                                && <AssignedProcessors<T>>::contains_key(job_id, verifier),
+                            Error::<T>::InvalidDisputeVerifier
+                        );
+                    }
+
+                    report.dispute = Some(Dispute {
+                        verifier,
+                        disputed_at: now,
+                    });
+                    Ok(())
+                },
+            )?;
+
+            Self::deposit_event(Event::ExecutionDisputed(
+                job_id.clone(),
+                source.clone(),
+                execution_index,
+            ));
+
+            Ok(().into())
+        }
+
+        /// Resolves a disputed execution.
+        ///
+        /// It assumes the caller was already authorized and is intended to be used from
+        /// * The [`Self::resolve_dispute`] extrinsic of this pallet
+        /// * The [`Self::report_re_execution`] extrinsic of this pallet
+        ///
+        /// If the execution is `upheld`, the escrowed fee is paid to the source. Otherwise the fee remains in the job's budget to be
+        /// refunded to the job's creator and the source is penalized as for a missed execution by lowering its reputation and
+        /// slashing its collateral. A rejected execution still counts as reported in the source's SLA, but is recorded in
+        /// [`RejectedExecutions`] to not be accounted as met when the source's reputation gets updated on finalization.
+        pub fn process_dispute_resolution(
+            job_id: &JobId<T::AccountId>,
+            source: &T::AccountId,
+            execution_index: u64,
+            upheld: bool,
+        ) -> Result<(), DispatchError> {
+            let report =
+                <StoredExecutionReports<T>>::get(job_id, (source.clone(), execution_index))
+                    .ok_or(Error::<T>::ExecutionReportNotFound)?;
+            ensure!(report.dispute.is_some(), Error::<T>::ExecutionNotDisputed);
+            <StoredExecutionReports<T>>::remove(job_id, (source.clone(), execution_index));
+
+            if upheld {
+                Self::pay_execution_fee(job_id, source, report.fee)?;
+            } else {
+                let registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
+                    .ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+
+                Self::penalize_unmet(source, report.fee, 1)?;
+                <RejectedExecutions<T>>::mutate(job_id, source, |rejected| *rejected += 1);
+                let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+                let requirements: JobRequirementsFor<T> = e.into();
+                Self::slash_collateral(job_id, source, &requirements, 1)?;
+            }
+
+            Self::deposit_event(Event::DisputeResolved(
+                job_id.clone(),
+                source.clone(),
+                execution_index,
+                upheld,
+            ));
+
+            Ok(())
+        }
+
+        /// Triggers an execution of an on-demand job.
+        ///
+        /// It assumes the caller was already authorized and is intended to be used from
+        /// * The [`Self::trigger_execution`] extrinsic of this pallet
+        /// * Other pallets through [`ExecutionTrigger`], e.g. when an inter-chain message arrives
+        ///
+        /// Every processor currently assigned to a slot is expected to report one more execution started at or after
+        /// the trigger. The reward for these executions is locked from the job creator instead of upfront on registration.
+        pub fn trigger_execution_for(job_id: &JobId<T::AccountId>) -> DispatchResultWithPostInfo {
+            let registration = <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+            ensure!(
+                registration.schedule.is_on_demand(),
+                Error::<T>::JobNotOnDemand
+            );
+
+            let job_status = <StoredJobStatus<T>>::get(&job_id.0, &job_id.1)
+                .ok_or(Error::<T>::JobStatusNotFound)?;
+            ensure!(
+                matches!(job_status, JobStatus::Matched | JobStatus::Assigned(_)),
+                Error::<T>::CannotTriggerWhenNotMatched
+            );
+
+            let now = Self::now()?;
+            ensure!(
+                registration.schedule.start_time <= now && now < registration.schedule.end_time,
+                Error::<T>::TriggerOutsideSchedule
+            );
+
+            let mut triggered: u128 = 0;
+            for (processor, _) in <AssignedProcessors<T>>::iter_prefix(job_id) {
+                <StoredMatches<T>>::try_mutate(&processor, job_id, |a| -> Result<(), Error<T>> {
+                    let assignment = a.as_mut().ok_or(Error::<T>::JobNotAssigned)?;
+                    ensure!(
+                        assignment.sla.total < MAX_EXECUTIONS_PER_JOB,
+                        Error::<T>::JobRegistrationScheduleExceedsMaximumExecutions
+                    );
+                    assignment.sla.total += 1;
+                    Ok(())
+                })?;
+                triggered += 1;
+            }
+            ensure!(triggered > 0, Error::<T>::CannotTriggerWhenNotMatched);
+
+            let e: <T as Config>::RegistrationExtra = registration.extra.into();
+            let requirements: JobRequirementsFor<T> = e.into();
+            let reward = requirements
+                .reward
+                .checked_mul(&triggered.into())
+                .ok_or(Error::<T>::CalculationOverflow)?;
+            // lock only after all other steps succeeded without errors because locking reward is not revertable
+            T::RewardManager::lock_reward(job_id, reward)?;
+
+            Self::deposit_event(Event::ExecutionTriggered(job_id.clone(), now));
+            Ok(().into())
+        }
+
+        /// Triggers the downstream jobs of the job fulfilled by `source` with `script`.
+        ///
+        /// It assumes the fulfillment was submitted by `source` and is intended to be used from the [`OnFulfillment`] handler
+        /// configured for `pallet_acurast_fulfillment_receiver`.
+        ///
+        /// The fulfillment is accounted to exactly one job with `script` that `source` acknowledged and still expects
+        /// executions from `source`, i.e. with less fulfillments than executions. If several jobs qualify, the one starting
+        /// earliest for `source` is fulfilled. The accepted fulfillment triggers an execution of all downstream jobs of
+        /// that job as described in [`Self::trigger_execution_for`], locking the reward from each stage's own budget.
+        /// A downstream job that cannot be triggered, e.g. because it is not matched yet, is skipped without affecting the
+        /// other stages.
+        ///
+        /// Fails with [`DispatchError::BadOrigin`] if no fulfillment was accepted.
+        pub fn fulfill_upstream_for(
+            source: &T::AccountId,
+            script: &Script,
+        ) -> DispatchResultWithPostInfo {
+            let mut fulfilled: Option<(u64, JobId<T::AccountId>, u64)> = None;
+            for (job_id, assignment) in <StoredMatches<T>>::iter_prefix(source) {
+                if !assignment.acknowledged {
+                    continue;
+                }
+                let start = match <StoredJobRegistration<T>>::get(&job_id.0, &job_id.1) {
+                    Some(registration) if &registration.script == script => registration
+                        .schedule
+                        .start_time
+                        .saturating_add(assignment.start_delay),
+                    _ => continue,
+                };
+                let fulfillments = <UpstreamFulfillments<T>>::get(&job_id, source);
+                if fulfillments >= assignment.sla.total {
+                    continue;
+                }
+                if fulfilled
+                    .as_ref()
+                    .map_or(true, |(earliest, _, _)| start < *earliest)
+                {
+                    fulfilled = Some((start, job_id, fulfillments));
+                }
+            }
+            let (_, job_id, fulfillments) = fulfilled.ok_or(DispatchError::BadOrigin)?;
+            <UpstreamFulfillments<T>>::insert(&job_id, source, fulfillments + 1);
+
+            for downstream in <JobDownstreams<T>>::iter_key_prefix(&job_id) {
+                // ignore failed triggers, the stage's creator can still trigger it manually
+                let _ = with_storage_layer(|| Self::trigger_execution_for(&downstream));
+            }
+
+            Ok(().into())
+        }
+
+        /// Ensures that `upstream` does not depend on `job_id` and has at most [MAX_PIPELINE_DEPTH] - 1 stages upstream.
+        fn ensure_acyclic(
+            job_id: &JobId<T::AccountId>,
+            upstream: &JobId<T::AccountId>,
+        ) -> Result<(), Error<T>> {
+            let mut stage = upstream.clone();
+            for _ in 0..MAX_PIPELINE_DEPTH {
+                ensure!(&stage != job_id, Error::<T>::PipelineCycle);
+                stage = match <JobUpstreams<T>>::get(&stage) {
+                    Some(stage) => stage,
+                    None => return Ok(()),
+                };
+            }
+            Err(Error::<T>::PipelineTooDeep)
+        }
+
+        /// Removes the job from the pipeline of its upstream job.
+        fn remove_upstream(job_id: &JobId<T::AccountId>) {
+            if let Some(upstream) = <JobUpstreams<T>>::take(job_id) {
+                <JobDownstreams<T>>::remove(&upstream, job_id);
+            }
+        }
+
+        /// Removes the job from the pipeline of its upstream job and unlinks all its downstream jobs.
+        fn remove_pipeline(job_id: &JobId<T::AccountId>) {
+            Self::remove_upstream(job_id);
+            let downstreams = <JobDownstreams<T>>::drain_prefix(job_id).collect::<Vec<_>>();
+            for (downstream, _) in downstreams {
+                <JobUpstreams<T>>::remove(&downstream);
+            }
+        }
+
+        /// Re-opens a recurring job with the next window of its schedule if its [`JobRenewal`] allows another renewal.
+        ///
+        /// The job's budget is topped up from the renewal's deposit to cover the next window, reusing the budget left over
+        /// from the previous window except for the collateral slashed in it, which is refunded to the job's creator. The
+        /// processors that finalized their slot in the previous window get matched again if they still fit, otherwise the
+        /// job stays open for matching. Only the processors matched again keep their execution environments.
+        ///
+        /// Returns `false` if the job has no renewal, the renewals are used up or the deposit does not cover the next window.
+        fn renew_job(
+            job_id: &JobId<T::AccountId>,
+            registration: JobRegistrationFor<T>,
+        ) -> Result<bool, DispatchError> {
+            let mut renewal = match <JobRenewals<T>>::get(job_id) {
+                Some(renewal) => renewal,
+                None => return Ok(false),
+            };
+            let policy = match renewal.policy {
+                RenewalPolicy::Times(0) => return Ok(false),
+                RenewalPolicy::Times(times) => RenewalPolicy::Times(times - 1),
+                RenewalPolicy::UntilBudgetExhausted => RenewalPolicy::UntilBudgetExhausted,
+            };
+
+            let mut renewed = registration;
+            renewed.schedule = Self::next_schedule(&renewed.schedule)?;
+
+            let reserved = Self::reserved(job_id);
+            let slashed_collateral = renewal.slashed_collateral.min(reserved);
+            let top_up = Self::total_reward_amount(&renewed)?
+                .saturating_sub(reserved.saturating_sub(slashed_collateral));
+            if top_up > renewal.deposit {
+                return Ok(false);
+            }
+            if slashed_collateral > 0u8.into() {
+                // the slashed collateral is held on the pallet account like the deposit
+                Self::unreserve(job_id, slashed_collateral).map_err(|_| {
+                    DispatchError::Other("Severe Error: JobBudget::unreserve failed")
+                })?;
+                T::RewardManager::refund_deposit(job_id, slashed_collateral)?;
+            }
+            T::RewardManager::top_up(job_id, top_up)?;
+
+            renewal.policy = policy;
+            renewal.deposit = renewal.deposit.saturating_sub(top_up);
+            renewal.slashed_collateral = 0u8.into();
+            let previous_sources = sp_std::mem::take(&mut renewal.previous_sources);
+            <JobRenewals<T>>::insert(job_id, renewal);
+
+            <StoredJobRegistration<T>>::insert(&job_id.0, &job_id.1, &renewed);
+            <StoredJobStatus<T>>::insert(&job_id.0, &job_id.1, JobStatus::Open);
+            Self::deposit_event(Event::JobRenewed(job_id.clone(), renewed.schedule));
+
+            if !previous_sources.is_empty() {
+                // ignore remaining rewards and failed matches, the job stays open for matching in the latter case
+                let _ = with_storage_layer(|| {
+                    Self::process_matching(once(&Match {
+                        job_id: job_id.clone(),
+                        sources: previous_sources,
+                    }))
+                });
+            }
+
+            // the environments of processors matched again are kept since they continue to execute the job
+            let sources = <ExecutionEnvironment<T>>::iter_key_prefix(job_id).collect::<Vec<_>>();
+            for source in sources {
+                if !<AssignedProcessors<T>>::contains_key(job_id, &source) {
+                    <ExecutionEnvironment<T>>::remove(job_id, &source);
+                }
+            }
+
+            Ok(true)
+        }
+
+        /// Calculates the next window of a recurring job's schedule.
+        ///
+        /// The next window starts after the last execution of `schedule`, skipping windows that already started.
+        fn next_schedule(schedule: &Schedule) -> Result<Schedule, Error<T>> {
+            let period = schedule.period().ok_or(Error::<T>::CalculationOverflow)?;
+            let mut offset = period;
+
+            let now = Self::now()?;
+            let start_time = schedule
+                .start_time
+                .checked_add(offset)
+                .ok_or(Error::<T>::CalculationOverflow)?;
+            if start_time <= now {
+                let skipped = (now - start_time) / period + 1;
+                offset = skipped
+                    .checked_add(1)
+                    .and_then(|periods| periods.checked_mul(period))
+                    .ok_or(Error::<T>::CalculationOverflow)?;
+            }
+
+            schedule
+                .shift(offset)
+                .ok_or(Error::<T>::CalculationOverflow)
+        }
+
+        /// Removes the renewal of a job and refunds its remaining deposit.
+        fn remove_renewal(
+            job_id: &JobId<T::AccountId>,
+        ) -> Result<Option<JobRenewalFor<T>>, DispatchError> {
+            let renewal = <JobRenewals<T>>::take(job_id);
+            if let Some(renewal) = &renewal {
+                T::RewardManager::refund_deposit(job_id, renewal.deposit)?;
+            }
+            Ok(renewal)
+        }
+
+        /// Proposes matches for open jobs in a [Call::propose_matching] transaction, if this node has a [MatcherConfig] and
+        /// [Config::MatchSubmitter] is able to submit, e.g. finds a matcher key in the keystore.
+        ///
+        /// At most one transaction is submitted every [MATCHER_SUBMISSION_INTERVAL] blocks, also when offchain workers of
+        /// several blocks run concurrently.
+        fn run_matcher(block_number: BlockNumberFor<T>) {
+            let config = match StorageValueRef::persistent(MATCHER_CONFIG_STORAGE_KEY)
+                .get::<MatcherConfig>()
+            {
+                Ok(Some(config)) => config,
+                Ok(None) => return,
+                Err(e) => {
+                    log::error!(
+                        target: "runtime::acurast_marketplace",
+                        "Failed to decode matcher config: {:?}",
+                        e,
+                    );
+                    return;
+                }
+            };
+
+            if !T::MatchSubmitter::can_submit() {
+                log::warn!(
+                    target: "runtime::acurast_marketplace",
+                    "Matcher configured but unable to submit matches, e.g. no matcher key in the keystore",
+                );
+                return;
+            }
+
+            // revert the processed matches instead of relying on offchain worker changes to be discarded
+            let matches = match with_transaction(|| {
+                TransactionOutcome::Rollback(Self::find_matches(&config))
+            }) {
+                Ok(matches) => matches,
+                Err(e) => {
+                    log::error!(
+                        target: "runtime::acurast_marketplace",
+                        "Failed to find matches: {:?}",
+                        e,
+                    );
+                    return;
+                }
+            };
+            if matches.is_empty() {
+                return;
+            }
+
+            // claim the submission for this block, failing if another worker submitted recently or concurrently
+            let claimed = StorageValueRef::persistent(MATCHER_LAST_SUBMISSION_STORAGE_KEY).mutate(
+                |last_submission: Result<Option<BlockNumberFor<T>>, _>| match last_submission {
+                    Ok(Some(last_submission))
+                        if block_number
+                            < last_submission
+                                .saturating_add(MATCHER_SUBMISSION_INTERVAL.into()) =>
+                    {
+                        Err(())
+                    }
+                    _ => Ok(block_number),
+                },
+            );
+            if claimed.is_err() {
+                log::debug!(
+                    target: "runtime::acurast_marketplace",
+                    "Skipped proposing matches, submitted recently",
+                );
+                return;
+            }
+
+            let len = matches.len();
+            match T::MatchSubmitter::submit(matches) {
+                Ok(()) => log::debug!(
+                    target: "runtime::acurast_marketplace",
+                    "Proposed {} matches",
+                    len,
+                ),
+                Err(()) => log::error!(
+                    target: "runtime::acurast_marketplace",
+                    "Failed to submit proposed matches",
+                ),
+            }
+        }
+
+        /// Finds matches for up to [MatcherConfig::max_matches] open or partially matched jobs, starting with the jobs starting first.
+        ///
+        /// Every match found is processed right away, so that it is considered when matching the following jobs. The
+        /// caller is expected to revert the changes to storage.
+        fn find_matches(
+            config: &MatcherConfig,
+        ) -> Result<BoundedVec<MatchFor<T>, <T as Config>::MaxProposedMatches>, DispatchError>
+        {
+            let max_matches = config
+                .max_matches
+                .min(<T as Config>::MaxProposedMatches::get());
+            let now = Self::now()?;
+            let latest_seen_after = config
+                .max_last_seen_age
+                .map(|age| now.saturating_sub(age).into());
+            let sources = <StoredAdvertisementRestriction<T>>::iter_keys().collect::<Vec<_>>();
+
+            let mut open_jobs = <StoredJobStatus<T>>::iter()
+                .filter_map(|(origin, job_id, status)| {
+                    let filled = match status {
+                        JobStatus::Open => 0,
+                        JobStatus::PartiallyMatched { filled, .. } => filled,
+                        _ => return None,
+                    };
+                    let registration = <StoredJobRegistration<T>>::get(&origin, job_id)?;
+                    Some(((origin, job_id), registration, filled))
+                })
+                .filter(|(_, registration, _)| now < registration.schedule.start_time)
+                .collect::<Vec<_>>();
+            open_jobs.sort_by_key(|(_, registration, _)| registration.schedule.start_time);
+
+            let mut matches = Vec::<MatchFor<T>>::new();
+            for (job_id, registration, filled) in open_jobs {
+                if matches.len() as u32 >= max_matches {
+                    break;
+                }
+                let m = match Self::find_match(
+                    job_id,
+                    &registration,
+                    filled,
+                    sources.clone(),
+                    latest_seen_after,
+                    now,
+                ) {
+                    Some(m) => m,
+                    None => continue,
+                };
+                if with_storage_layer(|| Self::process_matching(once(&m))).is_ok() {
+                    matches.push(m);
+                }
+            }
+
+            Ok(BoundedVec::truncate_from(matches))
+        }
+
+        /// Finds the cheapest of the `sources` for the free slots of a job with `filled` slots, each with the smallest start
+        /// delay fitting the source's schedule. Returns [None] if no slot can be filled.
+        fn find_match(
+            job_id: JobId<T::AccountId>,
+            registration: &JobRegistrationFor<T>,
+            filled: u8,
+            sources: Vec<T::AccountId>,
+            latest_seen_after: Option<u128>,
+            now: u64,
+        ) -> Option<MatchFor<T>> {
+            let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+            let requirements: JobRequirementsFor<T> = e.into();
+            let schedule = &registration.schedule;
+            let free_slots = requirements.slots.saturating_sub(filled) as usize;
+
+            // the schedule is checked below for every start delay tried
+            let partial_registration = PartialJobRegistration {
+                allowed_sources: registration.allowed_sources.clone(),
+                allow_only_verified_sources: registration.allow_only_verified_sources,
+                schedule: None,
+                memory: Some(registration.memory),
+                network_requests: Some(registration.network_requests),
+                storage: Some(registration.storage),
+                required_modules: registration.required_modules.clone(),
+                slots: Some(requirements.slots),
+                reward: requirements.reward,
+                min_reputation: requirements.min_reputation,
+                min_collateral: requirements.min_collateral,
+            };
+            let sources = Self::filter_matching_sources(
+                partial_registration,
+                sources,
+                Some(job_id.0.clone()),
+                latest_seen_after,
+            )
+            .ok()?;
+
+            let mut candidates = sources
+                .into_iter()
+                // sources already assigned to a slot of a partially matched job can't fill another one
+                .filter(|source| !<StoredMatches<T>>::contains_key(source, &job_id))
+                .filter_map(|source| {
+                    let ad = <StoredAdvertisementRestriction<T>>::get(&source)?;
+                    let pricing = <StoredAdvertisementPricing<T>>::get(&source)?;
+                    Self::check_network_request_quota_sufficient(
+                        &ad,
+                        schedule,
+                        registration.network_requests,
+                    )
+                    .ok()?;
+                    let fee_per_execution =
+                        Self::fee_per_execution(schedule, registration.storage, &pricing).ok()?;
+                    if fee_per_execution > requirements.reward {
+                        return None;
+                    }
+                    Some((fee_per_execution, source, pricing.scheduling_window))
+                })
+                .collect::<Vec<_>>();
+            candidates.sort_by(|(fee, _, _), (other_fee, _, _)| fee.cmp(other_fee));
+
+            let start_delays = (0..=MATCHER_START_DELAY_STEPS)
+                .map(|step| {
+                    (schedule.max_start_delay as u128 * step as u128
+                        / MATCHER_START_DELAY_STEPS as u128) as u64
+                })
+                .dedup()
+                .collect::<Vec<_>>();
+
+            let mut planned_executions = Vec::<PlannedExecution<T::AccountId>>::new();
+            for (_, source, scheduling_window) in candidates {
+                if planned_executions.len() >= free_slots {
+                    break;
+                }
+                let start_delay = start_delays.iter().copied().find(|start_delay| {
+                    Self::check_scheduling_window(&scheduling_window, schedule, now, *start_delay)
+                        .is_ok()
+                        && Self::fits_schedule(&source, schedule, *start_delay).is_ok()
+                });
+                if let Some(start_delay) = start_delay {
+                    planned_executions.push(PlannedExecution {
+                        source,
+                        start_delay,
+                    });
+                }
+            }
+            if planned_executions.is_empty() {
+                return None;
+            }
+
+            Some(Match {
+                job_id,
+                sources: planned_executions.try_into().ok()?,
+            })
+        }
+
         /// Returns the current timestamp.
         pub fn now() -> Result<u64, Error<T>> {
             Ok(<T as pallet_acurast::Config>::UnixTime::now()
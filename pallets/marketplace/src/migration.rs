@@ -1,10 +1,12 @@
 #![allow(deprecated)]
 
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
 use frame_support::{
     traits::{GetStorageVersion, StorageVersion},
     weights::Weight,
 };
-use pallet_acurast::JobModules;
+use pallet_acurast::{JobModules, RegistrationExtraMigration};
 use sp_core::Get;
 
 use super::*;
@@ -28,7 +30,31 @@ pub mod v1 {
     }
 }
 
+pub mod v4 {
+    use frame_support::pallet_prelude::*;
+    use pallet_acurast::ParameterBound;
+
+    use crate::PlannedExecutions;
+
+    /// The job requirements before [crate::JobRequirements::min_slots] and [crate::JobRequirements::min_collateral] were added.
+    #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
+    pub struct JobRequirements<Reward, AccountId, MaxSlots: ParameterBound> {
+        pub slots: u8,
+        pub reward: Reward,
+        pub min_reputation: Option<u128>,
+        pub instant_match: Option<PlannedExecutions<AccountId, MaxSlots>>,
+    }
+
+    pub type JobRequirementsFor<T> = JobRequirements<
+        <T as crate::Config>::Balance,
+        <T as frame_system::Config>::AccountId,
+        <T as pallet_acurast::Config>::MaxSlots,
+    >;
+}
+
 pub fn migrate<T: Config>() -> Weight {
+    // versions 5 and 6 only changed the job requirements, which are migrated along with the registrations by
+    // pallet_acurast, see [MigrateJobRequirements]
     let migrations: [(u16, &dyn Fn() -> Weight); 3] = [
         (2, &migrate_to_v2::<T>),
         (3, &migrate_to_v3::<T>),
@@ -81,3 +107,33 @@ fn migrate_to_v4<T: Config>() -> Weight {
     // clear again all storages since we want to clear at the same time as pallet acurast for consistent state
     migrate_to_v3::<T>()
 }
+
+/// Adds [JobRequirements::min_slots] and [JobRequirements::min_collateral] to the requirements of job registrations stored
+/// before storage version 11 of pallet_acurast, to be configured as its [pallet_acurast::Config::RegistrationExtraMigration].
+///
+/// Existing jobs have to fill all their slots to get matched and do not require any collateral.
+///
+/// The registration extra is configured by the runtime and only known to convert into [JobRequirements], so it is
+/// decoded from the encoded requirements. This holds for [JobRequirements] and [RegistrationExtra].
+pub struct MigrateJobRequirements<T>(PhantomData<T>);
+
+impl<T: Config> RegistrationExtraMigration<T> for MigrateJobRequirements<T> {
+    type OldExtra = v4::JobRequirementsFor<T>;
+
+    fn migrate(
+        requirements: Self::OldExtra,
+    ) -> Option<<T as pallet_acurast::Config>::RegistrationExtra> {
+        let requirements: JobRequirementsFor<T> = JobRequirements {
+            slots: requirements.slots,
+            min_slots: None,
+            reward: requirements.reward,
+            min_reputation: requirements.min_reputation,
+            min_collateral: None,
+            instant_match: requirements.instant_match,
+        };
+        <T as pallet_acurast::Config>::RegistrationExtra::decode(
+            &mut requirements.encode().as_slice(),
+        )
+        .ok()
+    }
+}
@@ -1,9 +1,12 @@
 use frame_support::{pallet_prelude::GenesisBuild, parameter_types, traits::Everything, PalletId};
 use sp_core::*;
 use sp_io;
-use sp_runtime::traits::{AccountIdConversion, AccountIdLookup, BlakeTwo256};
-use sp_runtime::DispatchError;
-use sp_runtime::{generic, Percent};
+use sp_runtime::traits::{
+    AccountIdConversion, AccountIdLookup, BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount,
+    Verify,
+};
+use sp_runtime::{generic, testing::TestXt, Percent};
+use sp_runtime::{DispatchError, MultiSignature};
 use sp_std::prelude::*;
 
 use pallet_acurast::{
@@ -15,6 +18,7 @@ use crate::*;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
 
 pub struct Barrier;
 
@@ -75,6 +79,12 @@ impl ExtBuilder {
         .assimilate_storage(&mut t)
         .unwrap();
 
+        <pallet_acurast::GenesisConfig<Test> as GenesisBuild<Test>>::assimilate_storage(
+            &Default::default(),
+            &mut t,
+        )
+        .unwrap();
+
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
         ext
@@ -116,6 +126,16 @@ parameter_types! {
     pub const AcurastPalletId: PalletId = PalletId(*b"acrstpid");
     pub const HyperdrivePalletId: PalletId = PalletId(*b"hypdrpid");
     pub const ReportTolerance: u64 = 12000;
+    pub const AcknowledgeDeadline: u64 = 600_000;
+    pub const CollateralSlash: Percent = Percent::from_percent(10);
+    pub const CollateralSlashTreasuryShare: Percent = Percent::from_percent(50);
+    pub const TreasuryPalletId: PalletId = PalletId(*b"acrsttry");
+    pub RevocationStatusListSigner: ed25519::Public = revocation_status_list_signer().public();
+}
+parameter_types! {
+    /// Defaults to 0 to pay fees of reported executions immediately, tests of disputes set a window.
+    pub static DisputeWindow: u64 = 0;
+    pub const DisputeTimeout: u64 = 3_600_000;
 }
 
 impl frame_system::Config for Test {
@@ -176,15 +196,28 @@ impl parachain_info::Config for Test {}
 impl pallet_acurast::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RegistrationExtra = JobRequirementsFor<Self>;
+    type RegistrationExtraMigration = crate::MigrateJobRequirements<Self>;
     type MaxAllowedSources = CU32<4>;
     type MaxCertificateRevocationListUpdates = frame_support::traits::ConstU32<10>;
+    type MaxTrustedRootCertificateUpdates = frame_support::traits::ConstU32<10>;
+    type MaxAllowedAppIdUpdates = frame_support::traits::ConstU32<10>;
+    type MaxRevocationStatusListLength = CU32<4096>;
+    type MaxRevocationStatusListEntries = frame_support::traits::ConstU32<16>;
+    type RevocationStatusListSigner = RevocationStatusListSigner;
     type MaxSlots = CU32<64>;
     type PalletId = AcurastPalletId;
     type MaxEnvVars = CU32<10>;
     type EnvKeyMaxSize = CU32<32>;
     type EnvValueMaxSize = CU32<1024>;
+    type MaxEnvironmentVersions = CU32<4>;
+    type MaxJobTemplatesPerOwner = frame_support::traits::ConstU32<2>;
     type RevocationListUpdateBarrier = Barrier;
+    type TrustedRootCertificatesUpdateOrigin = frame_system::EnsureRoot<AccountId>;
+    type AllowedAppIdsUpdateOrigin = frame_system::EnsureRoot<AccountId>;
+    type AttestationPolicyUpdateOrigin = frame_system::EnsureRoot<AccountId>;
+    type AttestationExpiryGracePeriod = frame_support::traits::ConstU64<604_800_000>;
     type KeyAttestationBarrier = ();
+    type AttestationKeyBinding = pallet_acurast::PublicKeyHashBinding;
     type UnixTime = pallet_timestamp::Pallet<Test>;
     type JobHooks = Pallet<Test>;
     type WeightInfo = pallet_acurast::weights::WeightInfo<Test>;
@@ -201,8 +234,10 @@ impl pallet_acurast::BenchmarkHelper<Test> for TestBenchmarkHelper {
     ) -> <Test as pallet_acurast::Config>::RegistrationExtra {
         JobRequirements {
             slots: 1,
+            min_slots: None,
             reward: 1,
             min_reputation: None,
+            min_collateral: None,
             instant_match: None,
         }
     }
@@ -213,6 +248,29 @@ impl pallet_acurast::BenchmarkHelper<Test> for TestBenchmarkHelper {
 
         caller
     }
+
+    fn sign_revocation_status_list(message: &[u8]) -> ed25519::Signature {
+        revocation_status_list_signer().sign(message)
+    }
+
+    fn acknowledge_environment_key(
+        job_id: &pallet_acurast::JobId<AccountId>,
+        source: &AccountId,
+        public_key: &PubKey,
+    ) {
+        <StoredMatches<Test>>::insert(
+            source,
+            job_id,
+            Assignment {
+                slot: 0,
+                start_delay: 0,
+                fee_per_execution: 1,
+                acknowledged: true,
+                sla: SLA { total: 1, met: 0 },
+                pub_keys: PubKeys::truncate_from(vec![public_key.clone()]),
+            },
+        );
+    }
 }
 
 pub struct ManagerOf;
@@ -242,16 +300,51 @@ impl Config for Test {
     type PalletId = AcurastPalletId;
     type HyperdrivePalletId = HyperdrivePalletId;
     type ReportTolerance = ReportTolerance;
+    type AcknowledgeDeadline = AcknowledgeDeadline;
+    type CollateralSlash = CollateralSlash;
+    type CollateralSlashTreasuryShare = CollateralSlashTreasuryShare;
+    type TreasuryPalletId = TreasuryPalletId;
+    type DisputeWindow = DisputeWindow;
+    type DisputeTimeout = DisputeTimeout;
+    type DisputeResolutionOrigin = frame_system::EnsureRoot<AccountId>;
     type Balance = Balance;
     type ManagerProvider = ManagerOf;
     type RewardManager = AssetRewardManager<FeeManagerImpl, Balances, Pallet<Self>>;
     type ProcessorLastSeenProvider = ProcessorLastSeenProvider;
     type MarketplaceHooks = ();
+    type MatchSubmitter = crate::crypto::SignedMatchSubmitter<Self, crate::crypto::MatcherAuthId>;
     type WeightInfo = weights::WeightInfo<Test>;
     #[cfg(feature = "runtime-benchmarks")]
     type BenchmarkHelper = TestBenchmarkHelper;
 }
 
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = <MultiSignature as Verify>::Signer;
+    type Signature = MultiSignature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type OverarchingCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: <MultiSignature as Verify>::Signer,
+        _account: AccountId,
+        nonce: u32,
+    ) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+        Some((call, (nonce.into(), ())))
+    }
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl crate::benchmarking::BenchmarkHelper<Test> for TestBenchmarkHelper {
     fn registration_extra(r: JobRequirementsFor<Test>) -> <Test as Config>::RegistrationExtra {
@@ -266,6 +359,15 @@ impl crate::benchmarking::BenchmarkHelper<Test> for TestBenchmarkHelper {
     }
 }
 
+pub fn revocation_status_list_signer() -> ed25519::Pair {
+    ed25519::Pair::from_seed(&[1; 32])
+}
+
+/// Returns the account of a matcher key.
+pub fn matcher_account_id(public: sp_core::sr25519::Public) -> AccountId {
+    <MultiSignature as Verify>::Signer::from(public).into_account()
+}
+
 pub fn events() -> Vec<RuntimeEvent> {
     let evt = System::events()
         .into_iter()
@@ -285,6 +387,10 @@ pub fn pallet_acurast_acount() -> <Test as frame_system::Config>::AccountId {
     PalletId(*b"acrstpid").into_account_truncating()
 }
 
+pub fn pallet_treasury_account() -> <Test as frame_system::Config>::AccountId {
+    TreasuryPalletId::get().into_account_truncating()
+}
+
 pub fn advertisement(
     fee_per_millisecond: u128,
     fee_per_storage_byte: u128,
@@ -304,5 +410,6 @@ pub fn advertisement(
         max_memory,
         network_request_quota,
         available_modules: JobModules::default(),
+        collateral: 0,
     }
 }
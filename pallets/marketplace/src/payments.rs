@@ -7,7 +7,7 @@ use frame_support::{
         traits::{AccountIdConversion, Get},
         DispatchError, Percent,
     },
-    traits::tokens::fungible,
+    traits::{tokens::fungible, Currency as CurrencyT, Imbalance, ReservableCurrency},
     PalletId,
 };
 use sp_runtime::SaturatedConversion;
@@ -34,6 +34,41 @@ pub trait RewardManager<T: frame_system::Config + Config> {
         matcher: &T::AccountId,
     ) -> Result<(), DispatchError>;
     fn refund(job_id: &JobId<T::AccountId>) -> Result<T::Balance, DispatchError>;
+    /// Locks a deposit for the job without adding it to the job's budget.
+    fn lock_deposit(
+        job_id: &JobId<T::AccountId>,
+        deposit: <T as Config>::Balance,
+    ) -> Result<(), DispatchError>;
+    /// Adds `amount` of a previously locked deposit to the job's budget.
+    fn top_up(
+        job_id: &JobId<T::AccountId>,
+        amount: <T as Config>::Balance,
+    ) -> Result<(), DispatchError>;
+    /// Refunds `deposit` of a previously locked deposit to the job creator.
+    fn refund_deposit(
+        job_id: &JobId<T::AccountId>,
+        deposit: <T as Config>::Balance,
+    ) -> Result<(), DispatchError>;
+    /// Locks `amount` of a processor's balance as collateral.
+    fn lock_collateral(
+        processor: &T::AccountId,
+        amount: <T as Config>::Balance,
+    ) -> Result<(), DispatchError>;
+    /// Unlocks `amount` of a processor's previously locked collateral.
+    fn unlock_collateral(
+        processor: &T::AccountId,
+        amount: <T as Config>::Balance,
+    ) -> Result<(), DispatchError>;
+    /// Slashes up to `amount` of a processor's locked collateral, transferring `treasury_share` of the slashed amount to the treasury
+    /// and adding the rest to the job's budget.
+    ///
+    /// Returns the amount actually slashed, which is less than `amount` if the processor's reserved balance does not cover it.
+    fn slash_collateral(
+        job_id: &JobId<T::AccountId>,
+        processor: &T::AccountId,
+        amount: <T as Config>::Balance,
+        treasury_share: Percent,
+    ) -> Result<<T as Config>::Balance, DispatchError>;
 }
 
 impl<T: frame_system::Config + Config> RewardManager<T> for () {
@@ -62,6 +97,50 @@ impl<T: frame_system::Config + Config> RewardManager<T> for () {
     fn refund(_job_id: &JobId<T::AccountId>) -> Result<T::Balance, DispatchError> {
         Ok(0u8.into())
     }
+
+    fn lock_deposit(
+        _job_id: &JobId<T::AccountId>,
+        _deposit: <T as Config>::Balance,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn top_up(
+        _job_id: &JobId<T::AccountId>,
+        _amount: <T as Config>::Balance,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn refund_deposit(
+        _job_id: &JobId<T::AccountId>,
+        _deposit: <T as Config>::Balance,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn lock_collateral(
+        _processor: &T::AccountId,
+        _amount: <T as Config>::Balance,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn unlock_collateral(
+        _processor: &T::AccountId,
+        _amount: <T as Config>::Balance,
+    ) -> Result<(), DispatchError> {
+        Ok(())
+    }
+
+    fn slash_collateral(
+        _job_id: &JobId<T::AccountId>,
+        _processor: &T::AccountId,
+        amount: <T as Config>::Balance,
+        _treasury_share: Percent,
+    ) -> Result<<T as Config>::Balance, DispatchError> {
+        Ok(amount)
+    }
 }
 
 // This trait provives methods for managing the fees.
@@ -93,33 +172,13 @@ impl<T, AssetSplit, Currency, Budget> RewardManager<T>
 where
     T: Config + frame_system::Config,
     AssetSplit: FeeManager,
-    Currency: fungible::Mutate<T::AccountId>,
+    Currency: fungible::Mutate<T::AccountId> + ReservableCurrency<T::AccountId>,
     <Currency as fungible::Inspect<T::AccountId>>::Balance: Member + From<T::Balance>,
+    <Currency as CurrencyT<T::AccountId>>::Balance: From<T::Balance> + Into<T::Balance>,
     Budget: JobBudget<T>,
 {
     fn lock_reward(job_id: &JobId<T::AccountId>, reward: T::Balance) -> Result<(), DispatchError> {
-        let pallet_account: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
-        let hyperdrive_pallet_account: T::AccountId =
-            <T as Config>::HyperdrivePalletId::get().into_account_truncating();
-        match &job_id.0 {
-            MultiOrigin::Acurast(who) => {
-                Currency::transfer(
-                    who,
-                    &pallet_account,
-                    reward.saturated_into(),
-                    Preservation::Preserve,
-                )?;
-            }
-            MultiOrigin::Tezos(_) | MultiOrigin::Ethereum(_) => {
-                // The availability of these funds was ensured on the target chain side
-                Currency::transfer(
-                    &hyperdrive_pallet_account,
-                    &pallet_account,
-                    reward.saturated_into(),
-                    Preservation::Preserve,
-                )?;
-            }
-        };
+        <Self as RewardManager<T>>::lock_deposit(job_id, reward)?;
 
         Budget::reserve(&job_id, reward)
             .map_err(|_| DispatchError::Other("Severe Error: JobBudget::reserve failed"))?;
@@ -147,13 +206,13 @@ where
         // Transfer fees to Acurast fees manager account
         let fee_pallet_account: T::AccountId = AssetSplit::pallet_id().into_account_truncating();
 
-        Currency::transfer(
+        <Currency as fungible::Mutate<T::AccountId>>::transfer(
             &pallet_account,
             &fee_pallet_account,
             fee.saturated_into::<<Currency as fungible::Inspect<T::AccountId>>::Balance>(),
             Preservation::Preserve,
         )?;
-        Currency::transfer(
+        <Currency as fungible::Mutate<T::AccountId>>::transfer(
             &pallet_account,
             target,
             reward_after_fee
@@ -190,13 +249,13 @@ where
         // Transfer fees to Acurast fees manager account
         let fee_pallet_account: T::AccountId = AssetSplit::pallet_id().into_account_truncating();
 
-        Currency::transfer(
+        <Currency as fungible::Mutate<T::AccountId>>::transfer(
             &pallet_account,
             &fee_pallet_account,
             fee.saturated_into::<<Currency as fungible::Inspect<T::AccountId>>::Balance>(),
             Preservation::Preserve,
         )?;
-        Currency::transfer(
+        <Currency as fungible::Mutate<T::AccountId>>::transfer(
             &pallet_account,
             matcher,
             reward_after_fee
@@ -215,7 +274,7 @@ where
             <T as Config>::HyperdrivePalletId::get().into_account_truncating();
         match &job_id.0 {
             MultiOrigin::Acurast(who) => {
-                Currency::transfer(
+                <Currency as fungible::Mutate<T::AccountId>>::transfer(
                     &pallet_account,
                     who,
                     remaining.saturated_into(),
@@ -223,7 +282,7 @@ where
                 )?;
             }
             MultiOrigin::Tezos(_) | MultiOrigin::Ethereum(_) => {
-                Currency::transfer(
+                <Currency as fungible::Mutate<T::AccountId>>::transfer(
                     &pallet_account,
                     &hyperdrive_pallet_account,
                     remaining.saturated_into(),
@@ -234,6 +293,111 @@ where
 
         Ok(remaining)
     }
+
+    fn lock_deposit(
+        job_id: &JobId<T::AccountId>,
+        deposit: T::Balance,
+    ) -> Result<(), DispatchError> {
+        let pallet_account: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+        let hyperdrive_pallet_account: T::AccountId =
+            <T as Config>::HyperdrivePalletId::get().into_account_truncating();
+        match &job_id.0 {
+            MultiOrigin::Acurast(who) => {
+                <Currency as fungible::Mutate<T::AccountId>>::transfer(
+                    who,
+                    &pallet_account,
+                    deposit.saturated_into(),
+                    Preservation::Preserve,
+                )?;
+            }
+            MultiOrigin::Tezos(_) | MultiOrigin::Ethereum(_) => {
+                // The availability of these funds was ensured on the target chain side
+                <Currency as fungible::Mutate<T::AccountId>>::transfer(
+                    &hyperdrive_pallet_account,
+                    &pallet_account,
+                    deposit.saturated_into(),
+                    Preservation::Preserve,
+                )?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn top_up(job_id: &JobId<T::AccountId>, amount: T::Balance) -> Result<(), DispatchError> {
+        // the deposit is already on the pallet account, only the job's budget is increased
+        Budget::reserve(&job_id, amount)
+            .map_err(|_| DispatchError::Other("Severe Error: JobBudget::reserve failed"))?;
+
+        Ok(())
+    }
+
+    fn refund_deposit(
+        job_id: &JobId<T::AccountId>,
+        deposit: T::Balance,
+    ) -> Result<(), DispatchError> {
+        let pallet_account: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+        let hyperdrive_pallet_account: T::AccountId =
+            <T as Config>::HyperdrivePalletId::get().into_account_truncating();
+        match &job_id.0 {
+            MultiOrigin::Acurast(who) => {
+                <Currency as fungible::Mutate<T::AccountId>>::transfer(
+                    &pallet_account,
+                    who,
+                    deposit.saturated_into(),
+                    Preservation::Preserve,
+                )?;
+            }
+            MultiOrigin::Tezos(_) | MultiOrigin::Ethereum(_) => {
+                <Currency as fungible::Mutate<T::AccountId>>::transfer(
+                    &pallet_account,
+                    &hyperdrive_pallet_account,
+                    deposit.saturated_into(),
+                    Preservation::Preserve,
+                )?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn lock_collateral(processor: &T::AccountId, amount: T::Balance) -> Result<(), DispatchError> {
+        Currency::reserve(processor, amount.into())
+    }
+
+    fn unlock_collateral(
+        processor: &T::AccountId,
+        amount: T::Balance,
+    ) -> Result<(), DispatchError> {
+        let _ = Currency::unreserve(processor, amount.into());
+
+        Ok(())
+    }
+
+    fn slash_collateral(
+        job_id: &JobId<T::AccountId>,
+        processor: &T::AccountId,
+        amount: T::Balance,
+        treasury_share: Percent,
+    ) -> Result<T::Balance, DispatchError> {
+        let pallet_account: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+        let treasury_account: T::AccountId =
+            <T as Config>::TreasuryPalletId::get().into_account_truncating();
+
+        // the treasury share is split off the amount actually slashed, which can be lower than `amount`
+        let (slashed, _) = Currency::slash_reserved(processor, amount.into());
+        let slashed_amount: T::Balance = slashed.peek().into();
+        let (to_treasury, to_budget) = slashed.split(treasury_share.mul_floor(slashed.peek()));
+        Currency::resolve_creating(&treasury_account, to_treasury);
+
+        // the rest is moved to the pallet account to get refunded as part of the job's budget
+        let to_budget_amount: T::Balance = to_budget.peek().into();
+        Currency::resolve_creating(&pallet_account, to_budget);
+        Budget::reserve(&job_id, to_budget_amount)
+            .map_err(|_| DispatchError::Other("Severe Error: JobBudget::reserve failed"))?;
+
+        Ok(slashed_amount)
+    }
 }
 
 /// Manages each job's budget by reserving/unreserving rewards that are externally strored, e.g. on a pallet account in `pallet_balances`.
@@ -3,7 +3,7 @@
 use hex_literal::hex;
 use sp_runtime::AccountId32;
 
-use pallet_acurast::{AttestationChain, Script, SerialNumber};
+use pallet_acurast::{AttestationChain, Script, SerialNumber, IPFS_SCHEME};
 
 use crate::*;
 
@@ -18,7 +18,7 @@ pub const EXISTENTIAL_DEPOSIT: Balance = MILLIUNIT;
 pub const UNIT: Balance = 1_000_000;
 pub const MILLIUNIT: Balance = UNIT / 1_000;
 pub const MICROUNIT: Balance = UNIT / 1_000_000;
-pub const SCRIPT_BYTES: [u8; 53] = hex!("697066733A2F2F00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000");
+pub const SCRIPT_BYTES: [u8; 53] = hex!("697066733A2F2F516D4E4C656937387A576D7A556462655242334369556641697A5755726265655A68354B31726841514B43683531");
 pub const SCRIPT_RANDOM_VALUE_BYTES: [u8; 53] = hex!("697066733a2f2f516d644a4e764d4c66766a7a4a6e48514a6d73454243384b554431667954757346726b5841463559615a6f755432");
 pub const OPERATION_HASH: [u8; 32] =
     hex!("a3f18e4c6f0cdd0d8666f407610351cacb9a263678cf058294be9977b69f2cb3");
@@ -37,17 +37,22 @@ pub fn operation_hash() -> ExecutionOperationHash {
 
 pub fn invalid_script_1() -> Script {
     let end = SCRIPT_BYTES.len() - 2;
-    SCRIPT_BYTES[0..end].to_vec().try_into().unwrap()
+    Script::Ipfs(
+        SCRIPT_BYTES[IPFS_SCHEME.len()..end]
+            .to_vec()
+            .try_into()
+            .unwrap(),
+    )
 }
 
 pub fn invalid_script_2() -> Script {
-    let mut bytes = SCRIPT_BYTES.to_vec();
-    bytes[0] = 0;
-    bytes.try_into().unwrap()
+    let mut bytes = SCRIPT_BYTES[IPFS_SCHEME.len()..].to_vec();
+    bytes[2] = b'0';
+    Script::Ipfs(bytes.try_into().unwrap())
 }
 
 pub fn attestation_chain() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![
             ROOT_CERT.to_vec().try_into().unwrap(),
             INT_CERT_1.to_vec().try_into().unwrap(),
@@ -60,7 +65,7 @@ pub fn attestation_chain() -> AttestationChain {
 }
 
 pub fn attestation_chain_processor_2() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![
             ROOT_CERT.to_vec().try_into().unwrap(),
             INT_CERT_1.to_vec().try_into().unwrap(),
@@ -73,7 +78,7 @@ pub fn attestation_chain_processor_2() -> AttestationChain {
 }
 
 pub fn attestation_chain_processor_3() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![
             hex!("3082056030820348a003020102020900e8fa196314d2fa18300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3136303532363136323835325a170d3236303532343136323835325a301b31193017060355040513106639323030396538353362366230343530820222300d06092a864886f70d01010105000382020f003082020a0282020100afb6c7822bb1a701ec2bb42e8bcc541663abef982f32c77f7531030c97524b1b5fe809fbc72aa9451f743cbd9a6f1335744aa55e77f6b6ac3535ee17c25e639517dd9c92e6374a53cbfe258f8ffbb6fd129378a22a4ca99c452d47a59f3201f44197ca1ccd7e762fb2f53151b6feb2fffd2b6fe4fe5bc6bd9ec34bfe08239daafceb8eb5a8ed2b3acd9c5e3a7790e1b51442793159859811ad9eb2a96bbdd7a57c93a91c41fccd27d67fd6f671aa0b815261ad384fa37944864604ddb3d8c4f920a19b1656c2f14ad6d03c56ec060899041c1ed1a5fe6d3440b556bad1d0a152589c53e55d370762f0122eef91861b1b0e6c4c80927499c0e9bec0b83e3bc1f93c72c049604bbd2f1345e62c3f8e26dbec06c94766f3c128239d4f4312fad8123887e06becf567583bf8355a81feeabaf99a83c8df3e2a322afc672bf120b135158b6821ceaf309b6eee77f98833b018daa10e451f06a374d50781f359082966bb778b9308942698e74e0bcd24628a01c2cc03e51f0b3e5b4ac1e4df9eaf9ff6a492a77c1483882885015b422ce67b80b88c9b48e13b607ab545c723ff8c44f8f2d368b9f6520d31145ebf9e862ad71df6a3bfd2450959d653740d97a12f368b13ef66d5d0a54a6e2f5d9a6fef446832bc67844725861f093dd0e6f3405da89643ef0f4d69b6420051fdb93049673e36950580d3cdf4fbd08bc58483952600630203010001a381a63081a3301d0603551d0e041604143661e1007c880509518b446c47ff1a4cc9ea4f12301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff04040302018630400603551d1f043930373035a033a031862f68747470733a2f2f616e64726f69642e676f6f676c65617069732e636f6d2f6174746573746174696f6e2f63726c2f300d06092a864886f70d01010b0500038202010020c8c38d4bdca9571b468c892fff72aac6f844a11d41a8f0736cc37d16d6426d8e7e9407044cea39e68b07c13dbf1503dd5c85bdafb2c02d5f6cdb4efa8127df8b04f182770fc4e7745b7fceaa87129a8801ce8e9bc0cb96379b4d26a82d30fd9c2f8eed6dc1be2f84b689e4d914258b144bbae624a1c70671132e2f0616a884b2a4d6a46ffa89b602bfbad80c1243711f56eb6056f637c8a0141cc54094268b8c3c7db994b35c0dcd6cb2abc2dafee252023d2dea0cd6c368bea3e6414886f6b1e58b5bd7c730b268c4e3c1fb6424b91febbdb80c586e2ae8368c84d5d10917bda2561789d4687393340e2e254f560ef64b2358fcdc0fbfc6700952e708bffcc627500c1f66e81ea17c098d7a2e9b18801b7ab4ac71587d345dcc8309d5b62a50427aa6d03dcb05996c96ba0c5d71e92162c016ca849ff35f0d52c65d05605a47f3ae917acd2df910efd2326688596ef69b3bf5fe3154f7aeb880a0a73ca04d94c2ce8317eeb43d5eff5883e336f5f249daaca4899237bf267e5c43ab02ea44162403723be6aa692c61bdae9ed409d463c4c97c64306577eef2bc7560b75715cc9c7dc67c86082db751a89c30349762b0782385875cf1a3c6166e0ae3c12d374e2d4f1846f318744bd879b587329bf018217a6c0c77241a4878e435c03079cb451289c5776206069a2f8d65f840e1445287bed877abae24e24435168d553ce4").to_vec().try_into().unwrap(),
             hex!("3082039930820181a0030201020210060d896bdc60a576a5947be0895f5989300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3230303931313138303232315a170d3330303930393138303232315a303f31123010060355040c0c095374726f6e67426f78312930270603550405132066336466313937623134316339333437633764616630333735656330663934393076301006072a8648ce3d020106052b81040022036200047246606805047a2007191896564ddc2931e0de34aa60fbd8b84ec6b544ef722a843b8fee768f2a611d7dc1785389736ff17314f67f7ec1e6484fc34b01e8493dc0c50c2af60d31c7f9b5a7f6963d5abc45ca36ba14a0b272cc6c6cf6f15ea363a3633061301d0603551d0e041604146ee611df7046d5bb346d8d2d8e06371f5271ab4d301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300d06092a864886f70d01010b050003820201003828d80663e5d9b0df41803e774fffe44e5c9ab0de9b067c9a8d569cdcef69bd880b6abbac853cb3f317dbb62a37584cb9e3ce108962a15db5531858edf57787d1dac77b675804aae8631586b08d0d758dedac0d6e331f34a1686f12e171bc040757ffd41ddd1840256e5e30d04cff0576bf27f1e60f05de5fc50a25fa9598d148db3e0a5fdcfcf92a4241129e5a8a3a0bbab94034bd75775a57f1fa2d0822e1874fd002b9856c0c53390ac221123edb25f40f50f06b3796ad92d20ff84f90cd7fb849a5784e395c4517cb630b762c6d12223acb2f80ff040c8b332856156e5d55196620cb1cfb4d4e3c50b396570f20f2c6925ad17e567246edb6a46e12077a88c59586131ab5f57b7bc8d79adef6049cd26563807f6854d977acea82068c56a1e8020570ca2a29f0730a5ac46e48844bea8b497b818c33642378d7714efbe4e23f43e80ad7dc83b7347ec25d6e3fc8af1a7462c5373deabfe2e00c13ca28a3bd65ba41070a0304c94a7883e4b4b957f8c1e6697aa17dba7c5850b5e44f02c2d741f0f5d5a76487bb2eaae58bdd957518d487fbcf6b6c6b4ed704a5f7c7079d4bb7d1d5d2c470403caf8ddd2eeccfc61a335081a5be1e5028fa01247a1ebfd4c56f461276a32ead8a3d87fc32a1b410283141ff4f0c10644e765f14c46ce3f14db5a52ed7474e8e3ee5b447eb4b4ac78e0604ca4accf5698e46591493d36ca1").to_vec().try_into().unwrap(),
@@ -86,7 +91,7 @@ pub fn attestation_chain_processor_3() -> AttestationChain {
 }
 
 pub fn attestation_chain_processor_4() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![
             hex!("3082056030820348a003020102020900e8fa196314d2fa18300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3136303532363136323835325a170d3236303532343136323835325a301b31193017060355040513106639323030396538353362366230343530820222300d06092a864886f70d01010105000382020f003082020a0282020100afb6c7822bb1a701ec2bb42e8bcc541663abef982f32c77f7531030c97524b1b5fe809fbc72aa9451f743cbd9a6f1335744aa55e77f6b6ac3535ee17c25e639517dd9c92e6374a53cbfe258f8ffbb6fd129378a22a4ca99c452d47a59f3201f44197ca1ccd7e762fb2f53151b6feb2fffd2b6fe4fe5bc6bd9ec34bfe08239daafceb8eb5a8ed2b3acd9c5e3a7790e1b51442793159859811ad9eb2a96bbdd7a57c93a91c41fccd27d67fd6f671aa0b815261ad384fa37944864604ddb3d8c4f920a19b1656c2f14ad6d03c56ec060899041c1ed1a5fe6d3440b556bad1d0a152589c53e55d370762f0122eef91861b1b0e6c4c80927499c0e9bec0b83e3bc1f93c72c049604bbd2f1345e62c3f8e26dbec06c94766f3c128239d4f4312fad8123887e06becf567583bf8355a81feeabaf99a83c8df3e2a322afc672bf120b135158b6821ceaf309b6eee77f98833b018daa10e451f06a374d50781f359082966bb778b9308942698e74e0bcd24628a01c2cc03e51f0b3e5b4ac1e4df9eaf9ff6a492a77c1483882885015b422ce67b80b88c9b48e13b607ab545c723ff8c44f8f2d368b9f6520d31145ebf9e862ad71df6a3bfd2450959d653740d97a12f368b13ef66d5d0a54a6e2f5d9a6fef446832bc67844725861f093dd0e6f3405da89643ef0f4d69b6420051fdb93049673e36950580d3cdf4fbd08bc58483952600630203010001a381a63081a3301d0603551d0e041604143661e1007c880509518b446c47ff1a4cc9ea4f12301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff04040302018630400603551d1f043930373035a033a031862f68747470733a2f2f616e64726f69642e676f6f676c65617069732e636f6d2f6174746573746174696f6e2f63726c2f300d06092a864886f70d01010b0500038202010020c8c38d4bdca9571b468c892fff72aac6f844a11d41a8f0736cc37d16d6426d8e7e9407044cea39e68b07c13dbf1503dd5c85bdafb2c02d5f6cdb4efa8127df8b04f182770fc4e7745b7fceaa87129a8801ce8e9bc0cb96379b4d26a82d30fd9c2f8eed6dc1be2f84b689e4d914258b144bbae624a1c70671132e2f0616a884b2a4d6a46ffa89b602bfbad80c1243711f56eb6056f637c8a0141cc54094268b8c3c7db994b35c0dcd6cb2abc2dafee252023d2dea0cd6c368bea3e6414886f6b1e58b5bd7c730b268c4e3c1fb6424b91febbdb80c586e2ae8368c84d5d10917bda2561789d4687393340e2e254f560ef64b2358fcdc0fbfc6700952e708bffcc627500c1f66e81ea17c098d7a2e9b18801b7ab4ac71587d345dcc8309d5b62a50427aa6d03dcb05996c96ba0c5d71e92162c016ca849ff35f0d52c65d05605a47f3ae917acd2df910efd2326688596ef69b3bf5fe3154f7aeb880a0a73ca04d94c2ce8317eeb43d5eff5883e336f5f249daaca4899237bf267e5c43ab02ea44162403723be6aa692c61bdae9ed409d463c4c97c64306577eef2bc7560b75715cc9c7dc67c86082db751a89c30349762b0782385875cf1a3c6166e0ae3c12d374e2d4f1846f318744bd879b587329bf018217a6c0c77241a4878e435c03079cb451289c5776206069a2f8d65f840e1445287bed877abae24e24435168d553ce4").to_vec().try_into().unwrap(),
             hex!("3082039930820181a0030201020210060d896bdc60a576a5947be0895f5989300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3230303931313138303232315a170d3330303930393138303232315a303f31123010060355040c0c095374726f6e67426f78312930270603550405132066336466313937623134316339333437633764616630333735656330663934393076301006072a8648ce3d020106052b81040022036200047246606805047a2007191896564ddc2931e0de34aa60fbd8b84ec6b544ef722a843b8fee768f2a611d7dc1785389736ff17314f67f7ec1e6484fc34b01e8493dc0c50c2af60d31c7f9b5a7f6963d5abc45ca36ba14a0b272cc6c6cf6f15ea363a3633061301d0603551d0e041604146ee611df7046d5bb346d8d2d8e06371f5271ab4d301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300d06092a864886f70d01010b050003820201003828d80663e5d9b0df41803e774fffe44e5c9ab0de9b067c9a8d569cdcef69bd880b6abbac853cb3f317dbb62a37584cb9e3ce108962a15db5531858edf57787d1dac77b675804aae8631586b08d0d758dedac0d6e331f34a1686f12e171bc040757ffd41ddd1840256e5e30d04cff0576bf27f1e60f05de5fc50a25fa9598d148db3e0a5fdcfcf92a4241129e5a8a3a0bbab94034bd75775a57f1fa2d0822e1874fd002b9856c0c53390ac221123edb25f40f50f06b3796ad92d20ff84f90cd7fb849a5784e395c4517cb630b762c6d12223acb2f80ff040c8b332856156e5d55196620cb1cfb4d4e3c50b396570f20f2c6925ad17e567246edb6a46e12077a88c59586131ab5f57b7bc8d79adef6049cd26563807f6854d977acea82068c56a1e8020570ca2a29f0730a5ac46e48844bea8b497b818c33642378d7714efbe4e23f43e80ad7dc83b7347ec25d6e3fc8af1a7462c5373deabfe2e00c13ca28a3bd65ba41070a0304c94a7883e4b4b957f8c1e6697aa17dba7c5850b5e44f02c2d741f0f5d5a76487bb2eaae58bdd957518d487fbcf6b6c6b4ed704a5f7c7079d4bb7d1d5d2c470403caf8ddd2eeccfc61a335081a5be1e5028fa01247a1ebfd4c56f461276a32ead8a3d87fc32a1b410283141ff4f0c10644e765f14c46ce3f14db5a52ed7474e8e3ee5b447eb4b4ac78e0604ca4accf5698e46591493d36ca1").to_vec().try_into().unwrap(),
@@ -99,7 +104,7 @@ pub fn attestation_chain_processor_4() -> AttestationChain {
 }
 
 pub fn invalid_attestation_chain_1() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![LEAF_CERT.to_vec().try_into().unwrap()]
             .try_into()
             .unwrap(),
@@ -107,7 +112,7 @@ pub fn invalid_attestation_chain_1() -> AttestationChain {
 }
 
 pub fn invalid_attestation_chain_2() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![
             INT_CERT_2.to_vec().try_into().unwrap(),
             LEAF_CERT.to_vec().try_into().unwrap(),
@@ -118,7 +123,7 @@ pub fn invalid_attestation_chain_2() -> AttestationChain {
 }
 
 pub fn invalid_attestation_chain_3() -> AttestationChain {
-    AttestationChain {
+    AttestationChain::AndroidKeyAttestation {
         certificate_chain: vec![
             ROOT_CERT.to_vec().try_into().unwrap(),
             INT_CERT_1.to_vec().try_into().unwrap(),
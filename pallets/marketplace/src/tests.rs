@@ -1,20 +1,33 @@
 #![cfg(test)]
 
-use frame_support::{assert_err, assert_ok, traits::Hooks};
-use sp_runtime::{bounded_vec, MultiAddress, Permill};
+use codec::{Decode, Encode};
+use frame_support::{
+    assert_err, assert_ok,
+    traits::{GetStorageVersion, Hooks, StorageVersion},
+    BoundedVec,
+};
+use sp_core::offchain::{
+    testing::{TestOffchainExt, TestTransactionPoolExt},
+    OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{bounded_vec, DispatchError, MultiAddress, Permill};
 
 use pallet_acurast::{
-    utils::validate_and_extract_attestation, JobModules, JobRegistrationFor, Schedule,
+    utils::validate_and_extract_attestation, CronPattern, Environment, JobId, JobModule,
+    JobModules, JobRegistrationFor, JobRegistrationUpdate, Schedule, ScheduleKind,
+    ScheduleTimestamps, Script,
 };
 use pallet_acurast::{Attestation, MultiOrigin};
 use reputation::{BetaReputation, ReputationEngine};
 
 use crate::payments::JobBudget;
 use crate::{
-    mock::*, AdvertisementRestriction, Assignment, Error, ExecutionResult, JobStatus, Match,
-    PlannedExecutions, SLA,
+    mock::*, AdvertisementFor, AdvertisementRestriction, Assignment, Error, ExecutionOperationHash,
+    ExecutionResult, JobRenewal, JobStatus, Match, MatcherConfig, PlannedExecutions, RenewalPolicy,
+    MATCHER_CONFIG_STORAGE_KEY, SLA,
 };
-use crate::{stub::*, PubKeys};
+use crate::{stub::*, PubKey, PubKeys};
 use crate::{JobRequirements, PlannedExecution};
 
 /// Job is not assigned and gets deregistered successfully.
@@ -32,6 +45,7 @@ fn test_valid_deregister() {
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -39,8 +53,10 @@ fn test_valid_deregister() {
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 1,
+            min_slots: None,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: None,
             instant_match: None,
         },
     };
@@ -144,6 +160,7 @@ fn test_deregister_on_matched_job() {
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -151,8 +168,10 @@ fn test_deregister_on_matched_job() {
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 2,
+            min_slots: None,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: None,
             instant_match: Some(bounded_vec![
                 PlannedExecution {
                     source: processor_account_id(),
@@ -291,6 +310,7 @@ fn test_deregister_on_assigned_job() {
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 0,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -298,8 +318,10 @@ fn test_deregister_on_assigned_job() {
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 2,
+            min_slots: None,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: None,
             instant_match: Some(bounded_vec![
                 PlannedExecution {
                     source: processor_account_id(),
@@ -494,6 +516,7 @@ fn test_match() {
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -501,8 +524,10 @@ fn test_match() {
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 1,
+            min_slots: None,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: None,
             instant_match: None,
         },
     };
@@ -516,6 +541,7 @@ fn test_match() {
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 10_000,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -523,8 +549,10 @@ fn test_match() {
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 1,
+            min_slots: None,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: None,
             instant_match: None,
         },
     };
@@ -870,6 +898,7 @@ fn test_multi_assignments() {
             end_time: 1_694_796_120_000,   // 15.09.2023 17:42 (2 minutes later)
             interval: 10000,               // 10 seconds
             max_start_delay: 0,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -877,8 +906,10 @@ fn test_multi_assignments() {
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 4,
+            min_slots: None,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: None,
             instant_match: None,
         },
     };
@@ -1084,250 +1115,341 @@ fn test_multi_assignments() {
 }
 
 #[test]
-fn test_no_match_schedule_overlap() {
-    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+fn test_partial_matching() {
+    let now = 1_694_790_000_000; // 15.09.2023 16:00
 
     // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
     let ad = advertisement(1000, 1, 100_000, 50_000, 8);
-    let registration1 = JobRegistrationFor::<Test> {
+    let registration = |min_slots| JobRegistrationFor::<Test> {
         script: script(),
         allowed_sources: None,
         allow_only_verified_sources: false,
         schedule: Schedule {
-            duration: 5000,
-            start_time: 1_671_800_400_000, // 23.12.2022 13:00
-            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
-            interval: 1_800_000,           // 30min -> 2 executions fit
-            max_start_delay: 5000,
+            duration: 1000,
+            start_time: 1_694_796_000_000, // 15.09.2023 17:40
+            end_time: 1_694_796_120_000,   // 15.09.2023 17:42 (2 minutes later)
+            interval: 10000,               // 10 seconds
+            max_start_delay: 0,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
         storage: 20_000u32,
         required_modules: JobModules::default(),
         extra: JobRequirements {
-            slots: 1,
+            slots: 4,
+            min_slots,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: None,
             instant_match: None,
         },
     };
-
-    let registration2 = JobRegistrationFor::<Test> {
-        script: script_random_value(),
-        allowed_sources: None,
-        allow_only_verified_sources: false,
-        schedule: Schedule {
-            duration: 5000,
-            start_time: 1_671_802_200_000, // 23.12.2022 13:30
-            end_time: 1_671_805_800_000,   // 23.12.2022 14:30 (one hour later)
-            interval: 1_200_000,           // 20min -> 3 executions fit
-            max_start_delay: 5000,
-        },
-        memory: 5_000u32,
-        network_requests: 5,
-        storage: 20_000u32,
-        required_modules: JobModules::default(),
-        extra: JobRequirements {
-            slots: 1,
-            reward: 3_000_000 * 2,
-            min_reputation: None,
-            instant_match: None,
-        },
+    let m = |job_id: &JobId<AccountId>, sources: Vec<AccountId>| Match {
+        job_id: job_id.clone(),
+        sources: sources
+            .into_iter()
+            .map(|source| PlannedExecution {
+                source,
+                start_delay: 0,
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
     };
 
     ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            1000_000_000,
+        );
+
         let initial_job_id = Acurast::job_id_sequence();
-        let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
-        let job_id2 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 2);
 
         // pretend current time
-        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
-        assert_ok!(AcurastMarketplace::advertise(
-            RuntimeOrigin::signed(processor_account_id()).into(),
-            ad.clone(),
-        ));
+        later(now);
 
-        // register first job
+        for processor in [
+            processor_account_id(),
+            processor_2_account_id(),
+            processor_3_account_id(),
+            processor_4_account_id(),
+        ] {
+            assert_ok!(AcurastMarketplace::advertise(
+                RuntimeOrigin::signed(processor).into(),
+                ad.clone(),
+            ));
+        }
+
+        // the quorum can't exceed the number of slots
+        assert_err!(
+            Acurast::register(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                registration(Some(5)),
+            ),
+            Error::<Test>::JobRegistrationInvalidMinSlots
+        );
+
+        let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
         assert_ok!(Acurast::register(
             RuntimeOrigin::signed(alice_account_id()).into(),
-            registration1.clone(),
+            registration(Some(3)),
+        ));
+        assert_eq!(288_000_000, AcurastMarketplace::reserved(&job_id1));
+
+        // a first match fills a single slot
+        let m1 = m(&job_id1, vec![processor_account_id()]);
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m1.clone()].try_into().unwrap(),
         ));
         assert_eq!(
-            Some(JobStatus::Open),
+            Some(JobStatus::PartiallyMatched {
+                filled: 1,
+                total: 4
+            }),
             AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
         );
+        // matcher got rewarded for the filled slot only
+        assert_eq!(282_024_000, AcurastMarketplace::reserved(&job_id1));
+        assert_eq!(None, AcurastMarketplace::total_assigned());
 
-        // register second job
-        assert_ok!(Acurast::register(
-            RuntimeOrigin::signed(alice_account_id()).into(),
-            registration2.clone(),
+        // the job can't be acknowledged before the quorum is reached
+        assert_err!(
+            AcurastMarketplace::acknowledge_match(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                job_id1.clone(),
+                PubKeys::default(),
+            ),
+            Error::<Test>::CannotAcknowledgeWhenNotMatched
+        );
+
+        // a source can't fill a second slot of the same job
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![m(&job_id1, vec![processor_account_id()])]
+                    .try_into()
+                    .unwrap(),
+            ),
+            Error::<Test>::DuplicateSourceInMatch
+        );
+
+        // more sources than free slots
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![m(
+                    &job_id1,
+                    vec![
+                        processor_2_account_id(),
+                        processor_3_account_id(),
+                        processor_4_account_id(),
+                        charlie_account_id(),
+                    ]
+                )]
+                .try_into()
+                .unwrap(),
+            ),
+            Error::<Test>::IncorrectSourceCountInMatch
+        );
+
+        // a second match reaches the quorum
+        let m2 = m(
+            &job_id1,
+            vec![processor_2_account_id(), processor_3_account_id()],
+        );
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m2.clone()].try_into().unwrap(),
         ));
         assert_eq!(
-            Some(JobStatus::Open),
-            AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1 + 1)
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
         );
+        assert_eq!(270_072_000, AcurastMarketplace::reserved(&job_id1));
+        assert_eq!(Some(1), AcurastMarketplace::total_assigned());
+        for (processor, slot) in [
+            (processor_account_id(), 0),
+            (processor_2_account_id(), 1),
+            (processor_3_account_id(), 2),
+        ] {
+            assert_eq!(
+                Some(slot),
+                AcurastMarketplace::stored_matches(processor, job_id1.clone())
+                    .map(|assignment| assignment.slot),
+            );
+        }
 
-        // the first job matches because capacity left
-        let m = Match {
-            job_id: job_id1.clone(),
-            sources: bounded_vec![PlannedExecution {
-                source: processor_account_id(),
-                start_delay: 0,
-            }],
-        };
+        // the remaining slot stays empty once the job got matched
         assert_ok!(AcurastMarketplace::propose_matching(
             RuntimeOrigin::signed(charlie_account_id()).into(),
-            vec![m.clone()].try_into().unwrap(),
+            vec![m(&job_id1, vec![processor_4_account_id()])]
+                .try_into()
+                .unwrap(),
         ));
+        assert_eq!(
+            None,
+            AcurastMarketplace::stored_matches(processor_4_account_id(), job_id1.clone())
+        );
 
-        // this one does not match anymore
-        let m2 = Match {
-            job_id: job_id2.clone(),
-            sources: bounded_vec![PlannedExecution {
-                source: processor_account_id(),
-                start_delay: 0,
-            }],
-        };
-        assert_err!(
-            AcurastMarketplace::propose_matching(
-                RuntimeOrigin::signed(charlie_account_id()).into(),
-                vec![m2.clone()].try_into().unwrap(),
-            ),
-            Error::<Test>::ScheduleOverlapInMatch
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id1.clone(),
+            PubKeys::default(),
+        ));
+        assert_eq!(
+            Some(JobStatus::Assigned(1)),
+            AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
         );
 
+        let matched_events = events()
+            .into_iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    RuntimeEvent::AcurastMarketplace(
+                        crate::Event::JobRegistrationPartiallyMatched(_)
+                            | crate::Event::JobRegistrationMatched(_)
+                    )
+                )
+            })
+            .collect::<Vec<_>>();
         assert_eq!(
-            events(),
+            matched_events,
             [
-                RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStored(
-                    ad.clone(),
-                    processor_account_id()
-                )),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: alice_account_id(),
-                    to: pallet_acurast_acount(),
-                    amount: 12_000_000
-                }),
-                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
-                    registration1.clone(),
-                    job_id1.clone()
-                )),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: alice_account_id(),
-                    to: pallet_acurast_acount(),
-                    amount: 18_000_000
-                }),
-                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
-                    registration2.clone(),
-                    (job_id2.0.clone(), job_id2.1.clone())
-                )),
-                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatched(m)),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: pallet_fees_account(),
-                    amount: 58800
-                }),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: charlie_account_id(),
-                    amount: 137200
-                }),
-                // no match event for second
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationPartiallyMatched(m1)),
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatched(m2)),
             ]
         );
     });
 }
 
 #[test]
-fn test_no_match_insufficient_reputation() {
-    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+fn test_finalize_under_filled_job() {
+    let now = 1_694_790_000_000; // 15.09.2023 16:00
 
     // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
-    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
-    let registration1 = JobRegistrationFor::<Test> {
+    let ad = AdvertisementFor::<Test> {
+        collateral: 5_000_000,
+        ..advertisement(1000, 1, 100_000, 50_000, 8)
+    };
+    let registration = JobRegistrationFor::<Test> {
         script: script(),
         allowed_sources: None,
         allow_only_verified_sources: false,
         schedule: Schedule {
-            duration: 5000,
-            start_time: 1_671_800_400_000, // 23.12.2022 13:00
-            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
-            interval: 1_800_000,           // 30min -> 2 executions fit
-            max_start_delay: 5000,
+            duration: 1000,
+            start_time: 1_694_796_000_000, // 15.09.2023 17:40
+            end_time: 1_694_796_120_000,   // 15.09.2023 17:42 (2 minutes later)
+            interval: 10000,               // 10 seconds
+            max_start_delay: 0,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
         storage: 20_000u32,
         required_modules: JobModules::default(),
         extra: JobRequirements {
-            slots: 1,
+            slots: 2,
+            min_slots: None,
             reward: 3_000_000 * 2,
-            min_reputation: Some(1_000_000),
+            min_reputation: None,
+            min_collateral: Some(4_000_000),
             instant_match: None,
         },
     };
 
     ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            1000_000_000,
+        );
+
         let initial_job_id = Acurast::job_id_sequence();
         let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
 
         // pretend current time
-        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+        later(now);
+
+        // the processor is attested to get its reputation updated on finalization
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
         assert_ok!(AcurastMarketplace::advertise(
             RuntimeOrigin::signed(processor_account_id()).into(),
             ad.clone(),
         ));
-
-        // register job
         assert_ok!(Acurast::register(
             RuntimeOrigin::signed(alice_account_id()).into(),
-            registration1.clone(),
+            registration.clone(),
+        ));
+
+        // only one of the two slots gets filled
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![Match {
+                job_id: job_id.clone(),
+                sources: bounded_vec![PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                }],
+            }]
+            .try_into()
+            .unwrap(),
         ));
         assert_eq!(
-            Some(JobStatus::Open),
-            AcurastMarketplace::stored_job_status(&job_id.0, job_id.1)
+            Some(JobStatus::PartiallyMatched {
+                filled: 1,
+                total: 2
+            }),
+            AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
         );
-
-        // the job matches except inssufficient reputation
-        let m = Match {
-            job_id: job_id.clone(),
-            sources: bounded_vec![PlannedExecution {
-                source: processor_account_id(),
-                start_delay: 0,
-            }],
-        };
         assert_err!(
-            AcurastMarketplace::propose_matching(
-                RuntimeOrigin::signed(charlie_account_id()).into(),
-                vec![m.clone()].try_into().unwrap(),
+            AcurastMarketplace::acknowledge_match(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                job_id.clone(),
+                PubKeys::default(),
             ),
-            Error::<Test>::InsufficientReputationInMatch
+            Error::<Test>::CannotAcknowledgeWhenNotMatched
         );
 
+        // the processor finalizes its slot after the schedule without being penalized
+        later(registration.schedule.end_time + 1);
+        let reputation = AcurastMarketplace::stored_reputation(processor_account_id());
+        let _ = events();
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
         assert_eq!(
-            events(),
-            [
-                RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStored(
-                    ad.clone(),
-                    processor_account_id()
-                )),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: alice_account_id(),
-                    to: pallet_acurast_acount(),
-                    amount: 12_000_000
-                }),
-                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
-                    registration1.clone(),
-                    job_id.clone()
-                )),
-                // no match event for job
-            ]
+            None,
+            AcurastMarketplace::stored_matches(processor_account_id(), job_id.clone())
+        );
+        assert_eq!(
+            reputation,
+            AcurastMarketplace::stored_reputation(processor_account_id())
+        );
+        assert!(!events().iter().any(|event| matches!(
+            event,
+            RuntimeEvent::AcurastMarketplace(crate::Event::CollateralSlashed(..))
+        )));
+        assert_eq!(
+            5_000_000,
+            AcurastMarketplace::stored_collateral(processor_account_id())
+        );
+        assert_eq!(
+            0,
+            AcurastMarketplace::committed_collateral(processor_account_id())
         );
     });
 }
 
 #[test]
-fn test_more_reports_than_expected() {
+fn test_replace_unacknowledged_processor() {
     let now = 1_671_789_600_000; // 23.12.2022 10:00;
 
     // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
@@ -1342,6 +1464,220 @@ fn test_more_reports_than_expected() {
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 2,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+    let replacement = PlannedExecution {
+        source: processor_3_account_id(),
+        start_delay: 0,
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        // pretend current time
+        later(now);
+
+        for processor in [processor_account_id(), processor_2_account_id()] {
+            assert_ok!(AcurastMarketplace::advertise(
+                RuntimeOrigin::signed(processor).into(),
+                ad.clone(),
+            ));
+        }
+        // the replacement is cheaper
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_3_account_id()).into(),
+            advertisement(900, 1, 100_000, 50_000, 8),
+        ));
+
+        let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![Match {
+                job_id: job_id1.clone(),
+                sources: bounded_vec![
+                    PlannedExecution {
+                        source: processor_account_id(),
+                        start_delay: 0,
+                    },
+                    PlannedExecution {
+                        source: processor_2_account_id(),
+                        start_delay: 0,
+                    }
+                ],
+            }]
+            .try_into()
+            .unwrap(),
+        ));
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_2_account_id()).into(),
+            job_id1.clone(),
+            PubKeys::default(),
+        ));
+        assert_eq!(23_608_000, AcurastMarketplace::reserved(&job_id1));
+        assert_eq!(
+            Some(80_000),
+            AcurastMarketplace::stored_storage_capacity(processor_account_id())
+        );
+
+        // the processor still has time to acknowledge
+        assert_err!(
+            AcurastMarketplace::propose_replacement(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id1.clone(),
+                processor_account_id(),
+                replacement.clone(),
+            ),
+            Error::<Test>::AcknowledgeDeadlineNotPassed
+        );
+
+        // pretend time moved on to the acknowledge deadline
+        later(registration.schedule.start_time - 600_000);
+
+        assert_err!(
+            AcurastMarketplace::propose_replacement(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id1.clone(),
+                processor_2_account_id(),
+                replacement.clone(),
+            ),
+            Error::<Test>::CannotReplaceAcknowledgedProcessor
+        );
+        assert_err!(
+            AcurastMarketplace::propose_replacement(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id1.clone(),
+                processor_account_id(),
+                PlannedExecution {
+                    source: processor_2_account_id(),
+                    start_delay: 0,
+                },
+            ),
+            Error::<Test>::DuplicateSourceInMatch
+        );
+
+        let reputation = |processor| {
+            BetaReputation::<u128>::normalize(
+                AcurastMarketplace::stored_reputation(processor).unwrap(),
+            )
+            .unwrap()
+        };
+        let reputation_before = reputation(processor_account_id());
+
+        assert_ok!(AcurastMarketplace::propose_replacement(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            job_id1.clone(),
+            processor_account_id(),
+            replacement.clone(),
+        ));
+
+        // the replacement took over the slot of the replaced processor
+        assert_eq!(
+            None,
+            AcurastMarketplace::stored_matches(processor_account_id(), job_id1.clone())
+        );
+        assert_eq!(
+            None,
+            AcurastMarketplace::assigned_processors(&job_id1, processor_account_id())
+        );
+        assert_eq!(
+            Some(Assignment {
+                slot: 0,
+                start_delay: 0,
+                fee_per_execution: 4_520_000,
+                acknowledged: false,
+                sla: SLA { total: 2, met: 0 },
+                pub_keys: PubKeys::default(),
+            }),
+            AcurastMarketplace::stored_matches(processor_3_account_id(), job_id1.clone())
+        );
+        assert_eq!(
+            Some(100_000),
+            AcurastMarketplace::stored_storage_capacity(processor_account_id())
+        );
+        assert_eq!(
+            Some(80_000),
+            AcurastMarketplace::stored_storage_capacity(processor_3_account_id())
+        );
+        assert!(reputation(processor_account_id()) < reputation_before);
+        // the matcher of the replacement got rewarded only from the savings of the cheaper replacement
+        assert_eq!(23_508_000, AcurastMarketplace::reserved(&job_id1));
+        assert_eq!(
+            Some(JobStatus::Assigned(1)),
+            AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
+        );
+        assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+            crate::Event::ProcessorReplaced(
+                job_id1.clone(),
+                processor_account_id(),
+                processor_3_account_id(),
+            )
+        )));
+
+        // the replacement can acknowledge the job as usual
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_3_account_id()).into(),
+            job_id1.clone(),
+            PubKeys::default(),
+        ));
+        assert_eq!(
+            Some(JobStatus::Assigned(2)),
+            AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
+        );
+
+        // no replacement once the job started
+        later(registration.schedule.start_time);
+        assert_err!(
+            AcurastMarketplace::propose_replacement(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id1.clone(),
+                processor_3_account_id(),
+                PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                },
+            ),
+            Error::<Test>::OverdueMatch
+        );
+    });
+}
+
+#[test]
+fn test_collateral_slashing() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let ad = AdvertisementFor::<Test> {
+        collateral: 5_000_000,
+        ..advertisement(1000, 1, 100_000, 50_000, 8)
+    };
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min -> 2 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -1349,8 +1685,10 @@ fn test_more_reports_than_expected() {
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 1,
+            min_slots: None,
             reward: 3_000_000 * 2,
             min_reputation: None,
+            min_collateral: Some(4_000_000),
             instant_match: None,
         },
     };
@@ -1360,20 +1698,23 @@ fn test_more_reports_than_expected() {
         let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
 
         // pretend current time
-        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+        later(now);
+
         assert_ok!(AcurastMarketplace::advertise(
             RuntimeOrigin::signed(processor_account_id()).into(),
             ad.clone(),
         ));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_2_account_id()).into(),
+            advertisement(1000, 1, 100_000, 50_000, 8),
+        ));
         assert_eq!(
-            Some(AdvertisementRestriction {
-                max_memory: 50_000,
-                network_request_quota: 8,
-                storage_capacity: 100_000,
-                allowed_consumers: ad.allowed_consumers.clone(),
-                available_modules: JobModules::default(),
-            }),
-            AcurastMarketplace::stored_advertisement(processor_account_id())
+            5_000_000,
+            AcurastMarketplace::stored_collateral(processor_account_id())
+        );
+        assert_eq!(
+            5_000_000,
+            Balances::reserved_balance(processor_account_id())
         );
 
         assert_ok!(Acurast::register(
@@ -1381,17 +1722,77 @@ fn test_more_reports_than_expected() {
             registration.clone(),
         ));
 
-        let m = Match {
-            job_id: job_id.clone(),
-            sources: bounded_vec![PlannedExecution {
-                source: processor_account_id(),
-                start_delay: 0,
-            }],
-        };
+        // the second processor did not bond any collateral
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![Match {
+                    job_id: job_id.clone(),
+                    sources: bounded_vec![PlannedExecution {
+                        source: processor_2_account_id(),
+                        start_delay: 0,
+                    }],
+                }]
+                .try_into()
+                .unwrap(),
+            ),
+            Error::<Test>::InsufficientCollateralInMatch
+        );
         assert_ok!(AcurastMarketplace::propose_matching(
             RuntimeOrigin::signed(charlie_account_id()).into(),
-            vec![m.clone()].try_into().unwrap(),
+            vec![Match {
+                job_id: job_id.clone(),
+                sources: bounded_vec![PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                }],
+            }]
+            .try_into()
+            .unwrap(),
+        ));
+        assert_eq!(11_804_000, AcurastMarketplace::reserved(&job_id));
+        assert_eq!(
+            4_000_000,
+            AcurastMarketplace::committed_collateral(processor_account_id())
+        );
+
+        // the collateral committed to the first job is not available for another job
+        let job_id2 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 2);
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            JobRegistrationFor::<Test> {
+                schedule: Schedule {
+                    start_time: registration.schedule.start_time + 86_400_000,
+                    end_time: registration.schedule.end_time + 86_400_000,
+                    ..registration.schedule.clone()
+                },
+                ..registration.clone()
+            },
         ));
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![Match {
+                    job_id: job_id2.clone(),
+                    sources: bounded_vec![PlannedExecution {
+                        source: processor_account_id(),
+                        start_delay: 0,
+                    }],
+                }]
+                .try_into()
+                .unwrap(),
+            ),
+            Error::<Test>::InsufficientCollateralInMatch
+        );
+
+        // the collateral cannot be reduced while matched
+        assert_err!(
+            AcurastMarketplace::advertise(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                advertisement(1000, 1, 100_000, 50_000, 8),
+            ),
+            Error::<Test>::CannotReduceCollateralWhileMatched
+        );
 
         assert_ok!(AcurastMarketplace::acknowledge_match(
             RuntimeOrigin::signed(processor_account_id()).into(),
@@ -1399,130 +1800,2758 @@ fn test_more_reports_than_expected() {
             PubKeys::default(),
         ));
 
-        // report twice with success
-        // -------------------------
-
-        // pretend time moved on
-        let mut iter = registration.schedule.iter(0).unwrap();
-        later(iter.next().unwrap() + 1000);
+        // only the first of two executions gets reported
+        later(registration.schedule.start_time + 3000);
         assert_ok!(AcurastMarketplace::report(
             RuntimeOrigin::signed(processor_account_id()).into(),
             job_id.clone(),
             ExecutionResult::Success(operation_hash())
         ));
+        assert_eq!(6_784_000, AcurastMarketplace::reserved(&job_id));
 
-        // pretend time moved on
-        later(iter.next().unwrap() + 1000);
-        assert_ok!(AcurastMarketplace::report(
+        later(registration.schedule.end_time + 1);
+        assert_ok!(AcurastMarketplace::finalize_job(
             RuntimeOrigin::signed(processor_account_id()).into(),
-            job_id.clone(),
-            ExecutionResult::Success(operation_hash())
+            job_id.clone()
         ));
 
-        // third report is illegal!
-        later(registration.schedule.range(0).unwrap().1 + 1000);
-        assert_err!(
-            AcurastMarketplace::report(
-                RuntimeOrigin::signed(processor_account_id()).into(),
-                job_id.clone(),
-                ExecutionResult::Success(operation_hash())
-            ),
-            Error::<Test>::MoreReportsThanExpected
+        // 10% of the required collateral is slashed for the missed execution, half of it is refunded to the job's creator
+        assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+            crate::Event::CollateralSlashed(job_id.clone(), processor_account_id(), 400_000)
+        )));
+        assert_eq!(
+            4_600_000,
+            AcurastMarketplace::stored_collateral(processor_account_id())
+        );
+        assert_eq!(
+            4_600_000,
+            Balances::reserved_balance(processor_account_id())
+        );
+        assert_eq!(200_000, Balances::free_balance(pallet_treasury_account()));
+        assert_eq!(6_984_000, AcurastMarketplace::reserved(&job_id));
+        // the commitment is released with the finalized slot
+        assert_eq!(
+            0,
+            AcurastMarketplace::committed_collateral(processor_account_id())
         );
 
+        let alice_balance = Balances::free_balance(alice_account_id());
+        assert_ok!(AcurastMarketplace::finalize_jobs(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            vec![job_id.1].try_into().unwrap(),
+        ));
+        assert_eq!(0, AcurastMarketplace::reserved(&job_id));
         assert_eq!(
-            events(),
-            [
-                RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStored(
-                    ad.clone(),
-                    processor_account_id()
-                )),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: alice_account_id(),
-                    to: pallet_acurast_acount(),
-                    amount: 12_000_000
-                }),
-                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
-                    registration.clone(),
-                    job_id.clone()
-                )),
-                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatched(m)),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: pallet_fees_account(),
-                    amount: 58_800
-                }),
+            alice_balance + 6_984_000,
+            Balances::free_balance(alice_account_id())
+        );
+
+        // the remaining collateral is released once no longer matched
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            advertisement(1000, 1, 100_000, 50_000, 8),
+        ));
+        assert_eq!(0, Balances::reserved_balance(processor_account_id()));
+    });
+}
+
+#[test]
+fn test_no_collateral_slashing_when_unacknowledged() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = AdvertisementFor::<Test> {
+        collateral: 5_000_000,
+        ..advertisement(1000, 1, 100_000, 50_000, 8)
+    };
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min -> 2 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: Some(4_000_000),
+            instant_match: None,
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        later(now);
+
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![Match {
+                job_id: job_id.clone(),
+                sources: bounded_vec![PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                }],
+            }]
+            .try_into()
+            .unwrap(),
+        ));
+        assert_eq!(
+            4_000_000,
+            AcurastMarketplace::committed_collateral(processor_account_id())
+        );
+
+        // the processor never acknowledges the match
+        later(registration.schedule.end_time + 1);
+        let _ = events();
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
+
+        // no collateral is slashed for a job the processor did not commit to, only the commitment is released
+        assert!(!events().iter().any(|event| matches!(
+            event,
+            RuntimeEvent::AcurastMarketplace(crate::Event::CollateralSlashed(..))
+        )));
+        assert_eq!(
+            5_000_000,
+            AcurastMarketplace::stored_collateral(processor_account_id())
+        );
+        assert_eq!(
+            5_000_000,
+            Balances::reserved_balance(processor_account_id())
+        );
+        assert_eq!(0, Balances::free_balance(pallet_treasury_account()));
+        assert_eq!(
+            0,
+            AcurastMarketplace::committed_collateral(processor_account_id())
+        );
+    });
+}
+
+#[test]
+fn test_dispute_execution() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min -> 2 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 2,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+    let wrong_operation_hash: ExecutionOperationHash = vec![1u8; 32].try_into().unwrap();
+
+    ExtBuilder::default().build().execute_with(|| {
+        DisputeWindow::set(600_000);
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        later(now);
+
+        for processor in [processor_account_id(), processor_2_account_id()] {
+            assert_ok!(AcurastMarketplace::advertise(
+                RuntimeOrigin::signed(processor).into(),
+                ad.clone(),
+            ));
+        }
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![Match {
+                job_id: job_id.clone(),
+                sources: bounded_vec![
+                    PlannedExecution {
+                        source: processor_account_id(),
+                        start_delay: 0,
+                    },
+                    PlannedExecution {
+                        source: processor_2_account_id(),
+                        start_delay: 0,
+                    },
+                ],
+            }]
+            .try_into()
+            .unwrap(),
+        ));
+        for processor in [processor_account_id(), processor_2_account_id()] {
+            assert_ok!(AcurastMarketplace::acknowledge_match(
+                RuntimeOrigin::signed(processor).into(),
+                job_id.clone(),
+                PubKeys::default(),
+            ));
+        }
+        let fee = AcurastMarketplace::stored_matches(processor_account_id(), &job_id)
+            .unwrap()
+            .fee_per_execution;
+        let reserved = AcurastMarketplace::reserved(&job_id);
+        let processor_balance = Balances::free_balance(processor_account_id());
+        let processor_2_balance = Balances::free_balance(processor_2_account_id());
+
+        // the fees of reported executions are escrowed
+        later(registration.schedule.start_time + 3000);
+        for processor in [processor_account_id(), processor_2_account_id()] {
+            assert_ok!(AcurastMarketplace::report(
+                RuntimeOrigin::signed(processor).into(),
+                job_id.clone(),
+                ExecutionResult::Success(operation_hash())
+            ));
+        }
+        assert_eq!(reserved, AcurastMarketplace::reserved(&job_id));
+        assert_eq!(
+            processor_balance,
+            Balances::free_balance(processor_account_id())
+        );
+        assert!(
+            AcurastMarketplace::stored_execution_report(&job_id, (processor_account_id(), 0))
+                .is_some()
+        );
+
+        assert_err!(
+            AcurastMarketplace::dispute_execution(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                processor_account_id(),
+                0,
+                wrong_operation_hash.clone(),
+                None,
+            ),
+            Error::<Test>::OperationHashMismatch
+        );
+        assert_err!(
+            AcurastMarketplace::dispute_execution(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                processor_account_id(),
+                1,
+                operation_hash(),
+                None,
+            ),
+            Error::<Test>::ExecutionReportNotFound
+        );
+        // only the job's creator can dispute its executions
+        assert_err!(
+            AcurastMarketplace::dispute_execution(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id.1,
+                processor_account_id(),
+                0,
+                operation_hash(),
+                None,
+            ),
+            Error::<Test>::ExecutionReportNotFound
+        );
+        assert_err!(
+            AcurastMarketplace::dispute_execution(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                processor_account_id(),
+                0,
+                operation_hash(),
+                Some(processor_account_id()),
+            ),
+            Error::<Test>::InvalidDisputeVerifier
+        );
+
+        assert_ok!(AcurastMarketplace::dispute_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            processor_account_id(),
+            0,
+            operation_hash(),
+            None,
+        ));
+        assert_err!(
+            AcurastMarketplace::dispute_execution(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                processor_account_id(),
+                0,
+                operation_hash(),
+                None,
+            ),
+            Error::<Test>::ExecutionAlreadyDisputed
+        );
+        assert_ok!(AcurastMarketplace::dispute_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            processor_2_account_id(),
+            0,
+            operation_hash(),
+            Some(processor_account_id()),
+        ));
+
+        // resolved by the configured origin in favour of the source
+        assert_err!(
+            AcurastMarketplace::resolve_dispute(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.clone(),
+                processor_account_id(),
+                0,
+                true,
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(AcurastMarketplace::resolve_dispute(
+            RuntimeOrigin::root().into(),
+            job_id.clone(),
+            processor_account_id(),
+            0,
+            true,
+        ));
+        assert_eq!(
+            processor_balance + fee,
+            Balances::free_balance(processor_account_id())
+        );
+        assert_eq!(reserved - fee, AcurastMarketplace::reserved(&job_id));
+
+        // the second executions are reported after the dispute window of the first ones passed
+        later(registration.schedule.start_time + 1_800_000 + 3000);
+        for processor in [processor_account_id(), processor_2_account_id()] {
+            assert_ok!(AcurastMarketplace::report(
+                RuntimeOrigin::signed(processor).into(),
+                job_id.clone(),
+                ExecutionResult::Success(operation_hash())
+            ));
+        }
+        assert_err!(
+            AcurastMarketplace::dispute_execution(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                processor_2_account_id(),
+                0,
+                operation_hash(),
+                None,
+            ),
+            Error::<Test>::ExecutionAlreadyDisputed
+        );
+        assert_ok!(AcurastMarketplace::dispute_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            processor_2_account_id(),
+            1,
+            operation_hash(),
+            None,
+        ));
+        // the disputed execution is still escrowed
+        assert_eq!(
+            processor_2_balance,
+            Balances::free_balance(processor_2_account_id())
+        );
+
+        // resolved by the verifier's re-execution against the source
+        let reputation = AcurastMarketplace::stored_reputation(processor_2_account_id());
+        assert_err!(
+            AcurastMarketplace::report_re_execution(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                job_id.clone(),
+                processor_2_account_id(),
+                0,
+                wrong_operation_hash.clone(),
+            ),
+            Error::<Test>::NotDisputeVerifier
+        );
+        assert_ok!(AcurastMarketplace::report_re_execution(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            processor_2_account_id(),
+            0,
+            wrong_operation_hash.clone(),
+        ));
+        assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+            crate::Event::DisputeResolved(job_id.clone(), processor_2_account_id(), 0, false)
+        )));
+        assert_ne!(
+            reputation,
+            AcurastMarketplace::stored_reputation(processor_2_account_id())
+        );
+        assert_eq!(
+            processor_2_balance,
+            Balances::free_balance(processor_2_account_id())
+        );
+
+        // the job cannot be finalized by the source while its execution is disputed
+        later(registration.schedule.end_time + 1);
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
+        assert_eq!(
+            processor_balance + 2 * fee,
+            Balances::free_balance(processor_account_id())
+        );
+        assert_err!(
+            AcurastMarketplace::finalize_job(
+                RuntimeOrigin::signed(processor_2_account_id()).into(),
+                job_id.clone()
+            ),
+            Error::<Test>::ExecutionReportsPending
+        );
+        assert_ok!(AcurastMarketplace::resolve_dispute(
+            RuntimeOrigin::root().into(),
+            job_id.clone(),
+            processor_2_account_id(),
+            1,
+            true,
+        ));
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_2_account_id()).into(),
+            job_id.clone()
+        ));
+        assert_eq!(
+            processor_2_balance + fee,
+            Balances::free_balance(processor_2_account_id())
+        );
+
+        // the fee of the rejected execution is refunded to the job's creator
+        assert_eq!(reserved - 3 * fee, AcurastMarketplace::reserved(&job_id));
+    });
+}
+
+#[test]
+fn test_rejected_execution_not_met() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min -> 2 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        DisputeWindow::set(600_000);
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        later(now);
+
+        // the processor is attested to get its reputation updated on finalization
+        assert_ok!(Acurast::submit_attestation(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            attestation_chain()
+        ));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![Match {
+                job_id: job_id.clone(),
+                sources: bounded_vec![PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                }],
+            }]
+            .try_into()
+            .unwrap(),
+        ));
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+        let fee = AcurastMarketplace::stored_matches(processor_account_id(), &job_id)
+            .unwrap()
+            .fee_per_execution;
+
+        // the first execution is rejected
+        later(registration.schedule.start_time + 3000);
+        assert_ok!(AcurastMarketplace::report(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            ExecutionResult::Success(operation_hash())
+        ));
+        assert_ok!(AcurastMarketplace::dispute_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            processor_account_id(),
+            0,
+            operation_hash(),
+            None,
+        ));
+        assert_ok!(AcurastMarketplace::resolve_dispute(
+            RuntimeOrigin::root().into(),
+            job_id.clone(),
+            processor_account_id(),
+            0,
+            false,
+        ));
+        assert_eq!(
+            1,
+            AcurastMarketplace::rejected_executions(&job_id, processor_account_id())
+        );
+
+        // the second execution is met
+        later(registration.schedule.start_time + 1_800_000 + 3000);
+        assert_ok!(AcurastMarketplace::report(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            ExecutionResult::Success(operation_hash())
+        ));
+        assert_eq!(
+            SLA { total: 2, met: 2 },
+            AcurastMarketplace::stored_matches(processor_account_id(), &job_id)
+                .unwrap()
+                .sla
+        );
+
+        later(registration.schedule.end_time + 1);
+        let reputation = AcurastMarketplace::stored_reputation(processor_account_id()).unwrap();
+        let average_reward = AcurastMarketplace::average_reward().unwrap_or(0);
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
+
+        // the rejected execution was already penalized on resolution and is not accounted as met again
+        assert_eq!(
+            BetaReputation::<u128>::update(reputation, 1, 0, fee, average_reward),
+            AcurastMarketplace::stored_reputation(processor_account_id())
+        );
+        assert_eq!(
+            0,
+            AcurastMarketplace::rejected_executions(&job_id, processor_account_id())
+        );
+    });
+}
+
+#[test]
+fn test_bounded_settlement() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_800_500_000,   // 23.12.2022 13:01:40
+            interval: 10_000,              // 10s -> 10 executions fit
+            max_start_delay: 0,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: Some(bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }]),
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        DisputeWindow::set(600_000);
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+        let fee = AcurastMarketplace::stored_matches(processor_account_id(), &job_id)
+            .unwrap()
+            .fee_per_execution;
+        let processor_balance = Balances::free_balance(processor_account_id());
+
+        // all executions are reported within the dispute window of the first one
+        for i in 0..10 {
+            later(registration.schedule.start_time + i * 10_000 + 3000);
+            assert_ok!(AcurastMarketplace::report(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                job_id.clone(),
+                ExecutionResult::Success(operation_hash())
+            ));
+        }
+        assert_eq!(
+            0,
+            AcurastMarketplace::settled_execution_reports(&job_id, processor_account_id())
+        );
+
+        // finalizing would settle more reports than allowed at once
+        later(registration.schedule.end_time + 600_000);
+        assert_err!(
+            AcurastMarketplace::finalize_job(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                job_id.clone()
+            ),
+            Error::<Test>::ExecutionReportsPending
+        );
+        assert_eq!(
+            processor_balance,
+            Balances::free_balance(processor_account_id())
+        );
+
+        // anyone can settle the reports in bounded steps
+        assert_ok!(AcurastMarketplace::settle_execution_reports(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            job_id.clone(),
+            processor_account_id(),
+        ));
+        assert_eq!(
+            8,
+            AcurastMarketplace::settled_execution_reports(&job_id, processor_account_id())
+        );
+        assert_eq!(
+            processor_balance + 8 * fee,
+            Balances::free_balance(processor_account_id())
+        );
+        assert!(
+            AcurastMarketplace::stored_execution_report(&job_id, (processor_account_id(), 8))
+                .is_some()
+        );
+
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
+        assert_eq!(
+            processor_balance + 10 * fee,
+            Balances::free_balance(processor_account_id())
+        );
+        assert_eq!(
+            0,
+            AcurastMarketplace::settled_execution_reports(&job_id, processor_account_id())
+        );
+    });
+}
+
+#[test]
+fn test_no_match_schedule_overlap() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration1 = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min -> 2 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    let registration2 = JobRegistrationFor::<Test> {
+        script: script_random_value(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_802_200_000, // 23.12.2022 13:30
+            end_time: 1_671_805_800_000,   // 23.12.2022 14:30 (one hour later)
+            interval: 1_200_000,           // 20min -> 3 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        let job_id2 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 2);
+
+        // pretend current time
+        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+
+        // register first job
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration1.clone(),
+        ));
+        assert_eq!(
+            Some(JobStatus::Open),
+            AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
+        );
+
+        // register second job
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration2.clone(),
+        ));
+        assert_eq!(
+            Some(JobStatus::Open),
+            AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1 + 1)
+        );
+
+        // the first job matches because capacity left
+        let m = Match {
+            job_id: job_id1.clone(),
+            sources: bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }],
+        };
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m.clone()].try_into().unwrap(),
+        ));
+
+        // this one does not match anymore
+        let m2 = Match {
+            job_id: job_id2.clone(),
+            sources: bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }],
+        };
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![m2.clone()].try_into().unwrap(),
+            ),
+            Error::<Test>::ScheduleOverlapInMatch
+        );
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStored(
+                    ad.clone(),
+                    processor_account_id()
+                )),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 12_000_000
+                }),
+                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
+                    registration1.clone(),
+                    job_id1.clone()
+                )),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 18_000_000
+                }),
+                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
+                    registration2.clone(),
+                    (job_id2.0.clone(), job_id2.1.clone())
+                )),
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatched(m)),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: pallet_fees_account(),
+                    amount: 58800
+                }),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: charlie_account_id(),
+                    amount: 137200
+                }),
+                // no match event for second
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_no_match_cron_schedule_overlap() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = |schedule_kind: ScheduleKind, executions: u128| JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 0,
+            max_start_delay: 5000,
+            kind: schedule_kind,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * executions,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+    // 13:00 and 13:40
+    let registration1 = registration(
+        ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(vec![
+            1_671_800_400_000,
+            1_671_802_800_000,
+        ])),
+        2,
+    );
+    // 13:00, 13:20 and 13:40
+    let registration2 = registration(
+        ScheduleKind::Cron(CronPattern {
+            minutes: 1 | 1 << 20 | 1 << 40,
+            hours: 1 << 13,
+            days_of_week: 0b1111111,
+        }),
+        3,
+    );
+    // 13:10 and 13:30
+    let registration3 = registration(
+        ScheduleKind::Cron(CronPattern {
+            minutes: 1 << 10 | 1 << 30,
+            hours: 1 << 13,
+            days_of_week: 0b1111111,
+        }),
+        2,
+    );
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = |offset| {
+            (
+                MultiOrigin::Acurast(alice_account_id()),
+                initial_job_id + offset,
+            )
+        };
+
+        // pretend current time
+        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+
+        // timestamps have to be strictly increasing
+        assert_err!(
+            Acurast::register(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                registration(
+                    ScheduleKind::Timestamps(ScheduleTimestamps::truncate_from(vec![
+                        1_671_802_800_000,
+                        1_671_800_400_000,
+                    ])),
+                    2,
+                ),
+            ),
+            Error::<Test>::JobRegistrationInvalidScheduleKind
+        );
+
+        for registration in [&registration1, &registration2, &registration3] {
+            assert_ok!(Acurast::register(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                registration.clone(),
+            ));
+        }
+        assert_eq!(
+            Some(JobStatus::Open),
+            AcurastMarketplace::stored_job_status(&job_id(3).0, &job_id(3).1)
+        );
+
+        let m = |offset| Match {
+            job_id: job_id(offset),
+            sources: bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }],
+        };
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m(1)].try_into().unwrap(),
+        ));
+
+        // the cron pattern matches 13:00 and 13:40 already assigned by the first job
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![m(2)].try_into().unwrap(),
+            ),
+            Error::<Test>::ScheduleOverlapInMatch
+        );
+
+        // the executions at 13:10 and 13:30 fit in between
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m(3)].try_into().unwrap(),
+        ));
+
+        assert_eq!(
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id(1).0, &job_id(1).1)
+        );
+        assert_eq!(
+            Some(JobStatus::Open),
+            AcurastMarketplace::stored_job_status(&job_id(2).0, &job_id(2).1)
+        );
+        assert_eq!(
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id(3).0, &job_id(3).1)
+        );
+    });
+}
+
+#[test]
+fn test_no_match_insufficient_reputation() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration1 = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min -> 2 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: Some(1_000_000),
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+
+        // register job
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration1.clone(),
+        ));
+        assert_eq!(
+            Some(JobStatus::Open),
+            AcurastMarketplace::stored_job_status(&job_id.0, job_id.1)
+        );
+
+        // the job matches except inssufficient reputation
+        let m = Match {
+            job_id: job_id.clone(),
+            sources: bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }],
+        };
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![m.clone()].try_into().unwrap(),
+            ),
+            Error::<Test>::InsufficientReputationInMatch
+        );
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStored(
+                    ad.clone(),
+                    processor_account_id()
+                )),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 12_000_000
+                }),
+                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
+                    registration1.clone(),
+                    job_id.clone()
+                )),
+                // no match event for job
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_no_match_missing_module() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let mut ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    ad.available_modules = vec![JobModule::DataEncryption, JobModule::Gps]
+        .try_into()
+        .unwrap();
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min -> 2 executions fit
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: vec![JobModule::DataEncryption, JobModule::LlmInference]
+            .try_into()
+            .unwrap(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+
+        // the processor provides only one of the two required modules
+        let m = Match {
+            job_id: job_id.clone(),
+            sources: bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }],
+        };
+        assert_err!(
+            AcurastMarketplace::propose_matching(
+                RuntimeOrigin::signed(charlie_account_id()).into(),
+                vec![m.clone()].try_into().unwrap(),
+            ),
+            Error::<Test>::ModuleNotAvailableInMatch
+        );
+
+        // once all required modules are advertised, the job matches
+        ad.available_modules = JobModule::ALL.to_vec().try_into().unwrap();
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m.clone()].try_into().unwrap(),
+        ));
+        assert_eq!(
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id.0, job_id.1)
+        );
+    });
+}
+
+#[test]
+fn test_more_reports_than_expected() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_eq!(
+            Some(AdvertisementRestriction {
+                max_memory: 50_000,
+                network_request_quota: 8,
+                storage_capacity: 100_000,
+                allowed_consumers: ad.allowed_consumers.clone(),
+                available_modules: JobModules::default(),
+            }),
+            AcurastMarketplace::stored_advertisement(processor_account_id())
+        );
+
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+
+        let m = Match {
+            job_id: job_id.clone(),
+            sources: bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }],
+        };
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m.clone()].try_into().unwrap(),
+        ));
+
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+
+        // report twice with success
+        // -------------------------
+
+        // pretend time moved on
+        let mut iter = registration.schedule.iter(0).unwrap();
+        later(iter.next().unwrap() + 1000);
+        assert_ok!(AcurastMarketplace::report(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            ExecutionResult::Success(operation_hash())
+        ));
+
+        // pretend time moved on
+        later(iter.next().unwrap() + 1000);
+        assert_ok!(AcurastMarketplace::report(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            ExecutionResult::Success(operation_hash())
+        ));
+
+        // third report is illegal!
+        later(registration.schedule.range(0).unwrap().1 + 1000);
+        assert_err!(
+            AcurastMarketplace::report(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                job_id.clone(),
+                ExecutionResult::Success(operation_hash())
+            ),
+            Error::<Test>::MoreReportsThanExpected
+        );
+
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStored(
+                    ad.clone(),
+                    processor_account_id()
+                )),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 12_000_000
+                }),
+                RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStored(
+                    registration.clone(),
+                    job_id.clone()
+                )),
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatched(m)),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: pallet_fees_account(),
+                    amount: 58_800
+                }),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: charlie_account_id(),
+                    amount: 137_200
+                }),
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationAssigned(
+                    job_id.clone(),
+                    processor_account_id(),
+                    Assignment {
+                        slot: 0,
+                        start_delay: 0,
+                        fee_per_execution: 5_020_000,
+                        acknowledged: true,
+                        sla: SLA { total: 2, met: 0 },
+                        pub_keys: PubKeys::default(),
+                    }
+                )),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: pallet_fees_account(),
+                    amount: 1_506_000
+                }),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: processor_account_id(),
+                    amount: 3_514_000
+                }),
+                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionSuccess(
+                    job_id.clone(),
+                    operation_hash()
+                )),
+                RuntimeEvent::AcurastMarketplace(crate::Event::Reported(
+                    job_id.clone(),
+                    processor_account_id(),
+                    Assignment {
+                        slot: 0,
+                        start_delay: 0,
+                        fee_per_execution: 5_020_000,
+                        acknowledged: true,
+                        sla: SLA { total: 2, met: 1 },
+                        pub_keys: PubKeys::default(),
+                    }
+                )),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: pallet_fees_account(),
+                    amount: 1_506_000
+                }),
+                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+                    from: pallet_acurast_acount(),
+                    to: processor_account_id(),
+                    amount: 3_514_000
+                }),
+                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionSuccess(
+                    job_id.clone(),
+                    operation_hash()
+                )),
+                RuntimeEvent::AcurastMarketplace(crate::Event::Reported(
+                    job_id.clone(),
+                    processor_account_id(),
+                    Assignment {
+                        slot: 0,
+                        start_delay: 0,
+                        fee_per_execution: 5_020_000,
+                        acknowledged: true,
+                        sla: SLA { total: 2, met: 2 },
+                        pub_keys: PubKeys::default(),
+                    }
+                )),
+            ]
+        );
+    });
+}
+
+#[test]
+fn test_update_registration() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    // 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        // pretend current time
+        later(now);
+
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_eq!(12_000_000, AcurastMarketplace::reserved(&job_id));
+
+        // while open, any update resulting in a valid registration is accepted
+        assert_ok!(Acurast::update_registration(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            JobRegistrationUpdate {
+                memory: Some(10_000u32),
+                end_time: Some(1_671_807_600_000), // 23.12.2022 15:00
+                ..Default::default()
+            },
+        ));
+        assert_eq!(24_000_000, AcurastMarketplace::reserved(&job_id));
+
+        // reducing the executions again refunds the reward locked in excess
+        assert_ok!(Acurast::update_registration(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            JobRegistrationUpdate {
+                end_time: Some(1_671_804_000_000), // 23.12.2022 14:00
+                ..Default::default()
+            },
+        ));
+        assert_eq!(12_000_000, AcurastMarketplace::reserved(&job_id));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 88_000_000);
+
+        let m = Match {
+            job_id: job_id.clone(),
+            sources: bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }],
+        };
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(charlie_account_id()).into(),
+            vec![m.clone()].try_into().unwrap(),
+        ));
+        assert_eq!(
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+        );
+
+        // once matched, only extensions of the schedule are accepted
+        assert_err!(
+            Acurast::update_registration(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                JobRegistrationUpdate {
+                    memory: Some(20_000u32),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::JobRegistrationUnmodifiable
+        );
+        assert_err!(
+            Acurast::update_registration(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                JobRegistrationUpdate {
+                    end_time: Some(1_671_802_200_000), // 23.12.2022 13:30
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::JobRegistrationUnmodifiable
+        );
+
+        let reserved = AcurastMarketplace::reserved(&job_id);
+        assert_ok!(Acurast::update_registration(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            JobRegistrationUpdate {
+                end_time: Some(1_671_805_800_000), // 23.12.2022 14:30
+                ..Default::default()
+            },
+        ));
+        assert_eq!(reserved + 6_000_000, AcurastMarketplace::reserved(&job_id));
+        assert_eq!(
+            Some(SLA { total: 3, met: 0 }),
+            AcurastMarketplace::stored_matches(processor_account_id(), job_id.clone())
+                .map(|assignment| assignment.sla)
+        );
+    });
+}
+
+#[test]
+fn test_set_environment_acknowledged_key() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 0,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 2,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: Some(bounded_vec![
+                PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                },
+                PlannedExecution {
+                    source: processor_2_account_id(),
+                    start_delay: 0,
+                }
+            ]),
+        },
+    };
+    let public_key = PubKey::SECP256r1(bounded_vec![2u8; 33]);
+    let environment = |public_key: PubKey| Environment {
+        public_key,
+        variables: bounded_vec![(bounded_vec![1u8], bounded_vec![2u8])],
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_2_account_id()).into(),
+            ad.clone(),
+        ));
+
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            bounded_vec![public_key.clone()],
+        ));
+
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            processor_account_id(),
+            environment(public_key.clone()),
+            None
+        ));
+        // same key bytes tagged with another curve
+        assert_err!(
+            Acurast::set_environment(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                processor_account_id(),
+                environment(PubKey::SECP256k1(bounded_vec![2u8; 33])),
+                None
+            ),
+            Error::<Test>::EnvironmentKeyNotAcknowledged
+        );
+        // matched but not acknowledged
+        assert_err!(
+            Acurast::set_environments(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                bounded_vec![
+                    (processor_account_id(), environment(public_key.clone())),
+                    (processor_2_account_id(), environment(public_key.clone()))
+                ],
+                None
+            ),
+            Error::<Test>::EnvironmentKeyNotAcknowledged
+        );
+        assert_err!(
+            Acurast::set_environment(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                bob_account_id(),
+                environment(public_key.clone()),
+                None
+            ),
+            Error::<Test>::JobNotAssigned
+        );
+    });
+}
+
+#[test]
+fn test_transfer_assigned_job() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 0,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 2,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: Some(bounded_vec![
+                PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                },
+                PlannedExecution {
+                    source: processor_2_account_id(),
+                    start_delay: 0,
+                }
+            ]),
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_2_account_id()).into(),
+            ad.clone(),
+        ));
+
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        let new_job_id = (MultiOrigin::Acurast(bob_account_id()), initial_job_id + 1);
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+
+        // the processors execute the job under its current job id, so it cannot be transferred while assigned
+        assert_ok!(Acurast::transfer_job(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            bob_account_id()
+        ));
+        assert_err!(
+            Acurast::accept_job_transfer(
+                RuntimeOrigin::signed(bob_account_id()).into(),
+                job_id.clone()
+            ),
+            Error::<Test>::CannotTransferAssignedJob
+        );
+        assert!(Acurast::stored_job_registration(&job_id.0, &job_id.1).is_some());
+        assert_eq!(
+            Some(JobStatus::Assigned(1)),
+            AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+        );
+        assert_eq!(Some(bob_account_id()), Acurast::job_transfer(&job_id));
+
+        // once all processors finalized their slot the job can be transferred
+        later(registration.schedule.end_time + 1);
+        for processor in [processor_account_id(), processor_2_account_id()] {
+            assert_ok!(AcurastMarketplace::finalize_job(
+                RuntimeOrigin::signed(processor).into(),
+                job_id.clone()
+            ));
+        }
+        let reserved = AcurastMarketplace::reserved(&job_id);
+        assert_ok!(Acurast::accept_job_transfer(
+            RuntimeOrigin::signed(bob_account_id()).into(),
+            job_id.clone()
+        ));
+
+        assert_eq!(
+            None,
+            AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+        );
+        assert_eq!(
+            Some(JobStatus::Assigned(1)),
+            AcurastMarketplace::stored_job_status(&new_job_id.0, &new_job_id.1)
+        );
+        assert_eq!(0, AcurastMarketplace::reserved(&job_id));
+        assert_eq!(reserved, AcurastMarketplace::reserved(&new_job_id));
+        assert_eq!(None, Acurast::job_transfer(&job_id));
+
+        // a pending transfer is removed together with the finalized job and the remaining budget is refunded to the new owner
+        assert_ok!(Acurast::transfer_job(
+            RuntimeOrigin::signed(bob_account_id()).into(),
+            new_job_id.1,
+            charlie_account_id()
+        ));
+        let bob_balance = Balances::free_balance(&bob_account_id());
+        assert_ok!(AcurastMarketplace::finalize_jobs(
+            RuntimeOrigin::signed(bob_account_id()).into(),
+            vec![new_job_id.1].try_into().unwrap(),
+        ));
+        assert_eq!(None, Acurast::job_transfer(&new_job_id));
+        assert_eq!(
+            None,
+            Acurast::stored_job_registration(&new_job_id.0, &new_job_id.1)
+        );
+        assert_eq!(
+            Balances::free_balance(&bob_account_id()),
+            bob_balance + reserved
+        );
+    });
+}
+
+#[test]
+fn test_recurring_job() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 0,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: Some(bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }]),
+        },
+    };
+    let renewed_schedule = Schedule {
+        start_time: 1_671_804_000_000, // 23.12.2022 14:00
+        end_time: 1_671_807_600_000,   // 23.12.2022 15:00
+        ..registration.schedule.clone()
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::set_job_renewal(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            RenewalPolicy::Times(1),
+            20_000_000,
+        ));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 68_000_000);
+
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+        for start in registration.schedule.iter(0).unwrap() {
+            later(start + 1000);
+            assert_ok!(AcurastMarketplace::report(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                job_id.clone(),
+                ExecutionResult::Success(operation_hash())
+            ));
+        }
+        assert_eq!(1_960_000, AcurastMarketplace::reserved(&job_id));
+
+        // the job is renewed with the next window once the processor finalized its slot
+        later(1_671_803_100_000); // 23.12.2022 13:45
+        events();
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
+        assert_eq!(
+            Some(JobRegistrationFor::<Test> {
+                schedule: renewed_schedule.clone(),
+                ..registration.clone()
+            }),
+            Acurast::stored_job_registration(&job_id.0, job_id.1)
+        );
+        assert_eq!(
+            Some(JobRenewal {
+                policy: RenewalPolicy::Times(0),
+                deposit: 9_960_000,
+                previous_sources: Default::default(),
+                slashed_collateral: 0,
+            }),
+            AcurastMarketplace::job_renewal(&job_id)
+        );
+        assert_eq!(12_000_000, AcurastMarketplace::reserved(&job_id));
+        // the previous processor is preferred for the next window
+        assert_eq!(
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+        );
+        assert!(AcurastMarketplace::stored_matches(processor_account_id(), &job_id).is_some());
+        assert_eq!(
+            events(),
+            [
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobFinalized(job_id.clone())),
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobRenewed(
+                    job_id.clone(),
+                    renewed_schedule.clone()
+                )),
+                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatched(Match {
+                    job_id: job_id.clone(),
+                    sources: bounded_vec![PlannedExecution {
+                        source: processor_account_id(),
+                        start_delay: 0,
+                    }],
+                })),
+            ]
+        );
+
+        // the renewals are used up, so the job gets finalized and the budget and deposit refunded
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+        later(1_671_807_600_000); // 23.12.2022 15:00
+        assert_ok!(AcurastMarketplace::finalize_jobs(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            vec![job_id.1].try_into().unwrap(),
+        ));
+        assert_eq!(None, Acurast::stored_job_registration(&job_id.0, job_id.1));
+        assert_eq!(None, AcurastMarketplace::job_renewal(&job_id));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 89_960_000);
+    });
+}
+
+#[test]
+fn test_recurring_job_with_slashed_collateral() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = AdvertisementFor::<Test> {
+        collateral: 5_000_000,
+        ..advertisement(1000, 1, 100_000, 50_000, 8)
+    };
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 0,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: Some(4_000_000),
+            instant_match: Some(bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }]),
+        },
+    };
+    let public_key = PubKey::SECP256r1(bounded_vec![2u8; 33]);
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::set_job_renewal(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            RenewalPolicy::Times(1),
+            20_000_000,
+        ));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 68_000_000);
+
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            bounded_vec![public_key.clone()],
+        ));
+        assert_ok!(Acurast::set_environment(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            processor_account_id(),
+            Environment {
+                public_key: public_key.clone(),
+                variables: bounded_vec![(bounded_vec![1u8], bounded_vec![2u8])],
+            },
+            None
+        ));
+
+        // only the first of two executions gets reported
+        later(registration.schedule.start_time + 1000);
+        assert_ok!(AcurastMarketplace::report(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            ExecutionResult::Success(operation_hash())
+        ));
+        assert_eq!(6_980_000, AcurastMarketplace::reserved(&job_id));
+
+        later(registration.schedule.end_time + 1);
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
+
+        // the half of the slashed collateral added to the job's budget is refunded to the job's creator on renewal
+        assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+            crate::Event::CollateralSlashed(job_id.clone(), processor_account_id(), 400_000)
+        )));
+        assert_eq!(200_000, Balances::free_balance(pallet_treasury_account()));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 68_200_000);
+        assert_eq!(
+            Some(JobRenewal {
+                policy: RenewalPolicy::Times(0),
+                deposit: 14_980_000,
+                previous_sources: Default::default(),
+                slashed_collateral: 0,
+            }),
+            AcurastMarketplace::job_renewal(&job_id)
+        );
+        assert_eq!(12_000_000, AcurastMarketplace::reserved(&job_id));
+
+        // the processor is matched again and keeps its environment
+        assert!(AcurastMarketplace::stored_matches(processor_account_id(), &job_id).is_some());
+        assert!(Acurast::execution_environment(&job_id, &processor_account_id()).is_some());
+    });
+}
+
+#[test]
+fn test_on_demand_job() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 0,
+            max_start_delay: 0,
+            kind: ScheduleKind::OnDemand,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: Some(bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }]),
+        },
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            ad.clone(),
+        ));
+        // no reward is locked upfront
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 100_000_000);
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+
+        // executions can only be triggered within the schedule's window
+        assert_err!(
+            AcurastMarketplace::trigger_execution(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+            ),
+            Error::<Test>::TriggerOutsideSchedule
+        );
+
+        later(1_671_801_000_000); // 23.12.2022 13:10
+        events();
+        assert_ok!(AcurastMarketplace::trigger_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+        ));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 94_000_000);
+        assert_eq!(6_000_000, AcurastMarketplace::reserved(&job_id));
+        assert_eq!(
+            SLA { total: 1, met: 0 },
+            AcurastMarketplace::stored_matches(processor_account_id(), &job_id)
+                .unwrap()
+                .sla
+        );
+        assert_eq!(
+            events(),
+            [
                 RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: charlie_account_id(),
-                    amount: 137_200
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 6_000_000
                 }),
-                RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationAssigned(
+                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionTriggered(
                     job_id.clone(),
-                    processor_account_id(),
-                    Assignment {
-                        slot: 0,
-                        start_delay: 0,
-                        fee_per_execution: 5_020_000,
-                        acknowledged: true,
-                        sla: SLA { total: 2, met: 0 },
-                        pub_keys: PubKeys::default(),
-                    }
+                    1_671_801_000_000
                 )),
+            ]
+        );
+
+        // exactly one report is accepted per trigger
+        later(1_671_801_001_000);
+        assert_ok!(AcurastMarketplace::report(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            ExecutionResult::Success(operation_hash())
+        ));
+        assert_err!(
+            AcurastMarketplace::report(
+                RuntimeOrigin::signed(processor_account_id()).into(),
+                job_id.clone(),
+                ExecutionResult::Success(operation_hash())
+            ),
+            Error::<Test>::MoreReportsThanExpected
+        );
+        assert_eq!(980_000, AcurastMarketplace::reserved(&job_id));
+
+        // the unspent budget is refunded when finalizing after the window
+        later(1_671_804_100_000); // 23.12.2022 14:01:40
+        assert_ok!(AcurastMarketplace::finalize_job(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone()
+        ));
+        assert_ok!(AcurastMarketplace::finalize_jobs(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            vec![job_id.1].try_into().unwrap(),
+        ));
+        assert_eq!(None, Acurast::stored_job_registration(&job_id.0, job_id.1));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 94_980_000);
+    });
+}
+
+#[test]
+fn test_pipeline() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 0,
+            max_start_delay: 0,
+            kind: ScheduleKind::OnDemand,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: Some(bounded_vec![PlannedExecution {
+                source: processor_account_id(),
+                start_delay: 0,
+            }]),
+        },
+    };
+    let upstream_registration = JobRegistrationFor::<Test> {
+        script: script_random_value(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: Some(bounded_vec![PlannedExecution {
+                source: processor_2_account_id(),
+                start_delay: 0,
+            }]),
+        },
+        ..registration.clone()
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let upstream_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 2);
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        for processor in [processor_account_id(), processor_2_account_id()] {
+            assert_ok!(AcurastMarketplace::advertise(
+                RuntimeOrigin::signed(processor).into(),
+                ad.clone(),
+            ));
+        }
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            upstream_registration.clone(),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration.clone(),
+        ));
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_2_account_id()).into(),
+            upstream_id.clone(),
+            PubKeys::default(),
+        ));
+        assert_ok!(AcurastMarketplace::acknowledge_match(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            job_id.clone(),
+            PubKeys::default(),
+        ));
+
+        assert_ok!(AcurastMarketplace::set_job_upstream(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            Some(upstream_id.clone()),
+        ));
+        assert_eq!(
+            Some(upstream_id.clone()),
+            AcurastMarketplace::job_upstream(&job_id)
+        );
+        assert_eq!(
+            Some(()),
+            AcurastMarketplace::job_downstreams(&upstream_id, &job_id)
+        );
+
+        // pipelines must not contain cycles
+        assert_err!(
+            AcurastMarketplace::set_job_upstream(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                upstream_id.1,
+                Some(job_id.clone()),
+            ),
+            Error::<Test>::PipelineCycle
+        );
+        assert_err!(
+            AcurastMarketplace::set_job_upstream(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                job_id.1,
+                Some(job_id.clone()),
+            ),
+            Error::<Test>::PipelineCycle
+        );
+
+        // a fulfillment is only accepted for an execution expected from the upstream job's processor
+        assert_err!(
+            AcurastMarketplace::fulfill_upstream_for(
+                &processor_2_account_id(),
+                &script_random_value()
+            ),
+            DispatchError::BadOrigin
+        );
+
+        later(1_671_801_000_000); // 23.12.2022 13:10
+        assert_ok!(AcurastMarketplace::trigger_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            upstream_id.1,
+        ));
+        assert_eq!(Balances::free_balance(&alice_account_id()), 94_000_000);
+
+        events();
+        assert_ok!(AcurastMarketplace::fulfill_upstream_for(
+            &processor_2_account_id(),
+            &script_random_value()
+        ));
+        // each stage locks the reward from its own budget
+        assert_eq!(Balances::free_balance(&alice_account_id()), 88_000_000);
+        assert_eq!(6_000_000, AcurastMarketplace::reserved(&upstream_id));
+        assert_eq!(6_000_000, AcurastMarketplace::reserved(&job_id));
+        assert_eq!(
+            SLA { total: 1, met: 0 },
+            AcurastMarketplace::stored_matches(processor_account_id(), &job_id)
+                .unwrap()
+                .sla
+        );
+        assert_eq!(
+            events(),
+            [
                 RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: pallet_fees_account(),
-                    amount: 1_506_000
-                }),
-                RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: processor_account_id(),
-                    amount: 3_514_000
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 6_000_000
                 }),
-                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionSuccess(
+                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionTriggered(
                     job_id.clone(),
-                    operation_hash()
+                    1_671_801_000_000
                 )),
-                RuntimeEvent::AcurastMarketplace(crate::Event::Reported(
-                    job_id.clone(),
+            ]
+        );
+
+        // further fulfillments exceed the upstream job's executions
+        assert_err!(
+            AcurastMarketplace::fulfill_upstream_for(
+                &processor_2_account_id(),
+                &script_random_value()
+            ),
+            DispatchError::BadOrigin
+        );
+        // fulfillments by other processors or for other scripts are rejected
+        assert_err!(
+            AcurastMarketplace::fulfill_upstream_for(
+                &processor_account_id(),
+                &script_random_value()
+            ),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(AcurastMarketplace::set_job_upstream(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            job_id.1,
+            None,
+        ));
+        assert_eq!(None, AcurastMarketplace::job_upstream(&job_id));
+        assert_eq!(
+            None,
+            AcurastMarketplace::job_downstreams(&upstream_id, &job_id)
+        );
+    });
+}
+
+#[test]
+fn test_pipeline_upstreams_sharing_script() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+    let registration = |script: Script,
+                        start_time: u64,
+                        end_time: u64,
+                        source: <Test as frame_system::Config>::AccountId| {
+        JobRegistrationFor::<Test> {
+            script,
+            allowed_sources: None,
+            allow_only_verified_sources: false,
+            schedule: Schedule {
+                duration: 5000,
+                start_time,
+                end_time,
+                interval: 0,
+                max_start_delay: 0,
+                kind: ScheduleKind::OnDemand,
+            },
+            memory: 5_000u32,
+            network_requests: 5,
+            storage: 20_000u32,
+            required_modules: JobModules::default(),
+            extra: JobRequirements {
+                slots: 1,
+                min_slots: None,
+                reward: 3_000_000 * 2,
+                min_reputation: None,
+                min_collateral: None,
+                instant_match: Some(bounded_vec![PlannedExecution {
+                    source,
+                    start_delay: 0,
+                }]),
+            },
+        }
+    };
+
+    ExtBuilder::default().build().execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id = |offset: u128| {
+            (
+                MultiOrigin::Acurast(alice_account_id()),
+                initial_job_id + offset,
+            )
+        };
+        let (upstream_1, upstream_2, downstream_1, downstream_2) =
+            (job_id(1), job_id(2), job_id(3), job_id(4));
+
+        // pretend current time
+        later(now);
+
+        let _ = Balances::force_set_balance(
+            RuntimeOrigin::root(),
+            MultiAddress::Id(alice_account_id()),
+            100_000_000,
+        );
+        for processor in [
+            processor_account_id(),
+            processor_2_account_id(),
+            processor_3_account_id(),
+        ] {
+            assert_ok!(AcurastMarketplace::advertise(
+                RuntimeOrigin::signed(processor).into(),
+                ad.clone(),
+            ));
+        }
+        // both upstream jobs are assigned to the same processor and share the same script
+        for (registration, job_id, processor) in [
+            (
+                registration(
+                    script_random_value(),
+                    1_671_800_400_000, // 23.12.2022 13:00
+                    1_671_802_200_000, // 23.12.2022 13:30
+                    processor_2_account_id(),
+                ),
+                &upstream_1,
+                processor_2_account_id(),
+            ),
+            (
+                registration(
+                    script_random_value(),
+                    1_671_802_260_000, // 23.12.2022 13:31
+                    1_671_804_000_000, // 23.12.2022 14:00
+                    processor_2_account_id(),
+                ),
+                &upstream_2,
+                processor_2_account_id(),
+            ),
+            (
+                registration(
+                    script(),
+                    1_671_800_400_000, // 23.12.2022 13:00
+                    1_671_804_000_000, // 23.12.2022 14:00
                     processor_account_id(),
-                    Assignment {
-                        slot: 0,
-                        start_delay: 0,
-                        fee_per_execution: 5_020_000,
-                        acknowledged: true,
-                        sla: SLA { total: 2, met: 1 },
-                        pub_keys: PubKeys::default(),
-                    }
-                )),
+                ),
+                &downstream_1,
+                processor_account_id(),
+            ),
+            (
+                registration(
+                    script(),
+                    1_671_800_400_000, // 23.12.2022 13:00
+                    1_671_804_000_000, // 23.12.2022 14:00
+                    processor_3_account_id(),
+                ),
+                &downstream_2,
+                processor_3_account_id(),
+            ),
+        ] {
+            assert_ok!(Acurast::register(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                registration,
+            ));
+            assert_ok!(AcurastMarketplace::acknowledge_match(
+                RuntimeOrigin::signed(processor).into(),
+                job_id.clone(),
+                PubKeys::default(),
+            ));
+        }
+        for (downstream, upstream) in [(&downstream_1, &upstream_1), (&downstream_2, &upstream_2)] {
+            assert_ok!(AcurastMarketplace::set_job_upstream(
+                RuntimeOrigin::signed(alice_account_id()).into(),
+                downstream.1,
+                Some(upstream.clone()),
+            ));
+        }
+
+        later(1_671_801_000_000); // 23.12.2022 13:10
+        assert_ok!(AcurastMarketplace::trigger_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            upstream_1.1,
+        ));
+        later(1_671_802_800_000); // 23.12.2022 13:40
+        assert_ok!(AcurastMarketplace::trigger_execution(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            upstream_2.1,
+        ));
+
+        // the fulfillment is accounted to the earlier upstream job only
+        events();
+        assert_ok!(AcurastMarketplace::fulfill_upstream_for(
+            &processor_2_account_id(),
+            &script_random_value()
+        ));
+        assert_eq!(
+            1,
+            AcurastMarketplace::upstream_fulfillments(&upstream_1, processor_2_account_id())
+        );
+        assert_eq!(
+            0,
+            AcurastMarketplace::upstream_fulfillments(&upstream_2, processor_2_account_id())
+        );
+        assert_eq!(
+            events(),
+            [
                 RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: pallet_fees_account(),
-                    amount: 1_506_000
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 6_000_000
                 }),
+                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionTriggered(
+                    downstream_1.clone(),
+                    1_671_802_800_000
+                )),
+            ]
+        );
+
+        // the next fulfillment is accounted to the later upstream job
+        assert_ok!(AcurastMarketplace::fulfill_upstream_for(
+            &processor_2_account_id(),
+            &script_random_value()
+        ));
+        assert_eq!(
+            1,
+            AcurastMarketplace::upstream_fulfillments(&upstream_2, processor_2_account_id())
+        );
+        assert_eq!(
+            events(),
+            [
                 RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-                    from: pallet_acurast_acount(),
-                    to: processor_account_id(),
-                    amount: 3_514_000
+                    from: alice_account_id(),
+                    to: pallet_acurast_acount(),
+                    amount: 6_000_000
                 }),
-                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionSuccess(
-                    job_id.clone(),
-                    operation_hash()
-                )),
-                RuntimeEvent::AcurastMarketplace(crate::Event::Reported(
-                    job_id.clone(),
-                    processor_account_id(),
-                    Assignment {
-                        slot: 0,
-                        start_delay: 0,
-                        fee_per_execution: 5_020_000,
-                        acknowledged: true,
-                        sla: SLA { total: 2, met: 2 },
-                        pub_keys: PubKeys::default(),
-                    }
+                RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionTriggered(
+                    downstream_2.clone(),
+                    1_671_802_800_000
                 )),
             ]
         );
+        assert_eq!(
+            SLA { total: 1, met: 0 },
+            AcurastMarketplace::stored_matches(processor_account_id(), &downstream_1)
+                .unwrap()
+                .sla
+        );
+        assert_eq!(
+            SLA { total: 1, met: 0 },
+            AcurastMarketplace::stored_matches(processor_3_account_id(), &downstream_2)
+                .unwrap()
+                .sla
+        );
+
+        // all executions of both upstream jobs are fulfilled
+        assert_err!(
+            AcurastMarketplace::fulfill_upstream_for(
+                &processor_2_account_id(),
+                &script_random_value()
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_matcher_offchain_worker() {
+    let now = 1_671_789_600_000; // 23.12.2022 10:00;
+
+    let registration1 = JobRegistrationFor::<Test> {
+        script: script(),
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 1,
+            min_slots: None,
+            reward: 3_000_000 * 2,
+            min_reputation: None,
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+    // overlaps with the first job unless delayed by its full max_start_delay
+    let mut registration2 = registration1.clone();
+    registration2.script = script_random_value();
+    registration2.schedule.start_time += 1000;
+    registration2.schedule.end_time += 1000;
+
+    let keystore = MemoryKeystore::new();
+    let matcher = keystore
+        .sr25519_generate_new(crate::crypto::KEY_TYPE, None)
+        .unwrap();
+    let (offchain, _) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = ExtBuilder::default().build();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    ext.register_extension(KeystoreExt::new(keystore));
+
+    ext.execute_with(|| {
+        let initial_job_id = Acurast::job_id_sequence();
+        let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+        let job_id2 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 2);
+
+        later(now);
+        // the second processor is more expensive
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_account_id()).into(),
+            advertisement(1000, 1, 100_000, 50_000, 8),
+        ));
+        assert_ok!(AcurastMarketplace::advertise(
+            RuntimeOrigin::signed(processor_2_account_id()).into(),
+            advertisement(1100, 1, 100_000, 50_000, 8),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration1.clone(),
+        ));
+        assert_ok!(Acurast::register(
+            RuntimeOrigin::signed(alice_account_id()).into(),
+            registration2.clone(),
+        ));
+
+        // the matcher only runs if configured
+        AcurastMarketplace::offchain_worker(System::block_number());
+        assert!(pool_state.read().transactions.is_empty());
+
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            MATCHER_CONFIG_STORAGE_KEY,
+            &MatcherConfig {
+                max_matches: 10,
+                max_last_seen_age: Some(60_000),
+            }
+            .encode(),
+        );
+        AcurastMarketplace::offchain_worker(System::block_number());
+
+        // the cheapest processor gets both jobs, with the second one delayed to not overlap
+        let expected_matches: BoundedVec<_, _> = bounded_vec![
+            Match {
+                job_id: job_id1.clone(),
+                sources: bounded_vec![PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 0,
+                }],
+            },
+            Match {
+                job_id: job_id2.clone(),
+                sources: bounded_vec![PlannedExecution {
+                    source: processor_account_id(),
+                    start_delay: 5000,
+                }],
+            },
+        ];
+        let tx = pool_state.write().transactions.pop().unwrap();
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert_eq!(Some((0, ())), tx.signature);
+        assert_eq!(
+            RuntimeCall::AcurastMarketplace(crate::Call::propose_matching {
+                matches: expected_matches.clone()
+            }),
+            tx.call
+        );
+        // the matches processed by the offchain worker got reverted
+        assert_eq!(
+            Some(JobStatus::Open),
+            AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
+        );
+
+        // the matches are not proposed again while the transaction is pending
+        for _ in 0..5 {
+            AcurastMarketplace::offchain_worker(System::block_number());
+            assert!(pool_state.read().transactions.is_empty());
+            System::set_block_number(System::block_number() + 1);
+        }
+        AcurastMarketplace::offchain_worker(System::block_number());
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert_eq!(
+            RuntimeCall::AcurastMarketplace(crate::Call::propose_matching {
+                matches: expected_matches.clone()
+            }),
+            tx.call
+        );
+
+        assert_ok!(AcurastMarketplace::propose_matching(
+            RuntimeOrigin::signed(matcher_account_id(matcher)).into(),
+            expected_matches,
+        ));
+        assert_eq!(
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
+        );
+        assert_eq!(
+            Some(JobStatus::Matched),
+            AcurastMarketplace::stored_job_status(&job_id2.0, &job_id2.1)
+        );
+
+        // no open jobs left to match
+        AcurastMarketplace::offchain_worker(System::block_number());
+        assert!(pool_state.read().transactions.is_empty());
     });
 }
 
@@ -1535,6 +4564,108 @@ fn next_block() {
     Timestamp::on_initialize(System::block_number());
 }
 
+#[test]
+fn test_migrate_baseline_registrations() {
+    type BaselineRegistration = pallet_acurast::migration::v3::JobRegistration<
+        <Test as frame_system::Config>::AccountId,
+        <Test as pallet_acurast::Config>::MaxAllowedSources,
+        crate::migration::v4::JobRequirementsFor<Test>,
+    >;
+
+    let invalid_url: BoundedVec<u8, _> = b"ipfs://not-a-cid".to_vec().try_into().unwrap();
+    let baseline_registration = |url: BoundedVec<u8, _>| BaselineRegistration {
+        script: url,
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: pallet_acurast::migration::v1::Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000, // 23.12.2022 13:00
+            end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+            interval: 1_800_000,           // 30min
+            max_start_delay: 5000,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: crate::migration::v4::JobRequirements {
+            slots: 2,
+            reward: 3_000_000,
+            min_reputation: Some(0),
+            instant_match: None,
+        },
+    };
+    let registration = |script: Script| JobRegistrationFor::<Test> {
+        script,
+        allowed_sources: None,
+        allow_only_verified_sources: false,
+        schedule: Schedule {
+            duration: 5000,
+            start_time: 1_671_800_400_000,
+            end_time: 1_671_804_000_000,
+            interval: 1_800_000,
+            max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
+        },
+        memory: 5_000u32,
+        network_requests: 5,
+        storage: 20_000u32,
+        required_modules: JobModules::default(),
+        extra: JobRequirements {
+            slots: 2,
+            min_slots: None,
+            reward: 3_000_000,
+            min_reputation: Some(0),
+            min_collateral: None,
+            instant_match: None,
+        },
+    };
+
+    // the migration must not depend on the order the pallets are upgraded in
+    for acurast_first in [true, false] {
+        ExtBuilder::default().build().execute_with(|| {
+            let origin = MultiOrigin::Acurast(alice_account_id());
+            StorageVersion::new(3).put::<Acurast>();
+            StorageVersion::new(4).put::<AcurastMarketplace>();
+            for (job_id, url) in [
+                (1u128, SCRIPT_BYTES.to_vec().try_into().unwrap()),
+                (2u128, invalid_url.clone()),
+            ] {
+                frame_support::storage::unhashed::put_raw(
+                    &pallet_acurast::StoredJobRegistration::<Test>::hashed_key_for(&origin, job_id),
+                    &baseline_registration(url).encode(),
+                );
+            }
+
+            if acurast_first {
+                Acurast::on_runtime_upgrade();
+                AcurastMarketplace::on_runtime_upgrade();
+            } else {
+                AcurastMarketplace::on_runtime_upgrade();
+                Acurast::on_runtime_upgrade();
+            }
+
+            assert_eq!(
+                Some(registration(script())),
+                Acurast::stored_job_registration(&origin, 1)
+            );
+            // registrations with an invalid url are kept with a legacy script
+            assert_eq!(
+                Some(registration(Script::Legacy(invalid_url.clone()))),
+                Acurast::stored_job_registration(&origin, 2)
+            );
+            assert_eq!(
+                Acurast::current_storage_version(),
+                Acurast::on_chain_storage_version()
+            );
+            assert_eq!(
+                AcurastMarketplace::current_storage_version(),
+                AcurastMarketplace::on_chain_storage_version()
+            );
+        });
+    }
+}
+
 /// A helper function to move time on in tests. It ensures `Timestamp::set` is only called once per block by advancing the block otherwise.
 fn later(now: u64) {
     // If this is not the very first timestamp ever set, we always advance the block before setting new time
@@ -1,4 +1,10 @@
-use frame_support::{pallet_prelude::DispatchError, weights::Weight};
+use frame_support::{
+    dispatch::DispatchResultWithPostInfo, pallet_prelude::DispatchError, weights::Weight,
+    BoundedVec,
+};
+use pallet_acurast::JobId;
+
+use crate::{Config, MatchFor};
 
 /// Trait used to lookup the manager of a given processor account.
 pub trait ManagerProvider<T: frame_system::Config> {
@@ -10,6 +16,31 @@ pub trait ProcessorLastSeenProvider<T: frame_system::Config> {
     fn last_seen(processor: &T::AccountId) -> Option<u128>;
 }
 
+/// Trait used to trigger executions of on-demand jobs, e.g. from another pallet reacting to an event.
+pub trait ExecutionTrigger<T: frame_system::Config> {
+    fn trigger_execution(job_id: &JobId<T::AccountId>) -> DispatchResultWithPostInfo;
+}
+
+/// Trait used by the matcher offchain worker to submit the matches it found, see [crate::crypto::SignedMatchSubmitter].
+///
+/// The `()` implementation never submits, disabling the matcher.
+pub trait MatchSubmitter<T: Config> {
+    /// Returns whether this node is able to submit matches, e.g. has a matcher key in its keystore.
+    fn can_submit() -> bool;
+    /// Submits `matches` in a [crate::Call::propose_matching] transaction.
+    fn submit(matches: BoundedVec<MatchFor<T>, T::MaxProposedMatches>) -> Result<(), ()>;
+}
+
+impl<T: Config> MatchSubmitter<T> for () {
+    fn can_submit() -> bool {
+        false
+    }
+
+    fn submit(_matches: BoundedVec<MatchFor<T>, T::MaxProposedMatches>) -> Result<(), ()> {
+        Err(())
+    }
+}
+
 /// Weight functions needed for pallet_acurast_marketplace.
 pub trait WeightInfo {
     fn advertise() -> Weight;
@@ -19,4 +50,12 @@ pub trait WeightInfo {
     fn acknowledge_match() -> Weight;
     fn finalize_job() -> Weight;
     fn finalize_jobs(x: u32) -> Weight;
+    fn set_job_renewal() -> Weight;
+    fn trigger_execution() -> Weight;
+    fn set_job_upstream() -> Weight;
+    fn propose_replacement() -> Weight;
+    fn dispute_execution() -> Weight;
+    fn resolve_dispute() -> Weight;
+    fn report_re_execution() -> Weight;
+    fn settle_execution_reports() -> Weight;
 }
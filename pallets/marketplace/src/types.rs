@@ -2,8 +2,10 @@ use frame_support::{pallet_prelude::*, storage::bounded_vec::BoundedVec, PalletE
 use sp_std::prelude::*;
 
 use pallet_acurast::{
-    AllowedSources, JobId, JobModules, JobRegistration, MultiOrigin, ParameterBound, Schedule,
+    AllowedSources, JobId, JobModules, JobRegistration, MultiOrigin, ParameterBound,
+    RegistrationExtraReward, Schedule,
 };
+pub use pallet_acurast::{PubKey, PubKeyBytes, PubKeys, NUMBER_OF_PUB_KEYS, PUB_KEYS_MAX_LENGTH};
 
 use core::fmt::Debug;
 #[cfg(feature = "std")]
@@ -15,6 +17,28 @@ use crate::Config;
 
 pub(crate) const MAX_EXECUTIONS_PER_JOB: u64 = 6_308_000; // run a job every 5 seconds for a year
 
+/// The maximum number of stages upstream of a job in a pipeline.
+pub(crate) const MAX_PIPELINE_DEPTH: u32 = 16;
+/// The maximum number of downstream jobs triggered by the fulfillments of a single upstream job.
+pub(crate) const MAX_DOWNSTREAM_JOBS: u32 = 8;
+
+/// The number of evenly spaced start delays within a job's `max_start_delay` tried by the matcher offchain worker.
+pub(crate) const MATCHER_START_DELAY_STEPS: u64 = 4;
+
+/// The key of the [MatcherConfig] in the persistent offchain local storage.
+pub const MATCHER_CONFIG_STORAGE_KEY: &[u8] = b"acurast::marketplace::matcher_config";
+
+/// The key of the block number the matcher offchain worker last submitted matches in, in the persistent offchain local storage.
+pub(crate) const MATCHER_LAST_SUBMISSION_STORAGE_KEY: &[u8] =
+    b"acurast::marketplace::matcher_last_submission";
+
+/// The number of blocks the matcher offchain worker waits after submitting matches before submitting again, giving the
+/// transaction time to get included instead of proposing the same matches in every block.
+pub(crate) const MATCHER_SUBMISSION_INTERVAL: u32 = 5;
+
+/// The maximum number of execution reports of a source settled at once, bounding the work of reporting and finalizing.
+pub(crate) const MAX_SETTLED_EXECUTION_REPORTS: u64 = 8;
+
 pub(crate) const EXECUTION_OPERATION_HASH_MAX_LENGTH: u32 = 256;
 pub(crate) const EXECUTION_FAILURE_MESSAGE_MAX_LENGTH: u32 = 1024;
 
@@ -67,6 +91,8 @@ pub struct Advertisement<AccountId, Reward, MaxAllowedConsumers: Get<u32>> {
     pub allowed_consumers: Option<BoundedVec<MultiOrigin<AccountId>, MaxAllowedConsumers>>,
     /// The modules available to the job on processor.
     pub available_modules: JobModules,
+    /// The collateral bonded by the source, reserved from its balance. Can only be reduced while not matched to any job.
+    pub collateral: Reward,
 }
 
 pub type AdvertisementFor<T> = Advertisement<
@@ -140,21 +166,6 @@ pub struct Assignment<Reward> {
     pub pub_keys: PubKeys,
 }
 
-pub const NUMBER_OF_PUB_KEYS: u32 = 2;
-pub const PUB_KEYS_MAX_LENGTH: u32 = 33;
-
-pub type PubKeyBytes = BoundedVec<u8, ConstU32<PUB_KEYS_MAX_LENGTH>>;
-
-/// The public keys of the processor revealed when a job is acknowledged.
-pub type PubKeys = BoundedVec<PubKey, ConstU32<NUMBER_OF_PUB_KEYS>>;
-
-/// The public key revealed by a processor.
-#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
-pub enum PubKey {
-    SECP256r1(PubKeyBytes),
-    SECP256k1(PubKeyBytes),
-}
-
 pub type AssignmentFor<T> = Assignment<<T as Config>::Balance>;
 
 /// The allowed sources update operation.
@@ -164,10 +175,17 @@ pub type AssignmentFor<T> = Assignment<<T as Config>::Balance>;
 pub enum JobStatus {
     /// Status after a job got registered.
     Open,
-    /// Status after a valid match for a job got submitted.
+    /// Status after valid matches filled at least [JobRequirements::min_slots] of the job's slots.
     Matched,
     /// Status after a number of acknowledgments were submitted by sources.
     Assigned(u8),
+    /// Status after valid matches filled some of the job's slots without reaching [JobRequirements::min_slots].
+    PartiallyMatched {
+        /// The number of slots filled so far.
+        filled: u8,
+        /// The number of slots of the job.
+        total: u8,
+    },
     // The implicit final status leads to removal of job from status storage.
 }
 
@@ -195,23 +213,83 @@ pub type JobRequirementsFor<T> = JobRequirements<
 /// Structure representing a job registration.
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
 pub struct JobRequirements<Reward, AccountId, MaxSlots: ParameterBound> {
-    /// The number of execution slots to be assigned to distinct sources. Either all or no slot get assigned by matching.
+    /// The number of execution slots to be assigned to distinct sources. Slots can be filled by a single or several consecutive matches.
     pub slots: u8,
+    /// The minimum number of slots that have to be filled for the job to be [JobStatus::Matched], defaults to `slots` if [None].
+    ///
+    /// Slots that are still free once the job got matched remain empty.
+    pub min_slots: Option<u8>,
     /// Reward offered for each slot and scheduled execution of the job.
     pub reward: Reward,
     /// Minimum reputation required to process job, in parts per million, `r ∈ [0, 1_000_000]`.
     pub min_reputation: Option<u128>,
+    /// Minimum collateral a source has to bond with its advertisement to get matched to a slot of the job.
+    ///
+    /// Collateral of sources not meeting the job's SLA is slashed on finalization, see [Config::CollateralSlash].
+    pub min_collateral: Option<Reward>,
     /// Optional match provided with the job requirements. If provided, it gets processed instantaneously during
     /// registration call and validation errors lead to abortion of the call.
     pub instant_match: Option<PlannedExecutions<AccountId, MaxSlots>>,
 }
 
+impl<Reward, AccountId, MaxSlots: ParameterBound> RegistrationExtraReward
+    for JobRequirements<Reward, AccountId, MaxSlots>
+where
+    Reward: Parameter + Member + MaxEncodedLen,
+{
+    type Reward = Reward;
+
+    fn set_reward(&mut self, reward: Self::Reward) {
+        self.reward = reward;
+    }
+}
+
+impl<Reward, AccountId, MaxSlots: ParameterBound> RegistrationExtraReward
+    for RegistrationExtra<Reward, AccountId, MaxSlots>
+where
+    Reward: Parameter + Member + MaxEncodedLen,
+{
+    type Reward = Reward;
+
+    fn set_reward(&mut self, reward: Self::Reward) {
+        self.requirements.set_reward(reward);
+    }
+}
+
+/// Defines how often a job is renewed after its schedule completed.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Copy)]
+pub enum RenewalPolicy {
+    /// Renew the job the given number of times, as long as the deposit covers the renewals.
+    Times(u32),
+    /// Renew the job until the deposit no longer covers the budget of the next renewal.
+    UntilBudgetExhausted,
+}
+
+pub type JobRenewalFor<T> = JobRenewal<
+    <T as Config>::Balance,
+    <T as frame_system::Config>::AccountId,
+    <T as pallet_acurast::Config>::MaxSlots,
+>;
+
+/// The renewal of a recurring job, re-opening it with the next window once its current schedule completed.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
+pub struct JobRenewal<Reward, AccountId, MaxSlots: ParameterBound> {
+    /// The policy defining how often the job gets renewed.
+    pub policy: RenewalPolicy,
+    /// The remaining pre-funded deposit used to top up the job's budget on renewal.
+    pub deposit: Reward,
+    /// The processors that finalized their slot in the current window, preferred when matching the next window.
+    pub previous_sources: PlannedExecutions<AccountId, MaxSlots>,
+    /// The collateral slashed in the current window and added to the job's budget, refunded to the job's creator on renewal.
+    pub slashed_collateral: Reward,
+}
+
 /// A (one-sided) matching of a job to sources such that the requirements of both sides, consumer and source, are met.
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
 pub struct Match<AccountId, MaxSlots: ParameterBound> {
     /// The job to match.
     pub job_id: JobId<AccountId>,
-    /// The sources to match each of the job's slots with.
+    /// The sources to match free slots of the job with, one slot per source.
     pub sources: PlannedExecutions<AccountId, MaxSlots>,
 }
 
@@ -236,12 +314,27 @@ pub struct PartialJobRegistration<Reward, AccountId, MaxAllowedSources: Get<u32>
     pub storage: Option<u32>,
     /// The modules required for the job.
     pub required_modules: JobModules,
-    /// Job requirements: The number of execution slots to be assigned to distinct sources. Either all or no slot get assigned by matching.
+    /// Job requirements: The number of execution slots to be assigned to distinct sources. Slots can be filled by a single or several consecutive matches.
     pub slots: Option<u8>,
     /// Job requirements: Reward offered for each slot and scheduled execution of the job.
     pub reward: Reward,
     /// Job requirements: Minimum reputation required to process job, in parts per million, `r ∈ [0, 1_000_000]`.
     pub min_reputation: Option<u128>,
+    /// Job requirements: Minimum collateral bonded by a source to process job.
+    pub min_collateral: Option<Reward>,
+}
+
+/// The per-node configuration of the matcher offchain worker.
+///
+/// It is stored SCALE encoded under [MATCHER_CONFIG_STORAGE_KEY] in the persistent offchain local storage of a node,
+/// e.g. using the `offchain_localStorageSet` RPC. The matcher only runs on nodes with a configuration and a key of
+/// type [crate::crypto::KEY_TYPE] in their keystore.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq, Eq)]
+pub struct MatcherConfig {
+    /// The maximum number of jobs matched in one block, capped at [Config::MaxProposedMatches].
+    pub max_matches: u32,
+    /// If provided, only processors seen within the given milliseconds get matched.
+    pub max_last_seen_age: Option<u64>,
 }
 
 /// The details for a single planned slot execution with the delay.
@@ -261,6 +354,35 @@ pub enum ExecutionResult {
     Failure(ExecutionFailureMessage),
 }
 
+/// A successful execution reported by a source, whose fee is escrowed until the execution can no longer be disputed.
+///
+/// It's intended use is as part of a storage map that includes the job's ID, the source's ID and the execution index in its key.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+pub struct ExecutionReport<AccountId, Reward> {
+    /// The operation hash reported for the execution.
+    pub operation_hash: ExecutionOperationHash,
+    /// The time of the report in milliseconds since Unix Epoch, starting the dispute window.
+    pub reported_at: u64,
+    /// The fee escrowed for the execution.
+    pub fee: Reward,
+    /// The dispute raised by the job's creator, if any.
+    pub dispute: Option<Dispute<AccountId>>,
+}
+
+pub type ExecutionReportFor<T> =
+    ExecutionReport<<T as frame_system::Config>::AccountId, <T as Config>::Balance>;
+
+/// A dispute of a reported execution.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+pub struct Dispute<AccountId> {
+    /// Another source assigned to the job that re-executes the disputed execution to resolve the dispute.
+    ///
+    /// If [None], the dispute can only be resolved by [Config::DisputeResolutionOrigin].
+    pub verifier: Option<AccountId>,
+    /// The time of the dispute in milliseconds since Unix Epoch, starting the [Config::DisputeTimeout].
+    pub disputed_at: u64,
+}
+
 /// Allows to hook additional logic for marketplace related state transitions.
 pub trait MarketplaceHooks<T: Config> {
     fn assign_job(
@@ -40,6 +40,10 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: AcurastMarketplace StoredStorageCapacity (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: AcurastMarketplace StoredAdvertisementPricing (r:0 w:1)
 	/// Proof: AcurastMarketplace StoredAdvertisementPricing (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredCollateral (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredCollateral (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn advertise() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
@@ -47,8 +51,8 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 17_000_000 picoseconds.
 		Weight::from_parts(17_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 10840))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: AcurastMarketplace StoredAdvertisementRestriction (r:1 w:1)
 	/// Proof: AcurastMarketplace StoredAdvertisementRestriction (max_values: None, max_size: Some(3830), added: 6305, mode: MaxEncodedLen)
@@ -86,15 +90,19 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: AcurastFeeManager FeePercentage (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
 	/// Storage: System Account (r:3 w:3)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace SettledExecutionReports (r:1 w:1)
+	/// Proof: AcurastMarketplace SettledExecutionReports (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredExecutionReports (r:8 w:9)
+	/// Proof: AcurastMarketplace StoredExecutionReports (max_values: None, max_size: Some(445), added: 2920, mode: MaxEncodedLen)
 	fn report() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `2615`
-		//  Estimated: `67822`
-		// Minimum execution time: 86_000_000 picoseconds.
-		Weight::from_parts(87_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 67822))
-			.saturating_add(T::DbWeight::get().reads(11))
-			.saturating_add(T::DbWeight::get().writes(5))
+		//  Measured:  `5413`
+		//  Estimated: `95082`
+		// Minimum execution time: 171_000_000 picoseconds.
+		Weight::from_parts(174_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 95082))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(15))
 	}
 	/// Storage: AcurastMarketplace StoredJobStatus (r:10 w:10)
 	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
@@ -110,6 +118,8 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: AcurastMarketplace StoredStorageCapacity (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// Storage: AcurastMarketplace StoredReputation (r:640 w:0)
 	/// Proof: AcurastMarketplace StoredReputation (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace CommittedCollateral (r:640 w:640)
+	/// Proof: AcurastMarketplace CommittedCollateral (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	/// Storage: AcurastMarketplace StoredMatches (r:1280 w:640)
 	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
 	/// Storage: AcurastMarketplace StoredTotalAssignedV3 (r:1 w:1)
@@ -139,9 +149,9 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			// Standard Error: 8_200_029
 			.saturating_add(Weight::from_parts(1_476_890_801, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().reads((387_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().reads((451_u64).saturating_mul(x.into())))
 			.saturating_add(T::DbWeight::get().writes(3))
-			.saturating_add(T::DbWeight::get().writes((194_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((258_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 1278702).saturating_mul(x.into()))
 	}
 	/// Storage: AcurastMarketplace StoredMatches (r:1 w:1)
@@ -168,17 +178,31 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: Acurast StoredAttestation (max_values: None, max_size: Some(11622), added: 14097, mode: MaxEncodedLen)
 	/// Storage: AcurastMarketplace StoredStorageCapacity (r:1 w:1)
 	/// Proof: AcurastMarketplace StoredStorageCapacity (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
-	/// Storage: AcurastMarketplace AssignedProcessors (r:0 w:1)
+	/// Storage: AcurastMarketplace AssignedProcessors (r:1 w:1)
 	/// Proof: AcurastMarketplace AssignedProcessors (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobRenewals (r:2 w:0)
+	/// Proof: AcurastMarketplace JobRenewals (max_values: None, max_size: Some(2648), added: 5123, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredCollateral (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredCollateral (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace CommittedCollateral (r:1 w:1)
+	/// Proof: AcurastMarketplace CommittedCollateral (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace SettledExecutionReports (r:1 w:1)
+	/// Proof: AcurastMarketplace SettledExecutionReports (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredExecutionReports (r:8 w:8)
+	/// Proof: AcurastMarketplace StoredExecutionReports (max_values: None, max_size: Some(445), added: 2920, mode: MaxEncodedLen)
 	fn finalize_job() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1102`
-		//  Estimated: `62025`
-		// Minimum execution time: 36_000_000 picoseconds.
-		Weight::from_parts(37_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 62025))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Measured:  `3758`
+		//  Estimated: `98241`
+		// Minimum execution time: 109_000_000 picoseconds.
+		Weight::from_parts(112_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 98241))
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().writes(18))
 	}
 	/// Storage: AcurastMarketplace StoredJobStatus (r:10 w:10)
 	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
@@ -194,19 +218,217 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
 	/// Storage: AcurastMarketplace StoredMatches (r:0 w:10)
 	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobRenewals (r:10 w:10)
+	/// Proof: AcurastMarketplace JobRenewals (max_values: None, max_size: Some(2648), added: 5123, mode: MaxEncodedLen)
 	/// The range of component `x` is `[1, 10]`.
 	fn finalize_jobs(x: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `657 + x * (431 ±0)`
-		//  Estimated: `6443 + x * (49971 ±0)`
+		//  Estimated: `6443 + x * (55094 ±0)`
 		// Minimum execution time: 58_000_000 picoseconds.
 		Weight::from_parts(13_348_701, 0)
 			.saturating_add(Weight::from_parts(0, 6443))
 			// Standard Error: 62_399
 			.saturating_add(Weight::from_parts(47_230_935, 0).saturating_mul(x.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
-			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(x.into())))
-			.saturating_add(Weight::from_parts(0, 49971).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 55094).saturating_mul(x.into()))
+	}
+	/// Storage: AcurastMarketplace StoredJobStatus (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobRenewals (r:1 w:1)
+	/// Proof: AcurastMarketplace JobRenewals (max_values: None, max_size: Some(2648), added: 5123, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn set_job_renewal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `12838`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 12838))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredJobStatus (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace AssignedProcessors (r:2 w:0)
+	/// Proof: AcurastMarketplace AssignedProcessors (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredMatches (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	fn trigger_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1264`
+		//  Estimated: `55887`
+		// Minimum execution time: 56_000_000 picoseconds.
+		Weight::from_parts(58_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 55887))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobUpstreams (r:2 w:1)
+	/// Proof: AcurastMarketplace JobUpstreams (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredJobStatus (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobDownstreams (r:1 w:1)
+	/// Proof: AcurastMarketplace JobDownstreams (max_values: None, max_size: Some(130), added: 2605, mode: MaxEncodedLen)
+	fn set_job_upstream() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1032`
+		//  Estimated: `47562`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(32_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 47562))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: AcurastMarketplace StoredJobStatus (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredJobStatus (max_values: None, max_size: Some(34), added: 2509, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredMatches (r:3 w:2)
+	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredStorageCapacity (r:2 w:2)
+	/// Proof: AcurastMarketplace StoredStorageCapacity (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredAverageRewardV3 (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredAverageRewardV3 (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredReputation (r:2 w:1)
+	/// Proof: AcurastMarketplace StoredReputation (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace CommittedCollateral (r:2 w:2)
+	/// Proof: AcurastMarketplace CommittedCollateral (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredAdvertisementRestriction (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredAdvertisementRestriction (max_values: None, max_size: Some(3830), added: 6305, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredAdvertisementPricing (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredAdvertisementPricing (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: AcurastMatcherFeeManager Version (r:1 w:0)
+	/// Proof: AcurastMatcherFeeManager Version (max_values: Some(1), max_size: Some(2), added: 497, mode: MaxEncodedLen)
+	/// Storage: AcurastMatcherFeeManager FeePercentage (r:1 w:0)
+	/// Proof: AcurastMatcherFeeManager FeePercentage (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AcurastFeeManager Version (r:1 w:0)
+	/// Proof: AcurastFeeManager Version (max_values: Some(1), max_size: Some(2), added: 497, mode: MaxEncodedLen)
+	/// Storage: AcurastFeeManager FeePercentage (r:1 w:0)
+	/// Proof: AcurastFeeManager FeePercentage (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace AssignedProcessors (r:0 w:2)
+	/// Proof: AcurastMarketplace AssignedProcessors (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	fn propose_replacement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2500`
+		//  Estimated: `93641`
+		// Minimum execution time: 121_000_000 picoseconds.
+		Weight::from_parts(124_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 93641))
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredExecutionReports (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredExecutionReports (max_values: None, max_size: Some(445), added: 2920, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace AssignedProcessors (r:1 w:0)
+	/// Proof: AcurastMarketplace AssignedProcessors (max_values: None, max_size: Some(118), added: 2593, mode: MaxEncodedLen)
+	fn dispute_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812`
+		//  Estimated: `10998`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10998))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: AcurastMarketplace StoredExecutionReports (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredExecutionReports (max_values: None, max_size: Some(445), added: 2920, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredAverageRewardV3 (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredAverageRewardV3 (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredReputation (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredReputation (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredCollateral (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredCollateral (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3104`
+		//  Estimated: `56500`
+		// Minimum execution time: 64_000_000 picoseconds.
+		Weight::from_parts(66_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 56500))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: AcurastMarketplace StoredExecutionReports (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredExecutionReports (max_values: None, max_size: Some(445), added: 2920, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
+	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredAverageRewardV3 (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredAverageRewardV3 (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredReputation (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredReputation (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredCollateral (r:1 w:1)
+	/// Proof: AcurastMarketplace StoredCollateral (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn report_re_execution() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3126`
+		//  Estimated: `56500`
+		// Minimum execution time: 66_000_000 picoseconds.
+		Weight::from_parts(68_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 56500))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: AcurastMarketplace StoredMatches (r:1 w:0)
+	/// Proof: AcurastMarketplace StoredMatches (max_values: None, max_size: Some(231), added: 2706, mode: MaxEncodedLen)
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace SettledExecutionReports (r:1 w:1)
+	/// Proof: AcurastMarketplace SettledExecutionReports (max_values: None, max_size: Some(126), added: 2601, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace StoredExecutionReports (r:8 w:8)
+	/// Proof: AcurastMarketplace StoredExecutionReports (max_values: None, max_size: Some(445), added: 2920, mode: MaxEncodedLen)
+	/// Storage: AcurastProcessorManager ProcessorToManagerIdIndex (r:1 w:0)
+	/// Proof: AcurastProcessorManager ProcessorToManagerIdIndex (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: Uniques Asset (r:1 w:0)
+	/// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	/// Storage: AcurastMarketplace JobBudgets (r:1 w:1)
+	/// Proof: AcurastMarketplace JobBudgets (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	/// Storage: AcurastFeeManager Version (r:1 w:0)
+	/// Proof: AcurastFeeManager Version (max_values: Some(1), max_size: Some(2), added: 497, mode: MaxEncodedLen)
+	/// Storage: AcurastFeeManager FeePercentage (r:1 w:0)
+	/// Proof: AcurastFeeManager FeePercentage (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	/// Storage: System Account (r:3 w:3)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn settle_execution_reports() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3419`
+		//  Estimated: `46725`
+		// Minimum execution time: 104_000_000 picoseconds.
+		Weight::from_parts(107_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 46725))
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(13))
 	}
 }
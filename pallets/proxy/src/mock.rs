@@ -5,7 +5,7 @@ use xcm::latest::{Junction, MultiLocation, OriginKind};
 use xcm::prelude::*;
 use xcm_executor::traits::ConvertOrigin;
 
-use acurast_common::Schedule;
+use acurast_common::{Schedule, ScheduleKind};
 use acurast_runtime::AccountId as AcurastAccountId;
 use pallet_acurast::{JobModules, JobRegistration, CU32};
 use pallet_acurast_marketplace::{Advertisement, JobRequirements, Pricing, SchedulingWindow};
@@ -15,7 +15,7 @@ pub const SEED: u32 = 1337;
 
 pub type Balance = u128;
 
-pub const SCRIPT_BYTES: [u8; 53] = hex_literal::hex!("697066733A2F2F00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000");
+pub const SCRIPT_BYTES: [u8; 53] = hex_literal::hex!("697066733A2F2F516D4E4C656937387A576D7A556462655242334369556641697A5755726265655A68354B31726841514B43683531");
 
 pub type MaxAllowedSources = CU32<10>;
 pub type MaxSlots = CU32<64>;
@@ -41,6 +41,7 @@ pub fn registration() -> JobRegistration<
             end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
             interval: 1_800_000,           // 30min
             max_start_delay: 5000,
+            kind: ScheduleKind::Interval,
         },
         memory: 5_000u32,
         network_requests: 5,
@@ -48,8 +49,10 @@ pub fn registration() -> JobRegistration<
         required_modules: JobModules::default(),
         extra: JobRequirements {
             slots: 1,
+            min_slots: None,
             reward: 20000,
             min_reputation: None,
+            min_collateral: None,
             instant_match: None,
         },
     }
@@ -69,6 +72,7 @@ pub fn advertisement(
         max_memory: 5000,
         network_request_quota: 8,
         available_modules: JobModules::default(),
+        collateral: 0,
     }
 }
 
@@ -145,6 +149,17 @@ pub mod acurast_runtime {
         pub const IsRelay: bool = false;
         pub const AcurastPalletId: PalletId = PalletId(*b"acrstpid");
         pub const ReportTolerance: u64 = 12000;
+        pub const AcknowledgeDeadline: u64 = 600_000;
+        pub const CollateralSlash: sp_runtime::Percent = sp_runtime::Percent::from_percent(10);
+        pub const CollateralSlashTreasuryShare: sp_runtime::Percent = sp_runtime::Percent::from_percent(50);
+        pub const TreasuryPalletId: PalletId = PalletId(*b"acrsttry");
+        pub const DisputeWindow: u64 = 600_000;
+        pub const DisputeTimeout: u64 = 3_600_000;
+        pub RevocationStatusListSigner: ed25519::Public = revocation_status_list_signer().public();
+    }
+
+    pub fn revocation_status_list_signer() -> ed25519::Pair {
+        ed25519::Pair::from_seed(&[1; 32])
     }
     parameter_types! {
         pub const BlockHashCount: u64 = 250;
@@ -271,11 +286,24 @@ pub mod acurast_runtime {
     impl pallet_acurast::Config for Runtime {
         type RuntimeEvent = RuntimeEvent;
         type RegistrationExtra = JobRequirements<Balance, AccountId, super::MaxSlots>;
+        type RegistrationExtraMigration = pallet_acurast_marketplace::MigrateJobRequirements<Self>;
         type MaxAllowedSources = super::MaxAllowedSources;
         type MaxCertificateRevocationListUpdates = frame_support::traits::ConstU32<10>;
+        type MaxTrustedRootCertificateUpdates = frame_support::traits::ConstU32<10>;
+        type MaxAllowedAppIdUpdates = frame_support::traits::ConstU32<10>;
+        type MaxRevocationStatusListLength = CU32<4096>;
+        type MaxRevocationStatusListEntries = frame_support::traits::ConstU32<16>;
+        type RevocationStatusListSigner = RevocationStatusListSigner;
+        type MaxEnvironmentVersions = CU32<4>;
+        type MaxJobTemplatesPerOwner = frame_support::traits::ConstU32<2>;
         type PalletId = AcurastPalletId;
         type RevocationListUpdateBarrier = ();
+        type TrustedRootCertificatesUpdateOrigin = frame_system::EnsureRoot<AccountId>;
+        type AllowedAppIdsUpdateOrigin = frame_system::EnsureRoot<AccountId>;
+        type AttestationPolicyUpdateOrigin = frame_system::EnsureRoot<AccountId>;
+        type AttestationExpiryGracePeriod = frame_support::traits::ConstU64<604_800_000>;
         type KeyAttestationBarrier = ();
+        type AttestationKeyBinding = pallet_acurast::PublicKeyHashBinding;
         type UnixTime = pallet_timestamp::Pallet<Runtime>;
         type JobHooks = pallet_acurast_marketplace::Pallet<Runtime>;
         type WeightInfo = pallet_acurast::weights::WeightInfo<Runtime>;
@@ -288,8 +316,10 @@ pub mod acurast_runtime {
         fn registration_extra() -> <Runtime as pallet_acurast::Config>::RegistrationExtra {
             JobRequirements {
                 slots: 1,
+                min_slots: None,
                 reward: 1,
                 min_reputation: None,
+                min_collateral: None,
                 instant_match: None,
             }
         }
@@ -304,6 +334,29 @@ pub mod acurast_runtime {
 
             caller
         }
+
+        fn sign_revocation_status_list(message: &[u8]) -> ed25519::Signature {
+            revocation_status_list_signer().sign(message)
+        }
+
+        fn acknowledge_environment_key(
+            job_id: &pallet_acurast::JobId<super::AcurastAccountId>,
+            source: &super::AcurastAccountId,
+            public_key: &pallet_acurast::PubKey,
+        ) {
+            pallet_acurast_marketplace::StoredMatches::<Runtime>::insert(
+                source,
+                job_id,
+                pallet_acurast_marketplace::Assignment {
+                    slot: 0,
+                    start_delay: 0,
+                    fee_per_execution: 1,
+                    acknowledged: true,
+                    sla: pallet_acurast_marketplace::SLA { total: 1, met: 0 },
+                    pub_keys: pallet_acurast::PubKeys::truncate_from(vec![public_key.clone()]),
+                },
+            );
+        }
     }
 
     pub struct ManagerOf;
@@ -335,11 +388,19 @@ pub mod acurast_runtime {
         type RegistrationExtra = JobRequirements<Balance, AccountId, Self::MaxSlots>;
         type PalletId = AcurastPalletId;
         type ReportTolerance = ReportTolerance;
+        type AcknowledgeDeadline = AcknowledgeDeadline;
+        type CollateralSlash = CollateralSlash;
+        type CollateralSlashTreasuryShare = CollateralSlashTreasuryShare;
+        type TreasuryPalletId = TreasuryPalletId;
+        type DisputeWindow = DisputeWindow;
+        type DisputeTimeout = DisputeTimeout;
+        type DisputeResolutionOrigin = frame_system::EnsureRoot<AccountId>;
         type Balance = Balance;
         type ManagerProvider = ManagerOf;
         type RewardManager = AssetRewardManager<FeeManagerImpl, Balances, AcurastMarketplace>;
         type ProcessorLastSeenProvider = ProcessorLastSeenProvider;
         type MarketplaceHooks = ();
+        type MatchSubmitter = ();
         type WeightInfo = pallet_acurast_marketplace::weights::WeightInfo<Runtime>;
         #[cfg(feature = "runtime-benchmarks")]
         type BenchmarkHelper = TestBenchmarkHelper;